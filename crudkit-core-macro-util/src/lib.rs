@@ -47,6 +47,21 @@ pub fn to_pascal_case(name: &str) -> String {
     name.split('_').map(capitalize_first_letter).collect()
 }
 
+/// Converts a `snake_case` field name to a `SCREAMING_SNAKE_CASE` constant name.
+///
+/// A leading raw identifier prefix (`r#`) is dropped.
+///
+/// # Examples
+/// ```
+/// use crudkit_core_macro_util::to_screaming_snake_case;
+/// assert_eq!(to_screaming_snake_case("user_id"), "USER_ID");
+/// assert_eq!(to_screaming_snake_case("r#type"), "TYPE");
+/// ```
+#[must_use]
+pub fn to_screaming_snake_case(name: &str) -> String {
+    name.trim_start_matches("r#").to_uppercase()
+}
+
 /// Converts a `snake_case` field name to a `PascalCase` identifier.
 ///
 /// This is a convenience function that combines `to_pascal_case` with `Ident::new`.
@@ -96,6 +111,13 @@ mod tests {
         assert_eq!(to_pascal_case("some_long_field_name"), "SomeLongFieldName");
     }
 
+    #[test]
+    fn test_to_screaming_snake_case() {
+        assert_eq!(to_screaming_snake_case("user_id"), "USER_ID");
+        assert_eq!(to_screaming_snake_case("id"), "ID");
+        assert_eq!(to_screaming_snake_case("r#type"), "TYPE");
+    }
+
     #[test]
     fn test_strip_quotes() {
         assert_eq!(strip_quotes("\"hello\""), "hello");
@@ -120,7 +142,7 @@ pub trait ValueKindExt {
     fn value_variant_ident(&self) -> Ident;
 
     /// Returns the `ConditionClauseValue::to_*` method name for converting the clause value
-    /// to a `Value` of this `ValueKind`.
+    /// to a `Value` of this `ValueKind`. `to_enum` is generic over the enum type.
     ///
     /// Returns `None` for types without a conversion method (`Null`, `Void`, `Array`, `Other`).
     fn condition_method_name(&self) -> Option<&'static str>;
@@ -129,6 +151,11 @@ pub trait ValueKindExt {
     ///
     /// Returns `None` for types without an accessor method (`Null`, `Void`).
     fn accessor_method_name(&self) -> Option<&'static str>;
+
+    /// Whether a `Vec` of values of this kind is an `Array` field.
+    ///
+    /// `Vec<u8>` stays binary data, and kinds without a Postgres array type are excluded.
+    fn is_array_element(&self) -> bool;
 }
 
 impl ValueKindExt for ValueKind {
@@ -149,11 +176,15 @@ impl ValueKindExt for ValueKind {
             ValueKind::I128 => "I128",
             ValueKind::F32 => "F32",
             ValueKind::F64 => "F64",
+            ValueKind::Decimal => "Decimal",
             ValueKind::String => "String",
+            ValueKind::Enum => "Enum",
             ValueKind::Json => "Json",
             ValueKind::Uuid => "Uuid",
             ValueKind::PrimitiveDateTime => "PrimitiveDateTime",
             ValueKind::OffsetDateTime => "OffsetDateTime",
+            ValueKind::Date => "Date",
+            ValueKind::Time => "Time",
             ValueKind::Duration => "Duration",
             ValueKind::Array => "Array",
             ValueKind::Other => "Other",
@@ -179,11 +210,15 @@ impl ValueKindExt for ValueKind {
             ValueKind::I128 => Some("to_i128"),
             ValueKind::F32 => Some("to_f32"),
             ValueKind::F64 => Some("to_f64"),
+            ValueKind::Decimal => Some("to_decimal"),
             ValueKind::String => Some("to_string"),
+            ValueKind::Enum => Some("to_enum"),
             ValueKind::Json => Some("to_json_value"),
             ValueKind::Uuid => Some("to_uuid"),
             ValueKind::PrimitiveDateTime => Some("to_primitive_date_time"),
             ValueKind::OffsetDateTime => Some("to_offset_date_time"),
+            ValueKind::Date => Some("to_date"),
+            ValueKind::Time => Some("to_time"),
             ValueKind::Duration => Some("to_time_duration"),
             ValueKind::Null | ValueKind::Void | ValueKind::Array | ValueKind::Other => None,
         }
@@ -204,17 +239,36 @@ impl ValueKindExt for ValueKind {
             ValueKind::I128 => Some("as_i128"),
             ValueKind::F32 => Some("as_f32"),
             ValueKind::F64 => Some("as_f64"),
+            ValueKind::Decimal => Some("as_decimal"),
             ValueKind::String => Some("as_string"),
+            ValueKind::Enum => Some("as_enum"),
             ValueKind::Json => Some("as_json"),
             ValueKind::Uuid => Some("as_uuid"),
             ValueKind::PrimitiveDateTime => Some("as_primitive_date_time"),
             ValueKind::OffsetDateTime => Some("as_offset_date_time"),
+            ValueKind::Date => Some("as_date"),
+            ValueKind::Time => Some("as_time"),
             ValueKind::Duration => Some("as_duration"),
             ValueKind::Array => Some("as_array"),
             ValueKind::Other => Some("as_other"),
             ValueKind::Null | ValueKind::Void => None,
         }
     }
+
+    fn is_array_element(&self) -> bool {
+        !matches!(
+            self,
+            ValueKind::Null
+                | ValueKind::Void
+                | ValueKind::U8
+                | ValueKind::U128
+                | ValueKind::I128
+                | ValueKind::Json
+                | ValueKind::Duration
+                | ValueKind::Array
+                | ValueKind::Other
+        )
+    }
 }
 
 /// Checks if a `syn::Path` represents `Option<T>`.
@@ -257,19 +311,70 @@ pub fn strip_option_path(path: &syn::Path) -> Option<&syn::Type> {
     }
 }
 
+/// Returns the inner type of `Option<T>`, or the type itself if it is not an `Option`.
+#[must_use]
+pub fn strip_option_type(ty: &syn::Type) -> &syn::Type {
+    match ty {
+        syn::Type::Path(type_path) => strip_option_path(&type_path.path).unwrap_or(ty),
+        other => other,
+    }
+}
+
+/// Returns the element type of `Vec<T>`, or `None` if the type is not a `Vec`.
+///
+/// This handles both `Vec<T>` and `std::vec::Vec<T>`.
+#[must_use]
+pub fn strip_vec_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let last_segment = type_path.path.segments.last()?;
+    if last_segment.ident != "Vec" {
+        return None;
+    }
+    match &last_segment.arguments {
+        syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+            match args.args.first()? {
+                syn::GenericArgument::Type(element_ty) => Some(element_ty),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the element type of a `Vec<T>` field stored as an array, ignoring an `Option` wrapper
+/// around the `Vec`, e.g. `String` for `Option<Vec<String>>`.
+///
+/// Elements must be enums (`is_enum`) or of a kind passing [`ValueKindExt::is_array_element`].
+/// Optional elements are not supported.
+#[must_use]
+pub fn array_element_type(ty: &syn::Type, is_enum: bool) -> Option<&syn::Type> {
+    let element_ty = strip_vec_type(strip_option_type(ty))?;
+    let syn::Type::Path(type_path) = element_ty else {
+        return None;
+    };
+    let supported = !is_option_path(&type_path.path)
+        && (is_enum || classify_base_type(&path_to_string(&type_path.path)).is_array_element());
+    supported.then_some(element_ty)
+}
+
 /// Classifies a normalized type path string into a `ValueKind`.
 ///
 /// Expects the inner type for `Option<T>` (caller strips `Option` wrapper using [`strip_option_path`]).
-/// Returns `ValueKind::Other` for unrecognized types.
+/// Returns `ValueKind::Other` for unrecognized types. Enums are not recognized by their type;
+/// fields holding them are marked with `#[ck_field(enum)]` instead.
 ///
 /// # Type Recognition
 ///
 /// The following types are recognized:
 /// - Primitives: `bool`, `u8`..`u128`, `i8`..`i128`, `f32`, `f64`, `String`
-/// - Time types: `time::PrimitiveDateTime`, `time::OffsetDateTime`, `TimeDuration`
+/// - Time types: `time::PrimitiveDateTime`, `time::OffsetDateTime`, `time::Date`, `time::Time`,
+///   `TimeDuration`
 /// - UUID: `uuid::Uuid`, `Uuid`
 /// - JSON: `serde_json::Value`
 /// - Ordered floats: `OrderedFloat<f32>`, `OrderedFloat<f64>` (mapped to `F32`, `F64`)
+/// - Decimals: `rust_decimal::Decimal`, `Decimal` (also re-exported by SeaORM's prelude)
 ///
 /// # Examples
 /// ```
@@ -300,6 +405,8 @@ pub fn classify_base_type(path_str: &str) -> ValueKind {
         "f32" | "OrderedFloat<f32>" | "ordered_float::OrderedFloat<f32>" => ValueKind::F32,
         "f64" | "OrderedFloat<f64>" | "ordered_float::OrderedFloat<f64>" => ValueKind::F64,
 
+        "Decimal" | "rust_decimal::Decimal" | "sea_orm::prelude::Decimal" => ValueKind::Decimal,
+
         "String" => ValueKind::String,
 
         "serde_json::Value" => ValueKind::Json,
@@ -308,6 +415,8 @@ pub fn classify_base_type(path_str: &str) -> ValueKind {
 
         "time::PrimitiveDateTime" => ValueKind::PrimitiveDateTime,
         "time::OffsetDateTime" => ValueKind::OffsetDateTime,
+        "time::Date" => ValueKind::Date,
+        "time::Time" => ValueKind::Time,
         "TimeDuration" | "crudkit_sea_orm::newtypes::TimeDuration" => ValueKind::Duration,
 
         _ => ValueKind::Other,
@@ -364,7 +473,14 @@ mod value_kind_tests {
             classify_base_type("time::OffsetDateTime"),
             ValueKind::OffsetDateTime
         );
+        assert_eq!(classify_base_type("time::Date"), ValueKind::Date);
+        assert_eq!(classify_base_type("time::Time"), ValueKind::Time);
         assert_eq!(classify_base_type("TimeDuration"), ValueKind::Duration);
+        assert_eq!(classify_base_type("Decimal"), ValueKind::Decimal);
+        assert_eq!(
+            classify_base_type("rust_decimal::Decimal"),
+            ValueKind::Decimal
+        );
     }
 
     #[test]
@@ -404,6 +520,7 @@ mod value_kind_tests {
         assert_eq!(ValueKind::Bool.condition_method_name(), Some("to_bool"));
         assert_eq!(ValueKind::I32.condition_method_name(), Some("to_i32"));
         assert_eq!(ValueKind::String.condition_method_name(), Some("to_string"));
+        assert_eq!(ValueKind::Enum.condition_method_name(), Some("to_enum"));
         assert_eq!(
             ValueKind::Json.condition_method_name(),
             Some("to_json_value")
@@ -419,6 +536,7 @@ mod value_kind_tests {
         assert_eq!(ValueKind::Bool.accessor_method_name(), Some("as_bool"));
         assert_eq!(ValueKind::I32.accessor_method_name(), Some("as_i32"));
         assert_eq!(ValueKind::String.accessor_method_name(), Some("as_string"));
+        assert_eq!(ValueKind::Enum.accessor_method_name(), Some("as_enum"));
         assert_eq!(ValueKind::Json.accessor_method_name(), Some("as_json"));
         assert_eq!(ValueKind::Uuid.accessor_method_name(), Some("as_uuid"));
         assert_eq!(
@@ -433,6 +551,53 @@ mod value_kind_tests {
         assert_eq!(ValueKind::Null.accessor_method_name(), None);
     }
 
+    #[test]
+    fn test_is_array_element() {
+        assert!(ValueKind::String.is_array_element());
+        assert!(ValueKind::I32.is_array_element());
+        assert!(ValueKind::Enum.is_array_element());
+        assert!(ValueKind::Uuid.is_array_element());
+
+        // Vec<u8> is binary data.
+        assert!(!ValueKind::U8.is_array_element());
+        assert!(!ValueKind::Json.is_array_element());
+        assert!(!ValueKind::Array.is_array_element());
+        assert!(!ValueKind::Other.is_array_element());
+    }
+
+    #[test]
+    fn test_strip_vec_type() {
+        let element = |ty: syn::Type| strip_vec_type(&ty).map(|ty| quote::quote!(#ty).to_string());
+        assert_eq!(
+            element(syn::parse_quote!(Vec<String>)),
+            Some("String".to_owned())
+        );
+        assert_eq!(
+            element(syn::parse_quote!(std::vec::Vec<i32>)),
+            Some("i32".to_owned())
+        );
+        assert_eq!(element(syn::parse_quote!(Option<Vec<i32>>)), None);
+        assert_eq!(element(syn::parse_quote!(String)), None);
+    }
+
+    #[test]
+    fn test_array_element_type() {
+        let element = |ty: syn::Type, is_enum: bool| {
+            array_element_type(&ty, is_enum).map(|ty| quote::quote!(#ty).to_string())
+        };
+        assert_eq!(
+            element(syn::parse_quote!(Option<Vec<String>>), false),
+            Some("String".to_owned())
+        );
+        assert_eq!(
+            element(syn::parse_quote!(Vec<Status>), true),
+            Some("Status".to_owned())
+        );
+        assert_eq!(element(syn::parse_quote!(Vec<Status>), false), None);
+        assert_eq!(element(syn::parse_quote!(Vec<u8>), false), None);
+        assert_eq!(element(syn::parse_quote!(Vec<Option<i32>>), false), None);
+    }
+
     #[test]
     fn test_is_ordered_float() {
         assert!(is_ordered_float("OrderedFloat<f32>"));
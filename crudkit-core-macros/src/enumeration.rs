@@ -0,0 +1,182 @@
+//! Implementation of the `CkEnum` derive macro.
+
+use darling::*;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Ident, LitStr};
+
+#[derive(Debug, FromVariant)]
+#[darling(attributes(ck_enum), forward_attrs(serde))]
+struct CkEnumVariant {
+    ident: Ident,
+
+    attrs: Vec<syn::Attribute>,
+
+    /// Human-readable name. Derived from the variant name if not given.
+    label: Option<String>,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(ck_enum), forward_attrs(serde), supports(enum_unit))]
+struct CkEnumInput {
+    ident: Ident,
+
+    attrs: Vec<syn::Attribute>,
+
+    data: ast::Data<CkEnumVariant, ()>,
+}
+
+/// The serde attributes affecting the name of a variant.
+#[derive(Default)]
+struct SerdeNames {
+    rename: Option<String>,
+    rename_all: Option<String>,
+}
+
+impl SerdeNames {
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut names = SerdeNames::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+            attr.parse_nested_meta(|meta| {
+                let target = match &meta.path {
+                    path if path.is_ident("rename") => Some(&mut names.rename),
+                    path if path.is_ident("rename_all") => Some(&mut names.rename_all),
+                    _ => None,
+                };
+                match target {
+                    Some(target) => *target = Some(parse_serialize_name(&meta)?),
+                    None => skip_meta(&meta)?,
+                }
+                Ok(())
+            })?;
+        }
+        Ok(names)
+    }
+}
+
+/// Parses `key = "name"` or `key(serialize = "name", ...)`, returning the serialized name.
+fn parse_serialize_name(meta: &syn::meta::ParseNestedMeta) -> syn::Result<String> {
+    if meta.input.peek(syn::Token![=]) {
+        return Ok(meta.value()?.parse::<LitStr>()?.value());
+    }
+    let mut name = None;
+    meta.parse_nested_meta(|nested| {
+        match nested.path.is_ident("serialize") {
+            true => name = Some(nested.value()?.parse::<LitStr>()?.value()),
+            false => skip_meta(&nested)?,
+        }
+        Ok(())
+    })?;
+    name.ok_or_else(|| meta.error("expected a `serialize` name"))
+}
+
+/// Skips the value of a serde attribute this derive does not care about.
+fn skip_meta(meta: &syn::meta::ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(syn::Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|nested| skip_meta(&nested))?;
+    }
+    Ok(())
+}
+
+/// Splits a `PascalCase` identifier into its lowercase words.
+fn words(ident: &str) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    for c in ident.chars() {
+        match words.last_mut() {
+            Some(word) if !c.is_uppercase() => word.push(c),
+            _ => words.push(c.to_lowercase().collect()),
+        }
+    }
+    words
+}
+
+/// Applies a serde `rename_all` rule to a `PascalCase` variant name.
+fn apply_rename_rule(rule: &str, variant: &str) -> Option<String> {
+    let words = words(variant);
+    Some(match rule {
+        "lowercase" => variant.to_lowercase(),
+        "UPPERCASE" => variant.to_uppercase(),
+        "PascalCase" => variant.to_owned(),
+        "camelCase" => {
+            let mut chars = variant.chars();
+            chars
+                .next()
+                .map(|first| first.to_lowercase().chain(chars).collect())
+                .unwrap_or_default()
+        }
+        "snake_case" => words.join("_"),
+        "SCREAMING_SNAKE_CASE" => words.join("_").to_uppercase(),
+        "kebab-case" => words.join("-"),
+        "SCREAMING-KEBAB-CASE" => words.join("-").to_uppercase(),
+        _ => return None,
+    })
+}
+
+/// Derives a label like `In progress` from a variant name like `InProgress`.
+fn default_label(variant: &str) -> String {
+    let label = words(variant).join(" ");
+    let mut chars = label.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+pub fn expand_derive_ck_enum(input: DeriveInput) -> syn::Result<TokenStream> {
+    let input: CkEnumInput = FromDeriveInput::from_derive_input(&input)?;
+    let name = &input.ident;
+
+    let variants = match &input.data {
+        ast::Data::Enum(variants) => variants,
+        ast::Data::Struct(_) => unreachable!("darling #[supports(enum_unit)] prevents structs"),
+    };
+
+    let rename_all = SerdeNames::parse(&input.attrs)?.rename_all;
+
+    let mut idents = Vec::new();
+    let mut names = Vec::new();
+    let mut labels = Vec::new();
+    for variant in variants {
+        let ident = variant.ident.to_string();
+        let serialized = match (SerdeNames::parse(&variant.attrs)?.rename, &rename_all) {
+            (Some(rename), _) => rename,
+            (None, Some(rule)) => apply_rename_rule(rule, &ident).ok_or_else(|| {
+                syn::Error::new_spanned(name, format!("unknown serde rename rule '{rule}'"))
+            })?,
+            (None, None) => ident.clone(),
+        };
+        idents.push(&variant.ident);
+        names.push(serialized);
+        labels.push(
+            variant
+                .label
+                .clone()
+                .unwrap_or_else(|| default_label(&ident)),
+        );
+    }
+
+    Ok(quote! {
+        impl crudkit_core::CkEnum for #name {
+            fn variants() -> &'static [crudkit_core::EnumVariant] {
+                &[
+                    #(crudkit_core::EnumVariant { name: #names, label: #labels }),*
+                ]
+            }
+
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    #(Self::#idents => #names),*
+                }
+            }
+
+            fn from_variant_name(name: &str) -> Option<Self> {
+                match name {
+                    #(#names => Some(Self::#idents),)*
+                    _ => None,
+                }
+            }
+        }
+    })
+}
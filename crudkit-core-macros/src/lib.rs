@@ -9,6 +9,8 @@ use proc_macro_type_name::ToTypeName;
 use quote::quote;
 use syn::{parse_macro_input, spanned::Spanned, DeriveInput, Ident};
 
+mod enumeration;
+
 const SUPPORTED_TYPES_HELP: &str = indoc::indoc! {
     r#"
     Supported ID field types:
@@ -252,6 +254,36 @@ pub fn derive_ck_id(input: TokenStream) -> TokenStream {
     .into()
 }
 
+/// Implements `crudkit_core::CkEnum` for a fieldless enum.
+///
+/// Variant names follow the enum's serde `rename` and `rename_all` attributes, so that the names
+/// stored in `Value::Enum` match the enum's serialized form. Labels default to the variant name
+/// split into words (`InProgress` becomes `In progress`) and can be set with
+/// `#[ck_enum(label = "...")]`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use crudkit_core_macros::CkEnum;
+///
+/// #[derive(CkEnum, serde::Serialize, serde::Deserialize)]
+/// #[serde(rename_all = "snake_case")]
+/// enum Status {
+///     Open,
+///     #[ck_enum(label = "Work in progress")]
+///     InProgress,
+/// }
+///
+/// // Status::variants() == [("open", "Open"), ("in_progress", "Work in progress")]
+/// ```
+#[proc_macro_derive(CkEnum, attributes(ck_enum, serde))]
+pub fn derive_ck_enum(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    enumeration::expand_derive_ck_enum(ast)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
 /// Generates the `*Id` struct with its `Display` and `crudkit_id::Id` implementations.
 ///
 /// The struct contains only the ID fields of the original struct.
@@ -0,0 +1,52 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+
+use assertr::prelude::*;
+use crudkit_core::{CkEnum, EnumVariant};
+use crudkit_core_macros::CkEnum;
+
+#[derive(Debug, PartialEq, CkEnum, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Open,
+    #[ck_enum(label = "Work in progress")]
+    InProgress,
+    #[serde(rename = "done")]
+    Finished,
+}
+
+#[derive(Debug, PartialEq, CkEnum, serde::Serialize)]
+pub enum Plain {
+    FirstVariant,
+}
+
+fn main() {
+    assert_that(Status::variants()).is_equal_to(
+        &[
+            EnumVariant {
+                name: "open",
+                label: "Open",
+            },
+            EnumVariant {
+                name: "in_progress",
+                label: "Work in progress",
+            },
+            EnumVariant {
+                name: "done",
+                label: "Finished",
+            },
+        ][..],
+    );
+
+    // Variant names match the serialized form.
+    for status in [Status::Open, Status::InProgress, Status::Finished] {
+        let serialized = serde_json::to_string(&status).unwrap();
+        assert_that(serialized).is_equal_to(format!("\"{}\"", status.variant_name()));
+        assert_that(Status::from_variant_name(status.variant_name())).is_equal_to(Some(status));
+    }
+    assert_that(Status::from_variant_name("InProgress")).is_none();
+    assert_that(Status::InProgress.variant_label()).is_equal_to("Work in progress");
+
+    assert_that(Plain::FirstVariant.variant_name()).is_equal_to("FirstVariant");
+    assert_that(Plain::FirstVariant.variant_label()).is_equal_to("First variant");
+}
@@ -16,4 +16,5 @@ fn tests() {
     t.compile_fail("tests/12-error-f32.rs");
     t.compile_fail("tests/13-error-unknown-type.rs");
     t.pass("tests/14-has-id-impl.rs");
+    t.pass("tests/15-ck-enum.rs");
 }
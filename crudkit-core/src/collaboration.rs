@@ -22,6 +22,7 @@ pub enum CollabMessage {
     /// Some entities were validated.
     /// These results should be merged into the already known validation state forming an updated
     /// world view.
+    /// Violations carry their code and message parameters, allowing receivers to translate them.
     PartialValidationResult(PartialSerializableValidations),
 
     /// All entities / resources were validated.
@@ -35,6 +36,9 @@ pub struct EntityCreated {
     pub entity_id: SerializableId,
     /// These can only be non-critical violations, as critical's would have prevented the save.
     pub with_validation_errors: bool,
+    /// Whether infos, which are not counted as errors, exist for the entity.
+    #[serde(default)]
+    pub with_validation_infos: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -43,6 +47,9 @@ pub struct EntityUpdated {
     pub entity_id: SerializableId,
     /// These can only be non-critical violations, as critical's would have prevented the save.
     pub with_validation_errors: bool,
+    /// Whether infos, which are not counted as errors, exist for the entity.
+    #[serde(default)]
+    pub with_validation_infos: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
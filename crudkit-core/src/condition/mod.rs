@@ -0,0 +1,1713 @@
+//! Query filtering DSL with condition clauses and operators.
+
+pub mod evaluate;
+pub mod relative_time;
+pub mod text;
+
+pub use evaluate::{ConditionEvaluationError, ConditionTarget};
+pub use relative_time::{RelativeTime, TimeAnchor};
+pub use text::ConditionParseError;
+
+use crate::enumeration::CkEnum;
+use crate::id::{IdValue, SerializableIdEntry};
+use crate::{TimeDuration, Value, ValueKind};
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use time::format_description::well_known::{Iso8601, Rfc3339};
+use utoipa::ToSchema;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, ToSchema, Serialize, Deserialize)]
+pub enum Operator {
+    #[serde(rename = "=")]
+    Equal,
+    #[serde(rename = "!=")]
+    NotEqual,
+    #[serde(rename = "<")]
+    Less,
+    #[serde(rename = "<=")]
+    LessOrEqual,
+    #[serde(rename = ">")]
+    Greater,
+    #[serde(rename = ">=")]
+    GreaterOrEqual,
+    #[serde(rename = "is_in")]
+    IsIn,
+    /// Negated `IsIn`. Requires an array value.
+    #[serde(rename = "not_in")]
+    NotIn,
+    /// Inclusive range check. Requires an array value of exactly two elements: `[low, high]`.
+    #[serde(rename = "between")]
+    Between,
+    /// The column is `NULL`. The clause value is ignored.
+    #[serde(rename = "is_null")]
+    IsNull,
+    /// The column is not `NULL`. The clause value is ignored.
+    #[serde(rename = "is_not_null")]
+    IsNotNull,
+    /// Substring match. Requires a string value.
+    #[serde(rename = "contains")]
+    Contains,
+    /// Case-insensitive substring match. Requires a string value.
+    #[serde(rename = "contains_ignore_case")]
+    ContainsIgnoreCase,
+    /// Prefix match. Requires a string value.
+    #[serde(rename = "starts_with")]
+    StartsWith,
+    /// Suffix match. Requires a string value.
+    #[serde(rename = "ends_with")]
+    EndsWith,
+    /// Case-insensitive equality. Requires a string value.
+    #[serde(rename = "eq_ignore_case")]
+    EqualIgnoreCase,
+    /// The Json document (at the clause's `json_path`) contains the given JSON value, as defined
+    /// by the Postgres `@>` operator. An array contains a scalar if it has it as an element.
+    #[serde(rename = "json_contains")]
+    JsonContains,
+    /// The array column contains all given values. Accepts a single value or a list.
+    #[serde(rename = "array_contains")]
+    ArrayContains,
+    /// The array column shares at least one element with the given values. Accepts a single
+    /// value or a list.
+    #[serde(rename = "array_overlaps")]
+    ArrayOverlaps,
+    /// The number of elements of the array column equals the given integer, or lies within an
+    /// inclusive `[min, max]` list of two integers.
+    #[serde(rename = "array_length")]
+    ArrayLength,
+}
+
+impl Operator {
+    /// Whether this operator ignores the clause value and only inspects the column itself.
+    pub fn is_null_check(&self) -> bool {
+        matches!(self, Operator::IsNull | Operator::IsNotNull)
+    }
+
+    /// Whether this operator expects a list of values (`ConditionClauseValue::*Vec`).
+    pub fn expects_list(&self) -> bool {
+        matches!(self, Operator::IsIn | Operator::NotIn | Operator::Between)
+    }
+
+    /// Whether this operator only works on array columns.
+    pub fn is_array_operator(&self) -> bool {
+        matches!(
+            self,
+            Operator::ArrayContains | Operator::ArrayOverlaps | Operator::ArrayLength
+        )
+    }
+
+    /// Whether this operator only works on textual values.
+    pub fn is_text_operator(&self) -> bool {
+        matches!(
+            self,
+            Operator::Contains
+                | Operator::ContainsIgnoreCase
+                | Operator::StartsWith
+                | Operator::EndsWith
+                | Operator::EqualIgnoreCase
+        )
+    }
+
+    /// The `LIKE` pattern of a `contains`, `contains_ignore_case`, `starts_with` or `ends_with`
+    /// clause on `value`, escaping [`LIKE_ESCAPE`], `%` and `_` in `value` so that it matches
+    /// literally. The value is lowercased for `contains_ignore_case`.
+    pub fn like_pattern(&self, value: &str) -> Option<String> {
+        let escaped = |value: &str| {
+            let mut escaped = String::with_capacity(value.len());
+            for c in value.chars() {
+                if matches!(c, LIKE_ESCAPE | '%' | '_') {
+                    escaped.push(LIKE_ESCAPE);
+                }
+                escaped.push(c);
+            }
+            escaped
+        };
+        match self {
+            Operator::Contains => Some(format!("%{}%", escaped(value))),
+            Operator::ContainsIgnoreCase => Some(format!("%{}%", escaped(&value.to_lowercase()))),
+            Operator::StartsWith => Some(format!("{}%", escaped(value))),
+            Operator::EndsWith => Some(format!("%{}", escaped(value))),
+            _ => None,
+        }
+    }
+}
+
+/// The escape character of the patterns built by [`Operator::like_pattern`].
+pub const LIKE_ESCAPE: char = '\\';
+
+#[derive(Debug, Clone, PartialEq, ToSchema, Serialize, Deserialize)]
+pub struct ConditionClause {
+    pub column_name: String,
+
+    /// Keys leading to a nested value of the Json column `column_name`, e.g.
+    /// `["customer", "country"]`. Empty if the clause applies to the column itself.
+    ///
+    /// Values at a path are compared as JSON, so the clause value is converted to JSON (see
+    /// [`ConditionClauseValue::to_json`]) instead of to the column's type. Missing keys are
+    /// null. Text operators and null checks treat the value as text, JSON null being null.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub json_path: Vec<String>,
+
+    pub operator: Operator,
+    pub value: ConditionClauseValue,
+}
+
+impl ConditionClause {
+    /// Whether this clause compares JSON instead of a value of the column's type.
+    pub fn is_json_clause(&self) -> bool {
+        !self.json_path.is_empty() || self.operator == Operator::JsonContains
+    }
+}
+
+/// Values which might be part of a `ConditionClause`.
+/// You can convert a `crudkit_core::Value` using `.try_into`.
+/// You can convert a `crudkit_core::id::IdValue` using `.try_into`.
+#[derive(Debug, Clone, PartialEq, ToSchema, Serialize, Deserialize)]
+pub enum ConditionClauseValue {
+    Bool(bool),
+
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+
+    F32(f32),
+    F64(f64),
+    Decimal(rust_decimal::Decimal),
+
+    String(String),
+    Json(serde_json::Value),
+
+    Uuid(uuid::Uuid),
+
+    PrimitiveDateTime(time::PrimitiveDateTime),
+    OffsetDateTime(time::OffsetDateTime),
+    /// Compared against date-time columns as the start of that day.
+    Date(time::Date),
+    #[schema(value_type = String)]
+    Time(time::Time),
+    Duration(TimeDuration),
+    /// A point in time relative to the execution of the condition, e.g. `now - P7D`.
+    /// Must be resolved (see `Condition::resolve_relative_times`) before the value is converted.
+    RelativeTime(RelativeTime),
+    /// A value of the request executing the condition, written `$name`, e.g. `$tenant_id`.
+    /// Holds the name without the `$`. Must be resolved (see `Condition::resolve_placeholders`)
+    /// before the value is converted.
+    Placeholder(String),
+
+    U8Vec(Vec<u8>),
+    I32Vec(Vec<i32>),
+    I64Vec(Vec<i64>),
+    UuidVec(Vec<uuid::Uuid>),
+    StringVec(Vec<String>),
+}
+
+macro_rules! impl_from_for_condition_clause_value {
+    ($($ty:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$ty> for ConditionClauseValue {
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_for_condition_clause_value!(
+    bool => Bool,
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    u128 => U128,
+    i8 => I8,
+    i16 => I16,
+    i32 => I32,
+    i64 => I64,
+    i128 => I128,
+    f32 => F32,
+    f64 => F64,
+    rust_decimal::Decimal => Decimal,
+    String => String,
+    serde_json::Value => Json,
+    uuid::Uuid => Uuid,
+    time::PrimitiveDateTime => PrimitiveDateTime,
+    time::OffsetDateTime => OffsetDateTime,
+    time::Date => Date,
+    time::Time => Time,
+    TimeDuration => Duration,
+    RelativeTime => RelativeTime,
+    Vec<u8> => U8Vec,
+    Vec<i32> => I32Vec,
+    Vec<i64> => I64Vec,
+    Vec<uuid::Uuid> => UuidVec,
+    Vec<String> => StringVec,
+);
+
+impl From<time::Duration> for ConditionClauseValue {
+    fn from(value: time::Duration) -> Self {
+        Self::Duration(TimeDuration(value))
+    }
+}
+
+impl From<&str> for ConditionClauseValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("The value '{value:?}' cannot be used in a condition clause.")]
+pub struct NotConditionClauseCompatibleValue {
+    value: Value,
+}
+
+impl TryFrom<Value> for ConditionClauseValue {
+    type Error = NotConditionClauseCompatibleValue;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            value @ Value::Null => Err(NotConditionClauseCompatibleValue { value }),
+            value @ Value::Void(()) => Err(NotConditionClauseCompatibleValue { value }),
+
+            Value::Bool(value) => Ok(Self::Bool(value)),
+
+            Value::I8(value) => Ok(Self::I8(value)),
+            Value::I16(value) => Ok(Self::I16(value)),
+            Value::I32(value) => Ok(Self::I32(value)),
+            Value::I64(value) => Ok(Self::I64(value)),
+            Value::I128(value) => Ok(Self::I128(value)),
+
+            Value::U8(value) => Ok(Self::U8(value)),
+            Value::U16(value) => Ok(Self::U16(value)),
+            Value::U32(value) => Ok(Self::U32(value)),
+            Value::U64(value) => Ok(Self::U64(value)),
+            Value::U128(value) => Ok(Self::U128(value)),
+
+            Value::F32(value) => Ok(Self::F32(value)),
+            Value::F64(value) => Ok(Self::F64(value)),
+
+            Value::String(value) => Ok(Self::String(value)),
+            Value::Enum(value) => Ok(Self::String(value)),
+
+            // Ecosystem support.
+            Value::Json(value) => Ok(Self::Json(value)),
+            Value::Decimal(value) => Ok(Self::Decimal(value)),
+            Value::Uuid(value) => Ok(Self::Uuid(value)),
+
+            Value::PrimitiveDateTime(value) => Ok(Self::PrimitiveDateTime(value)),
+            Value::OffsetDateTime(value) => Ok(Self::OffsetDateTime(value)),
+            Value::Date(value) => Ok(Self::Date(value)),
+            Value::Time(value) => Ok(Self::Time(value)),
+            Value::Duration(value) => Ok(Self::Duration(value)),
+
+            Value::Array(values) => array_to_condition_clause_value(values)
+                .map_err(|value| NotConditionClauseCompatibleValue { value }),
+            value @ Value::Other(_) => Err(NotConditionClauseCompatibleValue { value }),
+        }
+    }
+}
+
+/// Converts an array of values into one of the list variants.
+/// Gives back the array (as `Value::Array`) if no list variant matches its elements.
+fn array_to_condition_clause_value(values: Vec<Value>) -> Result<ConditionClauseValue, Value> {
+    fn collect<T>(
+        values: &[Value],
+        extract: impl Fn(&Value) -> Option<T>,
+        wrap: impl FnOnce(Vec<T>) -> ConditionClauseValue,
+    ) -> Option<ConditionClauseValue> {
+        values
+            .iter()
+            .map(extract)
+            .collect::<Option<Vec<T>>>()
+            .map(wrap)
+    }
+
+    let converted = match values.first() {
+        Some(Value::U8(_)) => collect(&values, Value::as_u8, ConditionClauseValue::U8Vec),
+        Some(Value::I32(_)) => collect(&values, Value::as_i32, ConditionClauseValue::I32Vec),
+        Some(Value::I64(_)) => collect(&values, Value::as_i64, ConditionClauseValue::I64Vec),
+        Some(Value::Uuid(_)) => collect(&values, Value::as_uuid, ConditionClauseValue::UuidVec),
+        Some(Value::String(_)) => collect(
+            &values,
+            |value| value.as_string().cloned(),
+            ConditionClauseValue::StringVec,
+        ),
+        Some(Value::Enum(_)) => collect(
+            &values,
+            |value| value.as_enum().cloned(),
+            ConditionClauseValue::StringVec,
+        ),
+        _ => None,
+    };
+    converted.ok_or(Value::Array(values))
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("The ID value '{value:?}' cannot be used in a condition clause.")]
+pub struct NotConditionClauseCompatibleIdValue {
+    value: IdValue,
+}
+
+impl TryFrom<IdValue> for ConditionClauseValue {
+    type Error = NotConditionClauseCompatibleIdValue;
+
+    fn try_from(value: IdValue) -> Result<Self, Self::Error> {
+        match value {
+            IdValue::I8(value) => Ok(Self::I8(value)),
+            IdValue::I16(value) => Ok(Self::I16(value)),
+            IdValue::I32(value) => Ok(Self::I32(value)),
+            IdValue::I64(value) => Ok(Self::I64(value)),
+            IdValue::I128(value) => Ok(Self::I128(value)),
+            IdValue::U8(value) => Ok(Self::U8(value)),
+            IdValue::U16(value) => Ok(Self::U16(value)),
+            IdValue::U32(value) => Ok(Self::U32(value)),
+            IdValue::U64(value) => Ok(Self::U64(value)),
+            IdValue::U128(value) => Ok(Self::U128(value)),
+            IdValue::Bool(value) => Ok(Self::Bool(value)),
+            IdValue::String(value) => Ok(Self::String(value)),
+            IdValue::Uuid(value) => Ok(Self::Uuid(value)),
+            IdValue::PrimitiveDateTime(value) => Ok(Self::PrimitiveDateTime(value)),
+            IdValue::OffsetDateTime(value) => Ok(Self::OffsetDateTime(value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, ToSchema, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ConditionElement {
+    Clause(ConditionClause),
+
+    #[schema(no_recursion)]
+    Condition(Box<Condition>),
+}
+
+impl From<ConditionClause> for ConditionElement {
+    fn from(clause: ConditionClause) -> Self {
+        Self::Clause(clause)
+    }
+}
+
+impl From<Condition> for ConditionElement {
+    /// Single-clause `All`/`Any` conditions are unwrapped to their clause.
+    fn from(condition: Condition) -> Self {
+        match condition {
+            Condition::All(mut elements) | Condition::Any(mut elements)
+                if elements.len() == 1 && matches!(elements[0], ConditionElement::Clause(_)) =>
+            {
+                elements.remove(0)
+            }
+            condition => Self::Condition(Box::new(condition)),
+        }
+    }
+}
+
+/// A query condition combining multiple elements with AND (`All`) or OR (`Any`) logic,
+/// or negating another condition (`Not`).
+///
+/// # JSON Format
+///
+/// Uses externally tagged serialization for unambiguous deserialization:
+/// - `{"All": [...]}`  - All elements must match (AND)
+/// - `{"Any": [...]}`  - Any element must match (OR)
+/// - `{"Not": {...}}`  - The nested condition must not match (NOT)
+///
+/// # Example
+///
+/// ```json
+/// {
+///   "Any": [
+///     {"All": [{"column_name": "id", "operator": "=", "value": {"I64": 1}}]},
+///     {"All": [{"column_name": "id", "operator": "=", "value": {"I64": 2}}]}
+///   ]
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, ToSchema, Serialize, Deserialize)]
+pub enum Condition {
+    /// All elements must match (logical AND).
+    All(Vec<ConditionElement>),
+
+    /// Any element must match (logical OR).
+    Any(Vec<ConditionElement>),
+
+    /// The nested condition must not match (logical NOT).
+    #[schema(no_recursion)]
+    Not(Box<Condition>),
+}
+
+impl Condition {
+    /// Creates an empty `All` condition.
+    ///
+    /// An empty `All` condition matches everything (vacuous truth).
+    pub fn all() -> Self {
+        Self::All(Vec::new())
+    }
+
+    /// Creates an empty `Any` condition.
+    ///
+    /// An empty `Any` condition matches nothing (no element can satisfy it).
+    pub fn any() -> Self {
+        Self::Any(Vec::new())
+    }
+
+    /// Creates a condition that matches nothing.
+    ///
+    /// This is a semantic alias for [`Self::any()`] - an empty OR condition.
+    ///
+    /// Useful when building conditions dynamically and you need a neutral starting
+    /// point that won't match any entities (e.g., when no entities are selected
+    /// for a bulk operation).
+    pub fn none() -> Self {
+        Self::any()
+    }
+
+    /// Creates a condition matching exactly what `condition` does not match.
+    pub fn negate(condition: Condition) -> Self {
+        Self::Not(Box::new(condition))
+    }
+
+    /// The elements to append to. A `Not` condition is first wrapped in an `All` condition,
+    /// leaving the negated condition untouched.
+    fn elements_mut(&mut self) -> &mut Vec<ConditionElement> {
+        if let Condition::Not(_) = self {
+            let negation = std::mem::replace(self, Condition::all());
+            *self = Condition::All(vec![negation.into()]);
+        }
+        match self {
+            Condition::All(vec) | Condition::Any(vec) => vec,
+            Condition::Not(_) => unreachable!("Not conditions were wrapped above"),
+        }
+    }
+
+    /// Appends `elements`. A `Not` condition becomes `All[Not(..), elements..]`.
+    pub fn push_elements(&mut self, mut elements: Vec<ConditionElement>) {
+        self.elements_mut().append(&mut elements);
+    }
+
+    /// Appends a nested condition. A `Not` condition becomes `All[Not(..), condition]`.
+    pub fn push_condition(&mut self, condition: Condition) {
+        self.elements_mut()
+            .push(ConditionElement::Condition(Box::new(condition)));
+    }
+
+    /// Whether this is an `All` or `Any` condition without elements.
+    /// A `Not` condition is never empty, as it still restricts what matches.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Condition::All(vec) | Condition::Any(vec) => vec.is_empty(),
+            Condition::Not(_) => false,
+        }
+    }
+
+    /// Calls `f` with every clause of this condition, including the clauses of nested conditions.
+    pub fn for_each_clause_mut(&mut self, f: &mut impl FnMut(&mut ConditionClause)) {
+        match self {
+            Condition::All(elements) | Condition::Any(elements) => {
+                for element in elements {
+                    match element {
+                        ConditionElement::Clause(clause) => f(clause),
+                        ConditionElement::Condition(nested) => nested.for_each_clause_mut(f),
+                    }
+                }
+            }
+            Condition::Not(inner) => inner.for_each_clause_mut(f),
+        }
+    }
+
+    /// Replaces all placeholders for which `resolve` returns a value. `resolve` is called with
+    /// the placeholder name, without the leading `$`.
+    ///
+    /// Unknown placeholders are left untouched and fail to convert later on.
+    pub fn resolve_placeholders(
+        &mut self,
+        mut resolve: impl FnMut(&str) -> Option<ConditionClauseValue>,
+    ) {
+        self.for_each_clause_mut(&mut |clause| {
+            let resolved = match &clause.value {
+                ConditionClauseValue::Placeholder(name) => resolve(name),
+                _ => None,
+            };
+            if let Some(resolved) = resolved {
+                clause.value = resolved;
+            }
+        });
+    }
+}
+
+// =============================================================================
+// Fluent builder
+// =============================================================================
+
+/// Placeholder value for clauses whose operator ignores the value (`is_null`, `is_not_null`).
+const IGNORED_VALUE: ConditionClauseValue = ConditionClauseValue::Bool(true);
+
+impl Condition {
+    /// Appends a clause to this condition.
+    ///
+    /// ```
+    /// use crudkit_core::condition::{Condition, Operator};
+    ///
+    /// let condition = Condition::all()
+    ///     .clause("age", Operator::GreaterOrEqual, 18)
+    ///     .eq("name", "Bob");
+    /// ```
+    pub fn clause(
+        mut self,
+        column_name: impl Into<String>,
+        operator: Operator,
+        value: impl Into<ConditionClauseValue>,
+    ) -> Self {
+        self.push_elements(vec![ConditionElement::Clause(ConditionClause {
+            column_name: column_name.into(),
+            json_path: Vec::new(),
+            operator,
+            value: value.into(),
+        })]);
+        self
+    }
+
+    /// Appends a clause on the value at `path` inside the Json column `column_name`.
+    ///
+    /// ```
+    /// use crudkit_core::condition::{Condition, Operator};
+    ///
+    /// // metadata.customer.country = "DE"
+    /// let condition =
+    ///     Condition::all().json_path("metadata", ["customer", "country"], Operator::Equal, "DE");
+    /// ```
+    pub fn json_path<P: Into<String>>(
+        mut self,
+        column_name: impl Into<String>,
+        path: impl IntoIterator<Item = P>,
+        operator: Operator,
+        value: impl Into<ConditionClauseValue>,
+    ) -> Self {
+        self.push_elements(vec![ConditionElement::Clause(ConditionClause {
+            column_name: column_name.into(),
+            json_path: path.into_iter().map(Into::into).collect(),
+            operator,
+            value: value.into(),
+        })]);
+        self
+    }
+
+    pub fn eq(self, column_name: impl Into<String>, value: impl Into<ConditionClauseValue>) -> Self {
+        self.clause(column_name, Operator::Equal, value)
+    }
+
+    pub fn ne(self, column_name: impl Into<String>, value: impl Into<ConditionClauseValue>) -> Self {
+        self.clause(column_name, Operator::NotEqual, value)
+    }
+
+    pub fn lt(self, column_name: impl Into<String>, value: impl Into<ConditionClauseValue>) -> Self {
+        self.clause(column_name, Operator::Less, value)
+    }
+
+    pub fn lte(self, column_name: impl Into<String>, value: impl Into<ConditionClauseValue>) -> Self {
+        self.clause(column_name, Operator::LessOrEqual, value)
+    }
+
+    pub fn gt(self, column_name: impl Into<String>, value: impl Into<ConditionClauseValue>) -> Self {
+        self.clause(column_name, Operator::Greater, value)
+    }
+
+    pub fn gte(self, column_name: impl Into<String>, value: impl Into<ConditionClauseValue>) -> Self {
+        self.clause(column_name, Operator::GreaterOrEqual, value)
+    }
+
+    /// `values` must be a list value, e.g. a `Vec<i64>`.
+    pub fn in_(self, column_name: impl Into<String>, values: impl Into<ConditionClauseValue>) -> Self {
+        self.clause(column_name, Operator::IsIn, values)
+    }
+
+    /// `values` must be a list value, e.g. a `Vec<i64>`.
+    pub fn not_in(
+        self,
+        column_name: impl Into<String>,
+        values: impl Into<ConditionClauseValue>,
+    ) -> Self {
+        self.clause(column_name, Operator::NotIn, values)
+    }
+
+    /// Inclusive range check.
+    pub fn between<V>(self, column_name: impl Into<String>, low: V, high: V) -> Self
+    where
+        Vec<V>: Into<ConditionClauseValue>,
+    {
+        self.clause(column_name, Operator::Between, vec![low, high])
+    }
+
+    pub fn is_null(self, column_name: impl Into<String>) -> Self {
+        self.clause(column_name, Operator::IsNull, IGNORED_VALUE)
+    }
+
+    pub fn is_not_null(self, column_name: impl Into<String>) -> Self {
+        self.clause(column_name, Operator::IsNotNull, IGNORED_VALUE)
+    }
+
+    pub fn contains(self, column_name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.clause(column_name, Operator::Contains, value.into())
+    }
+
+    pub fn contains_ignore_case(
+        self,
+        column_name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.clause(column_name, Operator::ContainsIgnoreCase, value.into())
+    }
+
+    pub fn starts_with(self, column_name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.clause(column_name, Operator::StartsWith, value.into())
+    }
+
+    pub fn ends_with(self, column_name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.clause(column_name, Operator::EndsWith, value.into())
+    }
+
+    pub fn eq_ignore_case(self, column_name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.clause(column_name, Operator::EqualIgnoreCase, value.into())
+    }
+
+    /// The Json column contains `value`, e.g. `json!({"tags": ["urgent"]})`.
+    pub fn json_contains(
+        self,
+        column_name: impl Into<String>,
+        value: impl Into<ConditionClauseValue>,
+    ) -> Self {
+        self.clause(column_name, Operator::JsonContains, value)
+    }
+
+    /// The array column contains all of `values`, e.g. `vec!["urgent", "bug"]`.
+    pub fn array_contains(
+        self,
+        column_name: impl Into<String>,
+        values: impl Into<ConditionClauseValue>,
+    ) -> Self {
+        self.clause(column_name, Operator::ArrayContains, values)
+    }
+
+    /// The array column contains at least one of `values`.
+    pub fn array_overlaps(
+        self,
+        column_name: impl Into<String>,
+        values: impl Into<ConditionClauseValue>,
+    ) -> Self {
+        self.clause(column_name, Operator::ArrayOverlaps, values)
+    }
+
+    /// The array column has exactly `length` elements.
+    pub fn array_length(self, column_name: impl Into<String>, length: i64) -> Self {
+        self.clause(column_name, Operator::ArrayLength, length)
+    }
+
+    /// Combines this condition and `other` so that both must match.
+    ///
+    /// If `self` already is an `All` condition, `other` is appended to it instead of nesting.
+    pub fn and(self, other: Condition) -> Self {
+        match self {
+            Condition::All(mut elements) => {
+                elements.push(other.into());
+                Condition::All(elements)
+            }
+            this => Condition::All(vec![this.into(), other.into()]),
+        }
+    }
+
+    /// Combines this condition and `other` so that either one must match.
+    ///
+    /// If `self` already is an `Any` condition, `other` is appended to it instead of nesting.
+    pub fn or(self, other: Condition) -> Self {
+        match self {
+            Condition::Any(mut elements) => {
+                elements.push(other.into());
+                Condition::Any(elements)
+            }
+            this => Condition::Any(vec![this.into(), other.into()]),
+        }
+    }
+}
+
+/// A field name carrying the Rust type of the field's values.
+///
+/// The `CkField` derives generate one of these per field as an associated constant on the field
+/// enum, e.g. `ArticleField::TITLE`, so that clauses are type-checked at compile time:
+///
+/// ```
+/// use crudkit_core::condition::{Condition, TypedField};
+///
+/// const TITLE: TypedField<String> = TypedField::new("title");
+/// const ID: TypedField<i64> = TypedField::new("id");
+///
+/// let condition: Condition = TITLE.eq("x").and(ID.in_([1, 2, 3]));
+/// ```
+pub struct TypedField<T> {
+    name: &'static str,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for TypedField<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TypedField<T> {}
+
+impl<T> Debug for TypedField<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("TypedField").field(&self.name).finish()
+    }
+}
+
+impl<T> TypedField<T> {
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            phantom: PhantomData,
+        }
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn is_null(self) -> Condition {
+        Condition::all().is_null(self.name)
+    }
+
+    pub fn is_not_null(self) -> Condition {
+        Condition::all().is_not_null(self.name)
+    }
+}
+
+impl<T: Into<ConditionClauseValue>> TypedField<T> {
+    pub fn eq(self, value: impl Into<T>) -> Condition {
+        Condition::all().eq(self.name, value.into())
+    }
+
+    pub fn ne(self, value: impl Into<T>) -> Condition {
+        Condition::all().ne(self.name, value.into())
+    }
+
+    pub fn lt(self, value: impl Into<T>) -> Condition {
+        Condition::all().lt(self.name, value.into())
+    }
+
+    pub fn lte(self, value: impl Into<T>) -> Condition {
+        Condition::all().lte(self.name, value.into())
+    }
+
+    pub fn gt(self, value: impl Into<T>) -> Condition {
+        Condition::all().gt(self.name, value.into())
+    }
+
+    pub fn gte(self, value: impl Into<T>) -> Condition {
+        Condition::all().gte(self.name, value.into())
+    }
+}
+
+impl<T> TypedField<T>
+where
+    Vec<T>: Into<ConditionClauseValue>,
+{
+    pub fn in_<V: Into<T>>(self, values: impl IntoIterator<Item = V>) -> Condition {
+        let values = values.into_iter().map(Into::into).collect::<Vec<T>>();
+        Condition::all().in_(self.name, values)
+    }
+
+    pub fn not_in<V: Into<T>>(self, values: impl IntoIterator<Item = V>) -> Condition {
+        let values = values.into_iter().map(Into::into).collect::<Vec<T>>();
+        Condition::all().not_in(self.name, values)
+    }
+
+    pub fn between(self, low: impl Into<T>, high: impl Into<T>) -> Condition {
+        Condition::all().between(self.name, low.into(), high.into())
+    }
+}
+
+impl<E> TypedField<Vec<E>>
+where
+    Vec<E>: Into<ConditionClauseValue>,
+{
+    pub fn array_contains<V: Into<E>>(self, values: impl IntoIterator<Item = V>) -> Condition {
+        let values = values.into_iter().map(Into::into).collect::<Vec<E>>();
+        Condition::all().array_contains(self.name, values)
+    }
+
+    pub fn array_overlaps<V: Into<E>>(self, values: impl IntoIterator<Item = V>) -> Condition {
+        let values = values.into_iter().map(Into::into).collect::<Vec<E>>();
+        Condition::all().array_overlaps(self.name, values)
+    }
+
+    pub fn array_length(self, length: i64) -> Condition {
+        Condition::all().array_length(self.name, length)
+    }
+}
+
+impl TypedField<String> {
+    pub fn contains(self, value: impl Into<String>) -> Condition {
+        Condition::all().contains(self.name, value)
+    }
+
+    pub fn contains_ignore_case(self, value: impl Into<String>) -> Condition {
+        Condition::all().contains_ignore_case(self.name, value)
+    }
+
+    pub fn starts_with(self, value: impl Into<String>) -> Condition {
+        Condition::all().starts_with(self.name, value)
+    }
+
+    pub fn ends_with(self, value: impl Into<String>) -> Condition {
+        Condition::all().ends_with(self.name, value)
+    }
+
+    pub fn eq_ignore_case(self, value: impl Into<String>) -> Condition {
+        Condition::all().eq_ignore_case(self.name, value)
+    }
+}
+
+impl std::ops::Not for Condition {
+    type Output = Condition;
+
+    fn not(self) -> Self::Output {
+        Condition::negate(self)
+    }
+}
+
+// TODO: This always "AND"s them together. Are there places where an "OR" would be equally appropriate?
+pub fn merge_conditions(a: Option<Condition>, b: Option<Condition>) -> Option<Condition> {
+    match (a, b) {
+        (None, None) => None,
+        (None, Some(b)) => Some(b),
+        (Some(a), None) => Some(a),
+        (Some(a), Some(b)) => {
+            let mut combined = Condition::all();
+            combined.push_condition(a);
+            combined.push_condition(b);
+            match combined.is_empty() {
+                true => None,
+                false => Some(combined),
+            }
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Could not build all-equal-condition.")]
+pub struct IntoAllEqualConditionError;
+
+pub trait TryIntoAllEqualCondition {
+    type Error;
+
+    fn try_into_all_equal_condition(self) -> Result<Condition, Self::Error>;
+}
+
+impl<I> TryIntoAllEqualCondition for I
+where
+    I: Iterator<Item = SerializableIdEntry>,
+{
+    type Error = IntoAllEqualConditionError;
+
+    fn try_into_all_equal_condition(self) -> Result<Condition, Self::Error> {
+        let mut clauses = Vec::new();
+
+        for SerializableIdEntry { field_name, value } in self {
+            let clause = ConditionElement::Clause(ConditionClause {
+                column_name: field_name,
+                json_path: Vec::new(),
+                operator: Operator::Equal,
+                value: value
+                    .clone()
+                    .try_into()
+                    .map_err(|_| IntoAllEqualConditionError)?,
+            });
+
+            clauses.push(clause);
+        }
+
+        Ok(Condition::All(clauses))
+    }
+}
+
+/// Generates `to_<int>` conversions accepting any integer variant or list of integers whose
+/// values fit (lists are converted to `Value::Array`) and a parsable `String`.
+macro_rules! impl_to_integer {
+    ($($fn_name:ident, $variant:ident, $ty:ty);* $(;)?) => {
+        $(
+            pub fn $fn_name(self) -> Result<Value, String> {
+                fn convert(num: i128) -> Result<Value, String> {
+                    <$ty>::try_from(num).map(Value::$variant).map_err(|_| {
+                        format!(concat!("{} does not fit into an ", stringify!($ty), "."), num)
+                    })
+                }
+
+                match self {
+                    ConditionClauseValue::$variant(num) => Ok(Value::$variant(num)),
+                    ConditionClauseValue::String(string) => {
+                        parse::<$ty>(&string).map(Value::$variant)
+                    }
+                    other => match (other.as_integer(), other.as_integer_list()) {
+                        (Some(num), _) => convert(num),
+                        (None, Some(numbers)) => numbers
+                            .into_iter()
+                            .map(convert)
+                            .collect::<Result<Vec<_>, _>>()
+                            .map(Value::Array),
+                        (None, None) => Err(format!(
+                            concat!(
+                                "{:?} can not be converted to an ",
+                                stringify!($ty),
+                                ". Expected an integer, a list of integers or String."
+                            ),
+                            other
+                        )),
+                    },
+                }
+            }
+        )*
+    };
+}
+
+impl ConditionClauseValue {
+    /// Converts this clause value to a value of the given kind, using the matching `to_*` method.
+    ///
+    /// This is the conversion the `CkField` derives generate for fields of that kind.
+    pub fn into_value_of_kind(self, kind: ValueKind) -> Result<Value, String> {
+        match kind {
+            ValueKind::Bool => self.to_bool(),
+            ValueKind::U8 => self.to_u8(),
+            ValueKind::U16 => self.to_u16(),
+            ValueKind::U32 => self.to_u32(),
+            ValueKind::U64 => self.to_u64(),
+            ValueKind::U128 => self.to_u128(),
+            ValueKind::I8 => self.to_i8(),
+            ValueKind::I16 => self.to_i16(),
+            ValueKind::I32 => self.to_i32(),
+            ValueKind::I64 => self.to_i64(),
+            ValueKind::I128 => self.to_i128(),
+            ValueKind::F32 => self.to_f32(),
+            ValueKind::F64 => self.to_f64(),
+            ValueKind::Decimal => self.to_decimal(),
+            ValueKind::String => self.to_string(),
+            ValueKind::Enum => self.to_enum_name(),
+            ValueKind::Json => self.to_json_value(),
+            ValueKind::Uuid => self.to_uuid(),
+            ValueKind::PrimitiveDateTime => self.to_primitive_date_time(),
+            ValueKind::OffsetDateTime => self.to_offset_date_time(),
+            ValueKind::Date => self.to_date(),
+            ValueKind::Time => self.to_time(),
+            ValueKind::Duration => self.to_time_duration(),
+            ValueKind::Null | ValueKind::Void | ValueKind::Array | ValueKind::Other => Err(
+                format!("{self:?} can not be converted to a value of kind {kind:?}."),
+            ),
+        }
+    }
+
+    /// Converts this clause value to a `Value::Array`, converting its elements with `convert`.
+    /// A single value becomes an array of one element.
+    ///
+    /// This is the conversion the `CkField` derives generate for `Vec` fields.
+    pub fn to_array(
+        self,
+        convert: impl FnOnce(Self) -> Result<Value, String>,
+    ) -> Result<Value, String> {
+        match convert(self)? {
+            Value::Array(values) => Ok(Value::Array(values)),
+            value => Ok(Value::Array(vec![value])),
+        }
+    }
+
+    /// Like [`ConditionClauseValue::to_array`], converting the elements to values of
+    /// `element_kind`.
+    pub fn into_array_of_kind(self, element_kind: ValueKind) -> Result<Value, String> {
+        self.to_array(|value| value.into_value_of_kind(element_kind))
+    }
+
+    /// Converts the value of an `array_length` clause: an exact length (`Value::I64`) or an
+    /// inclusive `[min, max]` range (`Value::Array` of two `Value::I64`).
+    pub fn to_array_length(self) -> Result<Value, String> {
+        match self.to_i64()? {
+            Value::Array(bounds) if bounds.len() != 2 => Err(format!(
+                "Expected a length or a [min, max] range of lengths, but got {} values.",
+                bounds.len()
+            )),
+            value => Ok(value),
+        }
+    }
+
+    impl_to_integer! {
+        to_u8, U8, u8;
+        to_u16, U16, u16;
+        to_u32, U32, u32;
+        to_u64, U64, u64;
+        to_u128, U128, u128;
+        to_i8, I8, i8;
+        to_i16, I16, i16;
+        to_i32, I32, i32;
+        to_i64, I64, i64;
+        to_i128, I128, i128;
+    }
+
+    /// The value of any integer variant, widened to `i128`.
+    fn as_integer(&self) -> Option<i128> {
+        match *self {
+            ConditionClauseValue::U8(num) => Some(num.into()),
+            ConditionClauseValue::U16(num) => Some(num.into()),
+            ConditionClauseValue::U32(num) => Some(num.into()),
+            ConditionClauseValue::U64(num) => Some(num.into()),
+            ConditionClauseValue::U128(num) => i128::try_from(num).ok(),
+            ConditionClauseValue::I8(num) => Some(num.into()),
+            ConditionClauseValue::I16(num) => Some(num.into()),
+            ConditionClauseValue::I32(num) => Some(num.into()),
+            ConditionClauseValue::I64(num) => Some(num.into()),
+            ConditionClauseValue::I128(num) => Some(num),
+            _ => None,
+        }
+    }
+
+    /// The values of any integer list variant, widened to `i128`.
+    fn as_integer_list(&self) -> Option<Vec<i128>> {
+        match self {
+            ConditionClauseValue::U8Vec(numbers) => {
+                Some(numbers.iter().map(|&n| n.into()).collect())
+            }
+            ConditionClauseValue::I32Vec(numbers) => {
+                Some(numbers.iter().map(|&n| n.into()).collect())
+            }
+            ConditionClauseValue::I64Vec(numbers) => {
+                Some(numbers.iter().map(|&n| n.into()).collect())
+            }
+            _ => None,
+        }
+    }
+
+    // TODO: All these to functions support string->type parsing. Should this be removed and made explicit?
+    pub fn to_f32(self) -> Result<Value, String> {
+        match self {
+            ConditionClauseValue::F32(num) => Ok(Value::F32(num)),
+            ConditionClauseValue::F64(num) => Ok(Value::F32(num as f32)),
+            ConditionClauseValue::String(string) => parse::<f32>(&string).map(Value::F32),
+            other => match (other.as_integer(), other.as_integer_list()) {
+                (Some(num), _) => Ok(Value::F32(num as f32)),
+                (None, Some(numbers)) => Ok(Value::Array(
+                    numbers
+                        .into_iter()
+                        .map(|num| Value::F32(num as f32))
+                        .collect(),
+                )),
+                (None, None) => Err(format!(
+                    "{other:?} can not be converted to an f32. Expected a number or String."
+                )),
+            },
+        }
+    }
+
+    pub fn to_f64(self) -> Result<Value, String> {
+        match self {
+            ConditionClauseValue::F64(num) => Ok(Value::F64(num)),
+            ConditionClauseValue::F32(num) => Ok(Value::F64(num.into())),
+            ConditionClauseValue::String(string) => parse::<f64>(&string).map(Value::F64),
+            other => match (other.as_integer(), other.as_integer_list()) {
+                (Some(num), _) => Ok(Value::F64(num as f64)),
+                (None, Some(numbers)) => Ok(Value::Array(
+                    numbers
+                        .into_iter()
+                        .map(|num| Value::F64(num as f64))
+                        .collect(),
+                )),
+                (None, None) => Err(format!(
+                    "{other:?} can not be converted to an f64. Expected a number or String."
+                )),
+            },
+        }
+    }
+
+    /// Floats are converted to the decimal closest to them. Prefer strings or decimals for exact
+    /// values.
+    pub fn to_decimal(self) -> Result<Value, String> {
+        use rust_decimal::Decimal;
+
+        fn from_integer(num: i128) -> Result<Value, String> {
+            Decimal::try_from_i128_with_scale(num, 0)
+                .map(Value::Decimal)
+                .map_err(|err| err.to_string())
+        }
+
+        match self {
+            ConditionClauseValue::Decimal(num) => Ok(Value::Decimal(num)),
+            ConditionClauseValue::F32(num) => Decimal::try_from(num)
+                .map(Value::Decimal)
+                .map_err(|err| err.to_string()),
+            ConditionClauseValue::F64(num) => Decimal::try_from(num)
+                .map(Value::Decimal)
+                .map_err(|err| err.to_string()),
+            ConditionClauseValue::String(string) => parse::<Decimal>(&string).map(Value::Decimal),
+            ConditionClauseValue::StringVec(strings) => strings
+                .iter()
+                .map(|string| parse::<Decimal>(string).map(Value::Decimal))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array),
+            other => match (other.as_integer(), other.as_integer_list()) {
+                (Some(num), _) => from_integer(num),
+                (None, Some(numbers)) => numbers
+                    .into_iter()
+                    .map(from_integer)
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(Value::Array),
+                (None, None) => Err(format!(
+                    "{other:?} can not be converted to a Decimal. Expected a number, String or Vec<String>."
+                )),
+            },
+        }
+    }
+
+    pub fn to_byte_vec(self) -> Result<Value, String> {
+        match self {
+            ConditionClauseValue::U8Vec(vec) => Ok(Value::Array(
+                vec.into_iter().map(Value::U8).collect(),
+            )),
+            _ => Err(format!(
+                "{self:?} can not be converted to an U8Vec. Expected U8Vec."
+            )),
+        }
+    }
+
+    pub fn to_bool(self) -> Result<Value, String> {
+        match self {
+            ConditionClauseValue::Bool(bool) => Ok(Value::Bool(bool)),
+            ConditionClauseValue::String(string) => parse::<bool>(&string).map(Value::Bool),
+            _ => Err(format!(
+                "{self:?} can not be converted to a bool. Expected bool or String."
+            )),
+        }
+    }
+
+    pub fn to_string(self) -> Result<Value, String> {
+        match self {
+            ConditionClauseValue::String(string) => Ok(Value::String(string)),
+            ConditionClauseValue::StringVec(strings) => Ok(Value::Array(
+                strings.into_iter().map(Value::String).collect(),
+            )),
+            _ => Err(format!(
+                "{self:?} can not be converted to a String. Expected String or Vec<String>."
+            )),
+        }
+    }
+
+    /// Converts variant names to enum values, without checking them against the variants of an
+    /// enum. Prefer [`ConditionClauseValue::to_enum`] when the enum type is known.
+    pub fn to_enum_name(self) -> Result<Value, String> {
+        match self {
+            ConditionClauseValue::String(name) => Ok(Value::Enum(name)),
+            ConditionClauseValue::StringVec(names) => {
+                Ok(Value::Array(names.into_iter().map(Value::Enum).collect()))
+            }
+            _ => Err(format!(
+                "{self:?} can not be converted to an enum value. Expected String or Vec<String>."
+            )),
+        }
+    }
+
+    /// Converts variant names of `E` to enum values, rejecting names of no variant.
+    pub fn to_enum<E: CkEnum>(self) -> Result<Value, String> {
+        fn check<E: CkEnum>(name: String) -> Result<Value, String> {
+            match E::from_variant_name(&name) {
+                Some(_) => Ok(Value::Enum(name)),
+                None => Err(format!(
+                    "'{name}' is not a variant. Expected one of: {}.",
+                    E::variants()
+                        .iter()
+                        .map(|variant| variant.name)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )),
+            }
+        }
+
+        match self {
+            ConditionClauseValue::String(name) => check::<E>(name),
+            ConditionClauseValue::StringVec(names) => names
+                .into_iter()
+                .map(check::<E>)
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array),
+            _ => Err(format!(
+                "{self:?} can not be converted to an enum value. Expected String or Vec<String>."
+            )),
+        }
+    }
+
+    pub fn to_json_value(self) -> Result<Value, String> {
+        match self {
+            ConditionClauseValue::Json(json) => Ok(Value::Json(json)),
+            ConditionClauseValue::String(string) => Ok(Value::String(string)),
+            _ => Err(format!(
+                "{self:?} can not be converted to a Json value. Expected Json or String."
+            )),
+        }
+    }
+
+    pub fn to_uuid(self) -> Result<Value, String> {
+        match self {
+            ConditionClauseValue::Uuid(uuid) => Ok(Value::Uuid(uuid)),
+            ConditionClauseValue::UuidVec(uuids) => {
+                Ok(Value::Array(uuids.into_iter().map(Value::Uuid).collect()))
+            }
+            ConditionClauseValue::String(string) => parse::<uuid::Uuid>(&string).map(Value::Uuid),
+            ConditionClauseValue::StringVec(strings) => strings
+                .iter()
+                .map(|string| parse::<uuid::Uuid>(string).map(Value::Uuid))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array),
+            _ => Err(format!(
+                "{self:?} can not be converted to a Uuid. Expected Uuid, Vec<Uuid>, String or Vec<String>."
+            )),
+        }
+    }
+
+    pub fn to_primitive_date_time(self) -> Result<Value, String> {
+        fn parse_primitive_date_time(string: &str) -> Result<Value, String> {
+            time::PrimitiveDateTime::parse(string, &Rfc3339)
+                .map_err(|err| err.to_string())
+                .map(Value::PrimitiveDateTime)
+        }
+
+        match self {
+            ConditionClauseValue::PrimitiveDateTime(date_time) => {
+                Ok(Value::PrimitiveDateTime(date_time))
+            }
+            ConditionClauseValue::OffsetDateTime(date_time) => {
+                let date_time = date_time.to_offset(time::UtcOffset::UTC);
+                Ok(Value::PrimitiveDateTime(time::PrimitiveDateTime::new(
+                    date_time.date(),
+                    date_time.time(),
+                )))
+            }
+            ConditionClauseValue::Date(date) => Ok(Value::PrimitiveDateTime(date.midnight())),
+            ConditionClauseValue::RelativeTime(relative) => Err(unresolved(relative)),
+            ConditionClauseValue::String(string) => parse_primitive_date_time(&string),
+            ConditionClauseValue::StringVec(strings) => strings
+                .iter()
+                .map(|string| parse_primitive_date_time(string))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array),
+            _ => Err(format!(
+                "{self:?} can not be converted to a PrimitiveDateTime. Expected PrimitiveDateTime, OffsetDateTime (taken as UTC), Date, String or Vec<String>."
+            )),
+        }
+    }
+
+    pub fn to_offset_date_time(self) -> Result<Value, String> {
+        fn parse_offset_date_time(string: &str) -> Result<Value, String> {
+            time::OffsetDateTime::parse(string, &Rfc3339)
+                .map_err(|err| err.to_string())
+                .map(Value::OffsetDateTime)
+        }
+
+        match self {
+            ConditionClauseValue::OffsetDateTime(date_time) => Ok(Value::OffsetDateTime(date_time)),
+            ConditionClauseValue::Date(date) => {
+                Ok(Value::OffsetDateTime(date.midnight().assume_utc()))
+            }
+            ConditionClauseValue::RelativeTime(relative) => Err(unresolved(relative)),
+            ConditionClauseValue::String(string) => parse_offset_date_time(&string),
+            ConditionClauseValue::StringVec(strings) => strings
+                .iter()
+                .map(|string| parse_offset_date_time(string))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array),
+            _ => Err(format!(
+                "{self:?} can not be converted to an OffsetDateTime. Expected OffsetDateTime, Date, String or Vec<String>."
+            )),
+        }
+    }
+
+    /// Date-times are converted to their date in their own offset, so that resolved relative
+    /// times (like `start_of_week`) denote the date in the time zone of the request.
+    pub fn to_date(self) -> Result<Value, String> {
+        fn parse_date(string: &str) -> Result<Value, String> {
+            time::Date::parse(string, &Iso8601::DEFAULT)
+                .map_err(|err| err.to_string())
+                .map(Value::Date)
+        }
+
+        match self {
+            ConditionClauseValue::Date(date) => Ok(Value::Date(date)),
+            ConditionClauseValue::PrimitiveDateTime(date_time) => Ok(Value::Date(date_time.date())),
+            ConditionClauseValue::OffsetDateTime(date_time) => Ok(Value::Date(date_time.date())),
+            ConditionClauseValue::RelativeTime(relative) => Err(unresolved(relative)),
+            ConditionClauseValue::String(string) => parse_date(&string),
+            ConditionClauseValue::StringVec(strings) => strings
+                .iter()
+                .map(|string| parse_date(string))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array),
+            _ => Err(format!(
+                "{self:?} can not be converted to a Date. Expected Date, PrimitiveDateTime, OffsetDateTime, String or Vec<String>."
+            )),
+        }
+    }
+
+    pub fn to_time(self) -> Result<Value, String> {
+        fn parse_time(string: &str) -> Result<Value, String> {
+            time::Time::parse(string, &Iso8601::DEFAULT)
+                .map_err(|err| err.to_string())
+                .map(Value::Time)
+        }
+
+        match self {
+            ConditionClauseValue::Time(time) => Ok(Value::Time(time)),
+            ConditionClauseValue::String(string) => parse_time(&string),
+            ConditionClauseValue::StringVec(strings) => strings
+                .iter()
+                .map(|string| parse_time(string))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array),
+            _ => Err(format!(
+                "{self:?} can not be converted to a Time. Expected Time, String ('[hour]:[minute]:[second]') or Vec<String>."
+            )),
+        }
+    }
+
+    pub fn to_time_duration(self) -> Result<Value, String> {
+        match self {
+            ConditionClauseValue::Duration(duration) => Ok(Value::Duration(duration)),
+            ConditionClauseValue::I64(microseconds) => Ok(Value::Duration(TimeDuration(
+                time::Duration::microseconds(microseconds),
+            ))),
+            _ => Err(format!(
+                "{self:?} can not be converted to a Duration. Expected Duration or I64 (microseconds)."
+            )),
+        }
+    }
+
+    /// Converts this value to JSON, for clauses on values inside Json columns.
+    ///
+    /// Lists become arrays. Decimals become numbers, which may round them. Uuids, date-times
+    /// (RFC 3339), dates, times and durations (ISO 8601) become strings.
+    pub fn to_json(self) -> Result<serde_json::Value, String> {
+        use serde_json::Value as Json;
+
+        fn number<T: TryInto<i64> + TryInto<u64> + Debug + Copy>(num: T) -> Result<Json, String> {
+            match (TryInto::<i64>::try_into(num), TryInto::<u64>::try_into(num)) {
+                (Ok(num), _) => Ok(Json::from(num)),
+                (_, Ok(num)) => Ok(Json::from(num)),
+                _ => Err(format!("{num:?} can not be represented as a JSON number.")),
+            }
+        }
+
+        fn float(num: f64) -> Result<Json, String> {
+            serde_json::Number::from_f64(num)
+                .map(Json::Number)
+                .ok_or_else(|| format!("{num} can not be represented as a JSON number."))
+        }
+
+        fn date_time(date_time: time::OffsetDateTime) -> Result<Json, String> {
+            date_time
+                .format(&Rfc3339)
+                .map(Json::String)
+                .map_err(|err| err.to_string())
+        }
+
+        match self {
+            ConditionClauseValue::Bool(value) => Ok(Json::Bool(value)),
+            ConditionClauseValue::U8(num) => number(num),
+            ConditionClauseValue::U16(num) => number(num),
+            ConditionClauseValue::U32(num) => number(num),
+            ConditionClauseValue::U64(num) => number(num),
+            ConditionClauseValue::U128(num) => number(num),
+            ConditionClauseValue::I8(num) => number(num),
+            ConditionClauseValue::I16(num) => number(num),
+            ConditionClauseValue::I32(num) => number(num),
+            ConditionClauseValue::I64(num) => number(num),
+            ConditionClauseValue::I128(num) => number(num),
+            ConditionClauseValue::F32(num) => float(num.into()),
+            ConditionClauseValue::F64(num) => float(num),
+            ConditionClauseValue::Decimal(num) => serde_json::from_str(&num.to_string())
+                .map_err(|err| format!("{num} can not be represented as a JSON number: {err}")),
+            ConditionClauseValue::String(string) => Ok(Json::String(string)),
+            ConditionClauseValue::Json(json) => Ok(json),
+            ConditionClauseValue::Uuid(uuid) => Ok(Json::String(uuid.to_string())),
+            ConditionClauseValue::PrimitiveDateTime(value) => date_time(value.assume_utc()),
+            ConditionClauseValue::OffsetDateTime(value) => date_time(value),
+            ConditionClauseValue::Date(date) => Ok(Json::String(date.to_string())),
+            ConditionClauseValue::Time(time) => Ok(Json::String(text::TimeText(time).to_string())),
+            ConditionClauseValue::Duration(TimeDuration(duration)) => {
+                Ok(Json::String(text::IsoDuration(duration).to_string()))
+            }
+            ConditionClauseValue::RelativeTime(relative) => Err(unresolved(relative)),
+            ConditionClauseValue::Placeholder(name) => {
+                Err(format!("The placeholder '${name}' was not resolved."))
+            }
+            ConditionClauseValue::U8Vec(values) => values.into_iter().map(number).collect(),
+            ConditionClauseValue::I32Vec(values) => values.into_iter().map(number).collect(),
+            ConditionClauseValue::I64Vec(values) => values.into_iter().map(number).collect(),
+            ConditionClauseValue::UuidVec(values) => Ok(values
+                .into_iter()
+                .map(|uuid| Json::String(uuid.to_string()))
+                .collect()),
+            ConditionClauseValue::StringVec(values) => {
+                Ok(values.into_iter().map(Json::String).collect())
+            }
+        }
+    }
+}
+
+fn unresolved(relative: RelativeTime) -> String {
+    format!("The relative time '{relative}' was not resolved to a point in time.")
+}
+
+fn parse<T>(string: &str) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    string.parse::<T>().map_err(|e| format!("{}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::Condition;
+    use assertr::prelude::*;
+
+    const TITLE: TypedField<String> = TypedField::new("title");
+    const ID: TypedField<i64> = TypedField::new("id");
+
+    #[test]
+    fn fluent_builder_appends_to_existing_all_condition() {
+        let condition = Condition::all().eq("tenant_id", 1).and(TITLE.contains("foo"));
+
+        assert_that(condition).is_equal_to(Condition::All(vec![
+            ConditionElement::Clause(ConditionClause {
+                column_name: "tenant_id".to_string(),
+                json_path: Vec::new(),
+                operator: Operator::Equal,
+                value: ConditionClauseValue::I32(1),
+            }),
+            ConditionElement::Clause(ConditionClause {
+                column_name: "title".to_string(),
+                json_path: Vec::new(),
+                operator: Operator::Contains,
+                value: ConditionClauseValue::String("foo".to_string()),
+            }),
+        ]));
+    }
+
+    #[test]
+    fn typed_fields_build_list_values() {
+        let condition = ID.in_([1, 2, 3]).or(ID.between(10, 20));
+
+        assert_that(condition).is_equal_to(Condition::Any(vec![
+            ConditionElement::Clause(ConditionClause {
+                column_name: "id".to_string(),
+                json_path: Vec::new(),
+                operator: Operator::IsIn,
+                value: ConditionClauseValue::I64Vec(vec![1, 2, 3]),
+            }),
+            ConditionElement::Clause(ConditionClause {
+                column_name: "id".to_string(),
+                json_path: Vec::new(),
+                operator: Operator::Between,
+                value: ConditionClauseValue::I64Vec(vec![10, 20]),
+            }),
+        ]));
+    }
+
+    #[test]
+    fn homogeneous_arrays_convert_to_list_values() {
+        let value = Value::Array(vec![
+            Value::String("a".to_string()),
+            Value::String("b".to_string()),
+        ]);
+
+        let converted = ConditionClauseValue::try_from(value).unwrap();
+
+        assert_that(converted).is_equal_to(ConditionClauseValue::StringVec(vec![
+            "a".to_string(),
+            "b".to_string(),
+        ]));
+    }
+
+    #[test]
+    fn array_values_convert_from_single_values_and_lists() {
+        const TAGS: TypedField<Vec<String>> = TypedField::new("tags");
+        let Condition::All(elements) = TAGS.array_contains(["a", "b"]) else {
+            panic!("expected an All condition");
+        };
+        let [ConditionElement::Clause(clause)] = elements.as_slice() else {
+            panic!("expected a single clause");
+        };
+
+        let list = clause.value.clone().into_array_of_kind(ValueKind::String);
+        let single = ConditionClauseValue::from("a").into_array_of_kind(ValueKind::String);
+        let range = ConditionClauseValue::I32Vec(vec![1, 3]).to_array_length();
+
+        assert_that(clause.operator).is_equal_to(Operator::ArrayContains);
+        assert_that(list.unwrap().as_array().map(Vec::len)).is_equal_to(Some(2));
+        assert_that(single.unwrap().as_array().map(Vec::len)).is_equal_to(Some(1));
+        assert_that(range.unwrap().as_array().map(Vec::len)).is_equal_to(Some(2));
+        assert_that(ConditionClauseValue::I32Vec(vec![1]).to_array_length()).is_err();
+    }
+
+    #[test]
+    fn dates_convert_to_start_of_day() {
+        let date = time::Date::from_calendar_date(2024, time::Month::March, 1).unwrap();
+
+        let converted = ConditionClauseValue::Date(date).to_primitive_date_time().unwrap();
+
+        assert_that(converted.as_primitive_date_time()).is_equal_to(Some(date.midnight()));
+    }
+
+    #[test]
+    fn date_times_convert_to_their_local_date() {
+        let plus_two = time::UtcOffset::from_hms(2, 0, 0).unwrap();
+        let date = time::Date::from_calendar_date(2024, time::Month::March, 1).unwrap();
+        let start_of_day = date.midnight().assume_offset(plus_two);
+
+        let converted_date = ConditionClauseValue::OffsetDateTime(start_of_day).to_date();
+        let converted_time = ConditionClauseValue::from("08:30").to_time();
+
+        assert_that(converted_date.unwrap().as_date()).is_equal_to(Some(date));
+        assert_that(converted_time.unwrap().as_time())
+            .is_equal_to(Some(time::Time::from_hms(8, 30, 0).unwrap()));
+    }
+
+    #[test]
+    fn strings_and_integers_convert_to_exact_decimals() {
+        let price = ConditionClauseValue::String("19.99".to_owned()).to_decimal();
+        let count = ConditionClauseValue::I64(3).to_decimal();
+
+        assert_that(price.unwrap().as_decimal())
+            .is_equal_to(Some(rust_decimal::Decimal::new(1999, 2)));
+        assert_that(count.unwrap().as_decimal()).is_equal_to(Some(rust_decimal::Decimal::from(3)));
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    enum Status {
+        Open,
+        InProgress,
+    }
+
+    impl CkEnum for Status {
+        fn variants() -> &'static [crate::EnumVariant] {
+            &[
+                crate::EnumVariant {
+                    name: "open",
+                    label: "Open",
+                },
+                crate::EnumVariant {
+                    name: "in_progress",
+                    label: "In progress",
+                },
+            ]
+        }
+
+        fn variant_name(&self) -> &'static str {
+            match self {
+                Status::Open => "open",
+                Status::InProgress => "in_progress",
+            }
+        }
+
+        fn from_variant_name(name: &str) -> Option<Self> {
+            match name {
+                "open" => Some(Status::Open),
+                "in_progress" => Some(Status::InProgress),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn enum_values_are_checked_against_the_variants() {
+        const STATUS: TypedField<Status> = TypedField::new("status");
+
+        let condition = STATUS.in_([Status::Open, Status::InProgress]);
+        let known = ConditionClauseValue::from(Status::InProgress).to_enum::<Status>();
+        let unknown = ConditionClauseValue::from("closed").to_enum::<Status>();
+
+        assert_that(condition).is_equal_to(Condition::All(vec![ConditionElement::Clause(
+            ConditionClause {
+                column_name: "status".to_string(),
+                json_path: Vec::new(),
+                operator: Operator::IsIn,
+                value: ConditionClauseValue::StringVec(vec![
+                    "open".to_string(),
+                    "in_progress".to_string(),
+                ]),
+            },
+        )]));
+        assert_that(known.unwrap().as_enum().cloned()).is_equal_to(Some("in_progress".to_owned()));
+        assert_that(unknown).is_err();
+    }
+
+    #[test]
+    fn serialize_and_deserialize_negated_condition() {
+        let condition = !TITLE.is_null();
+
+        let json = serde_json::to_string(&condition).unwrap();
+
+        assert_that(&json).is_equal_to(
+            r#"{"Not":{"All":[{"column_name":"title","operator":"is_null","value":{"Bool":true}}]}}"#,
+        );
+
+        let deserialized: Condition = serde_json::from_str(json.as_str()).unwrap();
+
+        assert_that(deserialized).is_equal_to(condition);
+    }
+
+    #[test]
+    fn like_patterns_escape_wildcards() {
+        assert_that(Operator::Contains.like_pattern(r"50%_\"))
+            .is_equal_to(Some(r"%50\%\_\\%".to_owned()));
+        assert_that(Operator::ContainsIgnoreCase.like_pattern("A_b"))
+            .is_equal_to(Some(r"%a\_b%".to_owned()));
+        assert_that(Operator::StartsWith.like_pattern("a%")).is_equal_to(Some(r"a\%%".to_owned()));
+        assert_that(Operator::EndsWith.like_pattern("a")).is_equal_to(Some("%a".to_owned()));
+        assert_that(Operator::EqualIgnoreCase.like_pattern("a")).is_none();
+    }
+
+    #[test]
+    fn builder_methods_on_negated_condition_leave_the_negation_untouched() {
+        let negated = !TITLE.eq("x");
+
+        let condition = negated.clone().eq("tenant_id", 1);
+
+        assert_that(condition).is_equal_to(Condition::All(vec![
+            ConditionElement::Condition(Box::new(negated)),
+            ConditionElement::Clause(ConditionClause {
+                column_name: "tenant_id".to_string(),
+                json_path: Vec::new(),
+                operator: Operator::Equal,
+                value: ConditionClauseValue::I32(1),
+            }),
+        ]));
+    }
+
+    #[test]
+    fn negated_empty_condition_is_not_empty_and_survives_merging() {
+        let negated = !Condition::all();
+
+        assert_that(negated.is_empty()).is_false();
+        assert_that(merge_conditions(None, Some(negated.clone())))
+            .is_equal_to(Some(negated.clone()));
+
+        let merged = merge_conditions(Some(TITLE.eq("x")), Some(negated.clone()));
+
+        assert_that(merged).is_equal_to(Some(Condition::All(vec![
+            ConditionElement::Condition(Box::new(TITLE.eq("x"))),
+            ConditionElement::Condition(Box::new(negated)),
+        ])));
+    }
+}
@@ -0,0 +1,288 @@
+//! Points in time relative to the moment a condition is executed.
+//!
+//! Stored filters like "created in the last 7 days" use `now - P7D` instead of an absolute
+//! timestamp, so that they stay correct over time. Servers resolve these values right before
+//! executing a condition, see [`Condition::resolve_relative_times`].
+
+use super::text::{IsoDuration, parse_iso_duration};
+use super::{Condition, ConditionClauseValue};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use time::{Date, Duration, Month, OffsetDateTime, Time, UtcOffset};
+use utoipa::openapi::Type;
+
+/// The calendar point a [`RelativeTime`] starts from.
+///
+/// Calendar anchors are computed in the time zone the condition is resolved in. `end_of_*`
+/// anchors are the (exclusive) start of the following period, e.g. `end_of_day` is the next
+/// midnight, so that `due < end_of_month` matches the whole current month. Weeks start on Monday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeAnchor {
+    Now,
+    StartOfDay,
+    StartOfWeek,
+    StartOfMonth,
+    StartOfYear,
+    EndOfDay,
+    EndOfWeek,
+    EndOfMonth,
+    EndOfYear,
+}
+
+impl TimeAnchor {
+    pub const ALL: [TimeAnchor; 9] = [
+        TimeAnchor::Now,
+        TimeAnchor::StartOfDay,
+        TimeAnchor::StartOfWeek,
+        TimeAnchor::StartOfMonth,
+        TimeAnchor::StartOfYear,
+        TimeAnchor::EndOfDay,
+        TimeAnchor::EndOfWeek,
+        TimeAnchor::EndOfMonth,
+        TimeAnchor::EndOfYear,
+    ];
+
+    /// The keyword naming this anchor, e.g. `start_of_month`.
+    pub fn keyword(self) -> &'static str {
+        match self {
+            TimeAnchor::Now => "now",
+            TimeAnchor::StartOfDay => "start_of_day",
+            TimeAnchor::StartOfWeek => "start_of_week",
+            TimeAnchor::StartOfMonth => "start_of_month",
+            TimeAnchor::StartOfYear => "start_of_year",
+            TimeAnchor::EndOfDay => "end_of_day",
+            TimeAnchor::EndOfWeek => "end_of_week",
+            TimeAnchor::EndOfMonth => "end_of_month",
+            TimeAnchor::EndOfYear => "end_of_year",
+        }
+    }
+
+    /// Looks up an anchor by its keyword, ignoring case.
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|anchor| anchor.keyword().eq_ignore_ascii_case(keyword))
+    }
+
+    /// Computes this anchor for the instant `now`, as seen in the time zone `offset`.
+    fn resolve(self, now: OffsetDateTime, offset: UtcOffset) -> Option<OffsetDateTime> {
+        let now = now.checked_to_offset(offset)?;
+        let today = now.date();
+        let start_of_week = today.checked_sub(Duration::days(
+            today.weekday().number_days_from_monday().into(),
+        ));
+        let start_of_month = today.replace_day(1).ok();
+        let start_of_year = Date::from_calendar_date(today.year(), Month::January, 1).ok();
+        let date = match self {
+            TimeAnchor::Now => return Some(now),
+            TimeAnchor::StartOfDay => Some(today),
+            TimeAnchor::StartOfWeek => start_of_week,
+            TimeAnchor::StartOfMonth => start_of_month,
+            TimeAnchor::StartOfYear => start_of_year,
+            TimeAnchor::EndOfDay => today.next_day(),
+            TimeAnchor::EndOfWeek => start_of_week?.checked_add(Duration::weeks(1)),
+            TimeAnchor::EndOfMonth => {
+                let next_month = today.month().next();
+                let year = match next_month {
+                    Month::January => today.year().checked_add(1)?,
+                    _ => today.year(),
+                };
+                Date::from_calendar_date(year, next_month, 1).ok()
+            }
+            TimeAnchor::EndOfYear => {
+                Date::from_calendar_date(today.year().checked_add(1)?, Month::January, 1).ok()
+            }
+        }?;
+        Some(date.with_time(Time::MIDNIGHT).assume_offset(offset))
+    }
+}
+
+/// A point in time relative to the moment a condition is executed: an anchor shifted by a
+/// fixed duration, written like `now - P7D` or `start_of_month + PT8H`.
+///
+/// Serialized as that string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RelativeTime {
+    pub anchor: TimeAnchor,
+    pub offset: Duration,
+}
+
+impl RelativeTime {
+    pub fn new(anchor: TimeAnchor, offset: Duration) -> Self {
+        Self { anchor, offset }
+    }
+
+    /// The absolute point in time this value denotes when executed at `now`, computing calendar
+    /// anchors in the time zone `offset`.
+    ///
+    /// Returns `None` if the result is not representable.
+    pub fn resolve(&self, now: OffsetDateTime, offset: UtcOffset) -> Option<OffsetDateTime> {
+        self.anchor.resolve(now, offset)?.checked_add(self.offset)
+    }
+}
+
+impl From<TimeAnchor> for RelativeTime {
+    fn from(anchor: TimeAnchor) -> Self {
+        Self::new(anchor, Duration::ZERO)
+    }
+}
+
+impl Display for RelativeTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.anchor.keyword())?;
+        match self.offset {
+            offset if offset.is_zero() => Ok(()),
+            offset if offset.is_negative() => write!(f, " - {}", IsoDuration(offset.abs())),
+            offset => write!(f, " + {}", IsoDuration(offset)),
+        }
+    }
+}
+
+impl FromStr for RelativeTime {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let anchor_end = text
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(text.len());
+        let anchor = TimeAnchor::from_keyword(&text[..anchor_end])
+            .ok_or_else(|| format!("`{text}` does not start with a time anchor like `now`"))?;
+        let rest = text[anchor_end..].trim_start();
+        let offset = match rest.chars().next() {
+            None => Duration::ZERO,
+            Some('+') => parse_iso_duration(rest[1..].trim_start())?,
+            Some('-') => -parse_iso_duration(rest[1..].trim_start())?,
+            Some(_) => return Err(format!("expected `+` or `-` after `{}`", anchor.keyword())),
+        };
+        Ok(Self::new(anchor, offset))
+    }
+}
+
+impl Serialize for RelativeTime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RelativeTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = Cow::<str>::deserialize(deserializer)?;
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl utoipa::ToSchema for RelativeTime {
+    fn name() -> Cow<'static, str> {
+        Cow::Borrowed("RelativeTime")
+    }
+}
+
+impl utoipa::PartialSchema for RelativeTime {
+    fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        utoipa::openapi::ObjectBuilder::new()
+            .schema_type(utoipa::openapi::schema::SchemaType::Type(Type::String))
+            .description(Some(
+                "Anchor (now, start_of_day, start_of_week, start_of_month, start_of_year, \
+                 end_of_day, end_of_week, end_of_month, end_of_year), optionally followed by \
+                 '+' or '-' and an ISO 8601 duration",
+            ))
+            .examples(["now - P7D"])
+            .into()
+    }
+}
+
+impl Condition {
+    /// Replaces all relative times with the absolute points in time they denote when executed
+    /// at `now`, computing calendar anchors in the time zone `offset`.
+    ///
+    /// Values which are not representable are left untouched and fail to convert later on.
+    pub fn resolve_relative_times(&mut self, now: OffsetDateTime, offset: UtcOffset) {
+        self.for_each_clause_mut(&mut |clause| {
+            let resolved = match &clause.value {
+                ConditionClauseValue::RelativeTime(relative) => relative.resolve(now, offset),
+                _ => None,
+            };
+            if let Some(resolved) = resolved {
+                clause.value = ConditionClauseValue::OffsetDateTime(resolved);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::condition::Condition;
+    use assertr::prelude::*;
+
+    fn at(year: i32, month: Month, day: u8, hour: u8, offset: UtcOffset) -> OffsetDateTime {
+        Date::from_calendar_date(year, month, day)
+            .unwrap()
+            .with_hms(hour, 0, 0)
+            .unwrap()
+            .assume_offset(offset)
+    }
+
+    #[test]
+    fn resolves_anchors_in_the_given_time_zone() {
+        let plus_two = UtcOffset::from_hms(2, 0, 0).unwrap();
+        // 2024-12-31 23:00 UTC already is Wednesday, 2025-01-01 in UTC+2.
+        let now = at(2024, Month::December, 31, 23, UtcOffset::UTC);
+        let resolve = |text: &str| text.parse::<RelativeTime>().unwrap().resolve(now, plus_two);
+
+        assert_that(resolve("now - P7D")).is_equal_to(Some(now - Duration::days(7)));
+        assert_that(resolve("start_of_day")).is_equal_to(Some(at(
+            2025,
+            Month::January,
+            1,
+            0,
+            plus_two,
+        )));
+        assert_that(resolve("start_of_week")).is_equal_to(Some(at(
+            2024,
+            Month::December,
+            30,
+            0,
+            plus_two,
+        )));
+        assert_that(resolve("end_of_month + PT8H")).is_equal_to(Some(at(
+            2025,
+            Month::February,
+            1,
+            8,
+            plus_two,
+        )));
+        assert_that(resolve("start_of_year")).is_equal_to(Some(at(
+            2025,
+            Month::January,
+            1,
+            0,
+            plus_two,
+        )));
+    }
+
+    #[test]
+    fn round_trips_through_strings() {
+        let relative = RelativeTime::new(TimeAnchor::StartOfMonth, -Duration::hours(36));
+
+        assert_that(relative.to_string()).is_equal_to("start_of_month - P1DT12H".to_owned());
+        assert_that(relative.to_string().parse::<RelativeTime>()).is_equal_to(Ok(relative));
+        assert_that("tomorrow".parse::<RelativeTime>()).is_err();
+    }
+
+    #[test]
+    fn replaces_relative_times_in_conditions() {
+        let now = at(2024, Month::June, 15, 12, UtcOffset::UTC);
+        let mut condition =
+            !Condition::all().gte("created", RelativeTime::from(TimeAnchor::StartOfDay));
+
+        condition.resolve_relative_times(now, UtcOffset::UTC);
+
+        assert_that(condition).is_equal_to(
+            !Condition::all().gte("created", at(2024, Month::June, 15, 0, UtcOffset::UTC)),
+        );
+    }
+}
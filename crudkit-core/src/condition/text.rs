@@ -0,0 +1,1476 @@
+//! Textual filter syntax for conditions.
+//!
+//! A compact notation for [`Condition`]s, meant to be written by hand and shared in URL query
+//! strings:
+//!
+//! ```text
+//! status = "open" and (priority >= 3 or assignee is null)
+//! ```
+//!
+//! # Syntax
+//!
+//! - Clauses: `field = value` (also `!=`, `<`, `<=`, `>`, `>=`), `field in [..]`,
+//!   `field not in [..]`, `field between low and high`, `field is null`, `field is not null`
+//!   and `field contains "text"` (also `contains_ignore_case`, `starts_with`, `ends_with` and
+//!   `eq_ignore_case`).
+//! - Json fields: `field.key.nested = "value"` addresses a value inside the document (keys
+//!   other than letters, digits and underscores are quoted: `field."order-id"`),
+//!   `field json_contains json"{..}"` checks for containment.
+//! - Array fields: `field array_contains ["a", "b"]` (all of the values),
+//!   `field array_overlaps ["a", "b"]` (any of the values) and `field array_length 2` (also
+//!   `field array_length [1, 3]` for an inclusive range).
+//! - `not` binds stronger than `and`, which binds stronger than `or`. Parentheses group.
+//!   `all(..)` and `any(..)` spell out conditions with less than two elements, e.g. `any()`.
+//! - Values: `"strings"`, `true`, `false`, integers (`3` is an `i64`, `3i32` an `i32`), floats
+//!   (`1.5` is an `f64`, `1.5f32` an `f32`), decimals (`19.99dec`), lists (`[1, 2]`,
+//!   `i32[1, 2]`, `string[]`) and typed strings: `uuid"..."`, `datetime"..."` (RFC 3339),
+//!   `local_datetime"..."`, `date"..."`, `time"..."` (e.g. `08:30:00`), `duration"..."`
+//!   (ISO 8601, e.g. `P7D`), `json"..."`, `f64"NaN"`.
+//! - Lists and `between` bounds may also hold decimals, dates, date-times and times
+//!   (`date["2024-01-01"]`, `between 1.5dec and 3dec`). These are kept as strings, which the
+//!   field converts.
+//! - Relative times, resolved when the condition is executed: `now`, `now - P7D`,
+//!   `start_of_month + PT8H` (see [`RelativeTime`](super::RelativeTime)).
+//! - Placeholders, resolved from the request executing the condition: `$tenant_id`.
+//! - Keywords are case-insensitive.
+//!
+//! `Condition` implements `Display` in this syntax. Parsing the printed form yields the same
+//! condition, except that the (ignored) value of null checks is not printed.
+
+use super::{
+    Condition, ConditionClause, ConditionClauseValue, ConditionElement, IGNORED_VALUE, Operator,
+    RelativeTime, TimeAnchor,
+};
+use crate::TimeDuration;
+use std::fmt::{self, Display, Formatter, Write};
+use std::ops::Range;
+use std::str::FromStr;
+use time::format_description::well_known::{Iso8601, Rfc3339};
+
+/// A textual condition could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{message} (at {}..{})", span.start, span.end)]
+pub struct ConditionParseError {
+    pub message: String,
+    /// Byte range of the offending input.
+    pub span: Range<usize>,
+}
+
+impl ConditionParseError {
+    fn new(message: impl Into<String>, span: Range<usize>) -> Self {
+        Self {
+            message: message.into(),
+            span,
+        }
+    }
+}
+
+impl Condition {
+    /// Parses a condition in the textual filter syntax, accepting any field name.
+    pub fn parse(input: &str) -> Result<Condition, ConditionParseError> {
+        Self::parse_with_fields(input, |_| true)
+    }
+
+    /// Parses a condition in the textual filter syntax, rejecting field names for which
+    /// `is_known_field` returns false.
+    pub fn parse_with_fields(
+        input: &str,
+        is_known_field: impl Fn(&str) -> bool,
+    ) -> Result<Condition, ConditionParseError> {
+        Parser {
+            input,
+            tokens: tokenize(input)?,
+            pos: 0,
+            is_known_field,
+        }
+        .parse()
+    }
+}
+
+impl FromStr for Condition {
+    type Err = ConditionParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse(input)
+    }
+}
+
+// =============================================================================
+// Lexer
+// =============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    Word(String),
+    /// A field followed by a Json path with at least one quoted key, e.g. `metadata."order-id"`.
+    Path(Vec<String>),
+    String(String),
+    /// A string literal prefixed with its type, e.g. `uuid"..."`.
+    TypedString(String, String),
+    Number {
+        digits: String,
+        suffix: String,
+        is_float: bool,
+    },
+    Comparison(Operator),
+    Placeholder(String),
+    Plus,
+    Minus,
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    Comma,
+    Eof,
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    span: Range<usize>,
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, ConditionParseError> {
+    let mut lexer = Lexer { input, pos: 0 };
+    let mut tokens = Vec::new();
+    loop {
+        let token = lexer.next_token()?;
+        let is_eof = token.kind == TokenKind::Eof;
+        tokens.push(token);
+        if is_eof {
+            return Ok(tokens);
+        }
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+struct Lexer<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl Lexer<'_> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn bump_if(&mut self, expected: char) -> bool {
+        let matches = self.peek() == Some(expected);
+        if matches {
+            self.bump();
+        }
+        matches
+    }
+
+    fn eat_while(&mut self, predicate: impl Fn(char) -> bool) -> &str {
+        let start = self.pos;
+        while self.peek().is_some_and(&predicate) {
+            self.bump();
+        }
+        &self.input[start..self.pos]
+    }
+
+    fn next_token(&mut self) -> Result<Token, ConditionParseError> {
+        self.eat_while(char::is_whitespace);
+        let start = self.pos;
+        let Some(c) = self.bump() else {
+            return Ok(Token {
+                kind: TokenKind::Eof,
+                span: start..start,
+            });
+        };
+        let kind = match c {
+            '(' => TokenKind::LParen,
+            ')' => TokenKind::RParen,
+            '[' => TokenKind::LBracket,
+            ']' => TokenKind::RBracket,
+            ',' => TokenKind::Comma,
+            '=' => TokenKind::Comparison(Operator::Equal),
+            '!' if self.bump_if('=') => TokenKind::Comparison(Operator::NotEqual),
+            '<' if self.bump_if('=') => TokenKind::Comparison(Operator::LessOrEqual),
+            '<' => TokenKind::Comparison(Operator::Less),
+            '>' if self.bump_if('=') => TokenKind::Comparison(Operator::GreaterOrEqual),
+            '>' => TokenKind::Comparison(Operator::Greater),
+            '"' => TokenKind::String(self.string_body(start)?),
+            c if c.is_ascii_digit()
+                || (c == '-' && self.peek().is_some_and(|c| c.is_ascii_digit())) =>
+            {
+                self.number(start)
+            }
+            '$' => {
+                self.eat_while(|c| c.is_alphanumeric() || c == '_');
+                let name = &self.input[start + 1..self.pos];
+                if !name.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+                    return Err(ConditionParseError::new(
+                        "expected a placeholder name after `$`",
+                        start..self.pos,
+                    ));
+                }
+                TokenKind::Placeholder(name.to_owned())
+            }
+            '+' => TokenKind::Plus,
+            '-' => TokenKind::Minus,
+            c if c.is_alphabetic() || c == '_' => {
+                // Words may contain dots, as in the duration `PT1.5S` or the Json path
+                // `metadata.customer.country`. Json keys may also be quoted, as in
+                // `metadata."order-id"`.
+                self.eat_while(is_word_char);
+                let mut segments = vec![self.input[start..self.pos].to_owned()];
+                let mut has_quoted_key = false;
+                loop {
+                    let rest = &self.input[self.pos..];
+                    if rest.starts_with(".\"") {
+                        let key_start = self.pos + 1;
+                        self.pos += 2;
+                        segments.push(self.string_body(key_start)?);
+                        has_quoted_key = true;
+                    } else if rest.starts_with('.') && rest[1..].starts_with(is_word_char) {
+                        self.bump();
+                        segments.push(self.eat_while(is_word_char).to_owned());
+                    } else {
+                        break;
+                    }
+                }
+                if has_quoted_key {
+                    TokenKind::Path(segments)
+                } else {
+                    let word = segments.join(".");
+                    if self.bump_if('"') {
+                        TokenKind::TypedString(word, self.string_body(start)?)
+                    } else {
+                        TokenKind::Word(word)
+                    }
+                }
+            }
+            c => {
+                return Err(ConditionParseError::new(
+                    format!("unexpected character `{c}`"),
+                    start..self.pos,
+                ));
+            }
+        };
+        Ok(Token {
+            kind,
+            span: start..self.pos,
+        })
+    }
+
+    /// Reads the remainder of a string literal whose opening quote was already consumed.
+    fn string_body(&mut self, start: usize) -> Result<String, ConditionParseError> {
+        let mut value = String::new();
+        loop {
+            let escape_start = self.pos;
+            match self.bump() {
+                None => {
+                    return Err(ConditionParseError::new(
+                        "unterminated string",
+                        start..self.pos,
+                    ));
+                }
+                Some('"') => return Ok(value),
+                Some('\\') => match self.bump() {
+                    Some('"') => value.push('"'),
+                    Some('\\') => value.push('\\'),
+                    Some('n') => value.push('\n'),
+                    Some('r') => value.push('\r'),
+                    Some('t') => value.push('\t'),
+                    _ => {
+                        return Err(ConditionParseError::new(
+                            "unknown escape sequence",
+                            escape_start..self.pos,
+                        ));
+                    }
+                },
+                Some(c) => value.push(c),
+            }
+        }
+    }
+
+    /// Reads the remainder of a number whose first character was already consumed.
+    fn number(&mut self, start: usize) -> TokenKind {
+        self.eat_while(|c| c.is_ascii_digit());
+        let mut is_float = false;
+        let rest = &self.input[self.pos..];
+        if rest.starts_with('.') && rest[1..].starts_with(|c: char| c.is_ascii_digit()) {
+            self.bump();
+            self.eat_while(|c| c.is_ascii_digit());
+            is_float = true;
+        }
+        let rest = &self.input[self.pos..];
+        if let Some(exponent) = rest.strip_prefix(['e', 'E']) {
+            let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+            if exponent.starts_with(|c: char| c.is_ascii_digit()) {
+                self.pos += rest.len() - exponent.len();
+                self.eat_while(|c| c.is_ascii_digit());
+                is_float = true;
+            }
+        }
+        let digits = self.input[start..self.pos].to_owned();
+        let suffix = self
+            .eat_while(|c| c.is_alphanumeric() || c == '_')
+            .to_owned();
+        TokenKind::Number {
+            digits,
+            suffix,
+            is_float,
+        }
+    }
+}
+
+// =============================================================================
+// Parser
+// =============================================================================
+
+/// Result of parsing an expression. Keeps track of how the condition was written, so that
+/// parenthesized groups stay nested while `a and b and c` becomes a single `All`.
+enum Parsed {
+    Clause(ConditionClause),
+    /// An unparenthesized `and`/`or` chain.
+    Chain(Condition),
+    /// A parenthesized group, negation or `all(..)`/`any(..)`.
+    Group(Condition),
+}
+
+impl Parsed {
+    fn into_condition(self) -> Condition {
+        match self {
+            Parsed::Clause(clause) => Condition::All(vec![ConditionElement::Clause(clause)]),
+            Parsed::Chain(condition) | Parsed::Group(condition) => condition,
+        }
+    }
+
+    fn into_element(self) -> ConditionElement {
+        match self {
+            Parsed::Clause(clause) => ConditionElement::Clause(clause),
+            Parsed::Chain(condition) | Parsed::Group(condition) => {
+                ConditionElement::Condition(Box::new(condition))
+            }
+        }
+    }
+}
+
+struct Parser<'a, F> {
+    input: &'a str,
+    tokens: Vec<Token>,
+    pos: usize,
+    is_known_field: F,
+}
+
+impl<F: Fn(&str) -> bool> Parser<'_, F> {
+    fn parse(mut self) -> Result<Condition, ConditionParseError> {
+        let parsed = self.parse_or()?;
+        if self.peek().kind != TokenKind::Eof {
+            return Err(self.unexpected("`and`, `or` or end of input"));
+        }
+        Ok(parsed.into_condition())
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos]
+    }
+
+    fn peek_second(&self) -> &Token {
+        &self.tokens[(self.pos + 1).min(self.tokens.len() - 1)]
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.tokens[self.pos].clone();
+        if token.kind != TokenKind::Eof {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn previous_end(&self) -> usize {
+        self.pos
+            .checked_sub(1)
+            .map_or(0, |previous| self.tokens[previous].span.end)
+    }
+
+    fn is_keyword(token: &Token, keyword: &str) -> bool {
+        matches!(&token.kind, TokenKind::Word(word) if word.eq_ignore_ascii_case(keyword))
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let matches = Self::is_keyword(self.peek(), keyword);
+        if matches {
+            self.advance();
+        }
+        matches
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), ConditionParseError> {
+        match self.eat_keyword(keyword) {
+            true => Ok(()),
+            false => Err(self.unexpected(&format!("`{keyword}`"))),
+        }
+    }
+
+    fn eat(&mut self, kind: &TokenKind) -> bool {
+        let matches = &self.peek().kind == kind;
+        if matches {
+            self.advance();
+        }
+        matches
+    }
+
+    fn unexpected(&self, expected: &str) -> ConditionParseError {
+        let token = self.peek();
+        let found = match token.kind {
+            TokenKind::Eof => "end of input".to_owned(),
+            _ => format!("`{}`", &self.input[token.span.clone()]),
+        };
+        ConditionParseError::new(
+            format!("expected {expected}, found {found}"),
+            token.span.clone(),
+        )
+    }
+
+    fn parse_or(&mut self) -> Result<Parsed, ConditionParseError> {
+        let first = self.parse_and()?;
+        if !Self::is_keyword(self.peek(), "or") {
+            return Ok(first);
+        }
+        let mut elements = vec![first.into_element()];
+        while self.eat_keyword("or") {
+            elements.push(self.parse_and()?.into_element());
+        }
+        Ok(Parsed::Chain(Condition::Any(elements)))
+    }
+
+    fn parse_and(&mut self) -> Result<Parsed, ConditionParseError> {
+        let first = self.parse_unary()?;
+        if !Self::is_keyword(self.peek(), "and") {
+            return Ok(first);
+        }
+        let mut elements = vec![first.into_element()];
+        while self.eat_keyword("and") {
+            elements.push(self.parse_unary()?.into_element());
+        }
+        Ok(Parsed::Chain(Condition::All(elements)))
+    }
+
+    fn parse_unary(&mut self) -> Result<Parsed, ConditionParseError> {
+        if self.eat_keyword("not") {
+            let operand = self.parse_unary()?;
+            return Ok(Parsed::Group(Condition::negate(operand.into_condition())));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<Parsed, ConditionParseError> {
+        if self.eat(&TokenKind::LParen) {
+            let inner = self.parse_or()?;
+            if !self.eat(&TokenKind::RParen) {
+                return Err(self.unexpected("`and`, `or` or `)`"));
+            }
+            return Ok(Parsed::Group(match inner {
+                Parsed::Clause(clause) => Condition::All(vec![ConditionElement::Clause(clause)]),
+                Parsed::Chain(condition) => condition,
+                Parsed::Group(condition) => {
+                    Condition::All(vec![ConditionElement::Condition(Box::new(condition))])
+                }
+            }));
+        }
+
+        let is_call = self.peek_second().kind == TokenKind::LParen;
+        let is_all = Self::is_keyword(self.peek(), "all");
+        if is_call && (is_all || Self::is_keyword(self.peek(), "any")) {
+            self.advance();
+            self.advance();
+            let elements = self.parse_separated(TokenKind::RParen, "`)`", |p| {
+                Ok(p.parse_or()?.into_element())
+            })?;
+            return Ok(Parsed::Group(match is_all {
+                true => Condition::All(elements),
+                false => Condition::Any(elements),
+            }));
+        }
+
+        self.parse_clause().map(Parsed::Clause)
+    }
+
+    fn parse_separated<T>(
+        &mut self,
+        close: TokenKind,
+        close_description: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T, ConditionParseError>,
+    ) -> Result<Vec<T>, ConditionParseError> {
+        let mut items = Vec::new();
+        if self.eat(&close) {
+            return Ok(items);
+        }
+        loop {
+            items.push(item(self)?);
+            if self.eat(&close) {
+                return Ok(items);
+            }
+            if !self.eat(&TokenKind::Comma) {
+                return Err(self.unexpected(&format!("`,` or {close_description}")));
+            }
+        }
+    }
+
+    fn parse_clause(&mut self) -> Result<ConditionClause, ConditionParseError> {
+        let mut segments = match self.peek().kind.clone() {
+            TokenKind::Word(word) => word.split('.').map(str::to_owned).collect(),
+            TokenKind::Path(segments) => segments,
+            _ => return Err(self.unexpected("a field name, `not` or `(`")),
+        };
+        let field = self.advance();
+        let json_path = segments.split_off(1);
+        let column_name = segments.pop().unwrap_or_default();
+        if !(self.is_known_field)(&column_name) {
+            return Err(ConditionParseError::new(
+                format!("unknown field `{column_name}`"),
+                field.span.start..field.span.start + column_name.len(),
+            ));
+        }
+
+        let (operator, value) = match self.peek().kind.clone() {
+            TokenKind::Comparison(operator) => {
+                self.advance();
+                (operator, self.parse_value()?)
+            }
+            TokenKind::Word(word) => {
+                let operator = match word.to_ascii_lowercase().as_str() {
+                    "is" => None,
+                    "in" => Some(Operator::IsIn),
+                    "not" => Some(Operator::NotIn),
+                    "between" => Some(Operator::Between),
+                    "contains" => Some(Operator::Contains),
+                    "contains_ignore_case" => Some(Operator::ContainsIgnoreCase),
+                    "starts_with" => Some(Operator::StartsWith),
+                    "ends_with" => Some(Operator::EndsWith),
+                    "eq_ignore_case" => Some(Operator::EqualIgnoreCase),
+                    "json_contains" => Some(Operator::JsonContains),
+                    "array_contains" => Some(Operator::ArrayContains),
+                    "array_overlaps" => Some(Operator::ArrayOverlaps),
+                    "array_length" => Some(Operator::ArrayLength),
+                    _ => return Err(self.unexpected("an operator")),
+                };
+                self.advance();
+                match operator {
+                    None => {
+                        let operator = match self.eat_keyword("not") {
+                            true => Operator::IsNotNull,
+                            false => Operator::IsNull,
+                        };
+                        self.expect_keyword("null")?;
+                        (operator, IGNORED_VALUE)
+                    }
+                    Some(Operator::NotIn) => {
+                        self.expect_keyword("in")?;
+                        (Operator::NotIn, self.parse_value()?)
+                    }
+                    Some(Operator::Between) => (Operator::Between, self.parse_between()?),
+                    Some(operator) => (operator, self.parse_value()?),
+                }
+            }
+            _ => return Err(self.unexpected("an operator")),
+        };
+
+        Ok(ConditionClause {
+            column_name,
+            json_path,
+            operator,
+            value,
+        })
+    }
+
+    /// Parses either a two-element list or `low and high`.
+    fn parse_between(&mut self) -> Result<ConditionClauseValue, ConditionParseError> {
+        let start = self.peek().span.start;
+        let low = self.parse_value()?;
+        if is_list(&low) {
+            return Ok(low);
+        }
+        let low_span = start..self.previous_end();
+        self.expect_keyword("and")?;
+        let high_start = self.peek().span.start;
+        let high = self.parse_value()?;
+        let high_span = high_start..self.previous_end();
+        match (list_kind(&low), list_kind(&high)) {
+            (Some(kind), Some(high_kind)) if kind == high_kind => {
+                list_value(kind, vec![(low, low_span), (high, high_span)])
+            }
+            _ => Err(ConditionParseError::new(
+                format!("`between` bounds must have the same type, one of {LIST_TYPES}"),
+                start..self.previous_end(),
+            )),
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<ConditionClauseValue, ConditionParseError> {
+        let anchor = match &self.peek().kind {
+            TokenKind::Word(word) => TimeAnchor::from_keyword(word),
+            _ => None,
+        };
+        if let Some(anchor) = anchor {
+            self.advance();
+            return self.parse_relative_time(anchor);
+        }
+
+        let token = self.peek().clone();
+        let value = match token.kind {
+            TokenKind::String(string) => ConditionClauseValue::String(string),
+            TokenKind::Placeholder(name) => ConditionClauseValue::Placeholder(name),
+            TokenKind::TypedString(prefix, text) => typed_string(&prefix, &text)
+                .map_err(|message| ConditionParseError::new(message, token.span.clone()))?,
+            TokenKind::Number {
+                digits,
+                suffix,
+                is_float,
+            } => number(&digits, &suffix, is_float)
+                .map_err(|message| ConditionParseError::new(message, token.span.clone()))?,
+            TokenKind::Word(word) if word.eq_ignore_ascii_case("true") => {
+                ConditionClauseValue::Bool(true)
+            }
+            TokenKind::Word(word) if word.eq_ignore_ascii_case("false") => {
+                ConditionClauseValue::Bool(false)
+            }
+            TokenKind::Word(prefix) if self.peek_second().kind == TokenKind::LBracket => {
+                self.advance();
+                return self.parse_list(Some((prefix, token.span)));
+            }
+            TokenKind::LBracket => return self.parse_list(None),
+            _ => return Err(self.unexpected("a value")),
+        };
+        self.advance();
+        Ok(value)
+    }
+
+    /// Parses the optional `+ duration` or `- duration` following a time anchor.
+    fn parse_relative_time(
+        &mut self,
+        anchor: TimeAnchor,
+    ) -> Result<ConditionClauseValue, ConditionParseError> {
+        let negative = match self.peek().kind {
+            TokenKind::Plus => false,
+            TokenKind::Minus => true,
+            _ => return Ok(ConditionClauseValue::RelativeTime(anchor.into())),
+        };
+        self.advance();
+        let token = self.peek().clone();
+        let TokenKind::Word(text) = &token.kind else {
+            return Err(self.unexpected("an ISO 8601 duration like `P7D`"));
+        };
+        let duration = parse_iso_duration(text)
+            .map_err(|message| ConditionParseError::new(message, token.span.clone()))?;
+        self.advance();
+        let offset = if negative { -duration } else { duration };
+        Ok(ConditionClauseValue::RelativeTime(RelativeTime::new(
+            anchor, offset,
+        )))
+    }
+
+    fn parse_list(
+        &mut self,
+        prefix: Option<(String, Range<usize>)>,
+    ) -> Result<ConditionClauseValue, ConditionParseError> {
+        self.advance();
+        let items = self.parse_separated(TokenKind::RBracket, "`]`", |p| {
+            let start = p.peek().span.start;
+            let value = p.parse_value()?;
+            let span = start..p.previous_end();
+            match is_list(&value) {
+                true => Err(ConditionParseError::new("lists cannot be nested", span)),
+                false => Ok((value, span)),
+            }
+        })?;
+
+        let kind = match (&prefix, items.first()) {
+            (Some((prefix, span)), _) => LIST_KINDS
+                .iter()
+                .copied()
+                .find(|kind| prefix.eq_ignore_ascii_case(kind))
+                .ok_or_else(|| {
+                    ConditionParseError::new(format!("unknown list type `{prefix}`"), span.clone())
+                })?,
+            (None, None) => "i64",
+            (None, Some((first, span))) => list_kind(first).ok_or_else(|| {
+                ConditionParseError::new(
+                    format!("lists can only hold {LIST_TYPES} values"),
+                    span.clone(),
+                )
+            })?,
+        };
+        list_value(kind, items)
+    }
+}
+
+/// Types of list values and `between` bounds, as written in front of a list.
+const LIST_KINDS: &[&str] = &[
+    "u8", "i32", "i64", "uuid", "string", "decimal", "date", "datetime", "time",
+];
+const LIST_TYPES: &str = "u8, i32, i64, uuid, string, decimal, date, datetime or time";
+
+/// The list type `value` is an element of, if any.
+fn list_kind(value: &ConditionClauseValue) -> Option<&'static str> {
+    Some(match value {
+        ConditionClauseValue::U8(_) => "u8",
+        ConditionClauseValue::I32(_) => "i32",
+        ConditionClauseValue::I64(_) => "i64",
+        ConditionClauseValue::Uuid(_) => "uuid",
+        ConditionClauseValue::String(_) => "string",
+        ConditionClauseValue::Decimal(_) => "decimal",
+        ConditionClauseValue::Date(_) => "date",
+        ConditionClauseValue::OffsetDateTime(_) => "datetime",
+        ConditionClauseValue::Time(_) => "time",
+        _ => return None,
+    })
+}
+
+/// Converts the items to a list of type `kind`. Decimals, dates, date-times and times have no
+/// list value of their own and are kept as strings, which the field converts.
+fn list_value(
+    kind: &str,
+    items: Vec<(ConditionClauseValue, Range<usize>)>,
+) -> Result<ConditionClauseValue, ConditionParseError> {
+    fn convert_all<T>(
+        items: Vec<(ConditionClauseValue, Range<usize>)>,
+        expected: &str,
+        convert: impl Fn(ConditionClauseValue) -> Option<T>,
+    ) -> Result<Vec<T>, ConditionParseError> {
+        items
+            .into_iter()
+            .map(|(value, span)| {
+                convert(value)
+                    .ok_or_else(|| ConditionParseError::new(format!("expected {expected}"), span))
+            })
+            .collect()
+    }
+
+    /// Keeps strings which parse as a literal of type `prefix`.
+    fn checked(prefix: &str, string: String) -> Option<String> {
+        typed_string(prefix, &string).is_ok().then_some(string)
+    }
+
+    Ok(match kind {
+        "u8" => ConditionClauseValue::U8Vec(convert_all(items, "a u8", |value| {
+            value.as_integer().and_then(|num| num.try_into().ok())
+        })?),
+        "i32" => ConditionClauseValue::I32Vec(convert_all(items, "an i32", |value| {
+            value.as_integer().and_then(|num| num.try_into().ok())
+        })?),
+        "i64" => ConditionClauseValue::I64Vec(convert_all(items, "an i64", |value| {
+            value.as_integer().and_then(|num| num.try_into().ok())
+        })?),
+        "uuid" => {
+            ConditionClauseValue::UuidVec(convert_all(items, "a uuid", |value| match value {
+                ConditionClauseValue::Uuid(uuid) => Some(uuid),
+                ConditionClauseValue::String(string) => uuid::Uuid::parse_str(&string).ok(),
+                _ => None,
+            })?)
+        }
+        "decimal" => {
+            ConditionClauseValue::StringVec(convert_all(items, "a decimal", |value| match value {
+                ConditionClauseValue::Decimal(num) => Some(num.to_string()),
+                ConditionClauseValue::String(string) => string
+                    .parse::<rust_decimal::Decimal>()
+                    .is_ok()
+                    .then_some(string),
+                value => value.as_integer().map(|num| num.to_string()),
+            })?)
+        }
+        "date" => {
+            ConditionClauseValue::StringVec(convert_all(items, "a date", |value| match value {
+                ConditionClauseValue::Date(date) => Some(DateText(date).to_string()),
+                ConditionClauseValue::String(string) => checked("date", string),
+                _ => None,
+            })?)
+        }
+        "datetime" => ConditionClauseValue::StringVec(convert_all(
+            items,
+            "a date-time",
+            |value| match value {
+                ConditionClauseValue::OffsetDateTime(date_time) => date_time.format(&Rfc3339).ok(),
+                ConditionClauseValue::String(string) => checked("datetime", string),
+                _ => None,
+            },
+        )?),
+        "time" => {
+            ConditionClauseValue::StringVec(convert_all(items, "a time", |value| match value {
+                ConditionClauseValue::Time(time) => Some(TimeText(time).to_string()),
+                ConditionClauseValue::String(string) => checked("time", string),
+                _ => None,
+            })?)
+        }
+        _ => {
+            ConditionClauseValue::StringVec(convert_all(items, "a string", |value| match value {
+                ConditionClauseValue::String(string) => Some(string),
+                _ => None,
+            })?)
+        }
+    })
+}
+
+fn is_list(value: &ConditionClauseValue) -> bool {
+    matches!(
+        value,
+        ConditionClauseValue::U8Vec(_)
+            | ConditionClauseValue::I32Vec(_)
+            | ConditionClauseValue::I64Vec(_)
+            | ConditionClauseValue::UuidVec(_)
+            | ConditionClauseValue::StringVec(_)
+    )
+}
+
+fn number(digits: &str, suffix: &str, is_float: bool) -> Result<ConditionClauseValue, String> {
+    fn parse<T: FromStr>(digits: &str, suffix: &str, ty: &str) -> Result<T, String> {
+        digits
+            .parse::<T>()
+            .map_err(|_| format!("`{digits}{suffix}` is not a valid {ty}"))
+    }
+
+    Ok(match suffix {
+        "f32" => ConditionClauseValue::F32(parse(digits, suffix, "f32")?),
+        "f64" => ConditionClauseValue::F64(parse(digits, suffix, "f64")?),
+        "dec" => ConditionClauseValue::Decimal(parse(digits, suffix, "decimal")?),
+        "" if is_float => ConditionClauseValue::F64(parse(digits, suffix, "f64")?),
+        _ if is_float => return Err(format!("`{digits}{suffix}` is not a valid number")),
+        "" | "i64" => ConditionClauseValue::I64(parse(digits, suffix, "i64")?),
+        "i8" => ConditionClauseValue::I8(parse(digits, suffix, "i8")?),
+        "i16" => ConditionClauseValue::I16(parse(digits, suffix, "i16")?),
+        "i32" => ConditionClauseValue::I32(parse(digits, suffix, "i32")?),
+        "i128" => ConditionClauseValue::I128(parse(digits, suffix, "i128")?),
+        "u8" => ConditionClauseValue::U8(parse(digits, suffix, "u8")?),
+        "u16" => ConditionClauseValue::U16(parse(digits, suffix, "u16")?),
+        "u32" => ConditionClauseValue::U32(parse(digits, suffix, "u32")?),
+        "u64" => ConditionClauseValue::U64(parse(digits, suffix, "u64")?),
+        "u128" => ConditionClauseValue::U128(parse(digits, suffix, "u128")?),
+        _ => return Err(format!("unknown number suffix `{suffix}`")),
+    })
+}
+
+fn typed_string(prefix: &str, text: &str) -> Result<ConditionClauseValue, String> {
+    match prefix.to_ascii_lowercase().as_str() {
+        "uuid" => uuid::Uuid::parse_str(text)
+            .map(ConditionClauseValue::Uuid)
+            .map_err(|err| format!("invalid uuid: {err}")),
+        "datetime" => time::OffsetDateTime::parse(text, &Rfc3339)
+            .map(ConditionClauseValue::OffsetDateTime)
+            .map_err(|err| format!("invalid RFC 3339 date-time: {err}")),
+        "local_datetime" => time::PrimitiveDateTime::parse(text, &Iso8601::DEFAULT)
+            .map(ConditionClauseValue::PrimitiveDateTime)
+            .map_err(|err| format!("invalid local date-time: {err}")),
+        "date" => time::Date::parse(text, &Iso8601::DEFAULT)
+            .map(ConditionClauseValue::Date)
+            .map_err(|err| format!("invalid date: {err}")),
+        "time" => time::Time::parse(text, &Iso8601::DEFAULT)
+            .map(ConditionClauseValue::Time)
+            .map_err(|err| format!("invalid time: {err}")),
+        "duration" => parse_iso_duration(text)
+            .map(|duration| ConditionClauseValue::Duration(TimeDuration(duration))),
+        "json" => serde_json::from_str(text)
+            .map(ConditionClauseValue::Json)
+            .map_err(|err| format!("invalid json: {err}")),
+        "f32" => text
+            .parse()
+            .map(ConditionClauseValue::F32)
+            .map_err(|_| format!("`{text}` is not a valid f32")),
+        "f64" => text
+            .parse()
+            .map(ConditionClauseValue::F64)
+            .map_err(|_| format!("`{text}` is not a valid f64")),
+        _ => Err(format!("unknown literal type `{prefix}`")),
+    }
+}
+
+/// Parses an ISO 8601 duration made of weeks, days, hours, minutes and (fractional) seconds,
+/// e.g. `P7D`, `PT1H30M` or `-PT0.5S`. Years and months are rejected, as their length varies.
+pub(crate) fn parse_iso_duration(text: &str) -> Result<time::Duration, String> {
+    const DATE_UNITS: &[(char, i64)] = &[('W', 604_800), ('D', 86_400)];
+    const TIME_UNITS: &[(char, i64)] = &[('H', 3_600), ('M', 60), ('S', 1)];
+
+    let invalid = || format!("`{text}` is not a valid ISO 8601 duration, e.g. `P7D` or `PT1H30M`");
+    let (negative, unsigned) = match text.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, text),
+    };
+    let designators = unsigned.strip_prefix('P').ok_or_else(invalid)?;
+    let (date_part, time_part) = match designators.split_once('T') {
+        Some((_, "")) => return Err(invalid()),
+        Some((date_part, time_part)) => (date_part, time_part),
+        None => (designators, ""),
+    };
+
+    let mut total = time::Duration::ZERO;
+    let mut is_empty = true;
+    for (mut part, units) in [(date_part, DATE_UNITS), (time_part, TIME_UNITS)] {
+        let mut next_unit = 0;
+        while !part.is_empty() {
+            let end = part
+                .find(|c: char| c.is_ascii_alphabetic())
+                .ok_or_else(invalid)?;
+            let unit = part[end..].chars().next().ok_or_else(invalid)?;
+            let index = units[next_unit..]
+                .iter()
+                .position(|(designator, _)| *designator == unit)
+                .ok_or_else(invalid)?
+                + next_unit;
+            next_unit = index + 1;
+
+            let (whole, fraction) = part[..end].split_once('.').unwrap_or((&part[..end], ""));
+            let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+            if whole.is_empty()
+                || !is_digits(whole)
+                || !is_digits(fraction)
+                || fraction.len() > 9
+                || (!fraction.is_empty() && unit != 'S')
+            {
+                return Err(invalid());
+            }
+            let seconds = whole
+                .parse::<i64>()
+                .ok()
+                .and_then(|whole| whole.checked_mul(units[index].1))
+                .ok_or_else(invalid)?;
+            let nanoseconds = format!("{fraction:0<9}")
+                .parse::<i64>()
+                .map_err(|_| invalid())?;
+            total = total
+                .checked_add(time::Duration::new(seconds, nanoseconds as i32))
+                .ok_or_else(invalid)?;
+            is_empty = false;
+            part = &part[end + 1..];
+        }
+    }
+    if is_empty {
+        return Err(invalid());
+    }
+    Ok(if negative { -total } else { total })
+}
+
+// =============================================================================
+// Printer
+// =============================================================================
+
+impl Display for Condition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Condition::All(elements) => match elements.as_slice() {
+                [ConditionElement::Clause(clause)] => clause.fmt(f),
+                [_, _, ..] => fmt_chain(elements, " and ", f),
+                _ => fmt_call("all", elements, f),
+            },
+            Condition::Any(elements) if elements.len() >= 2 => fmt_chain(elements, " or ", f),
+            condition => fmt_operand(condition, f),
+        }
+    }
+}
+
+/// Formats `condition` so that it parses back as a single operand of `and`, `or` or `not`.
+fn fmt_operand(condition: &Condition, f: &mut Formatter<'_>) -> fmt::Result {
+    match condition {
+        Condition::All(elements) => match elements.as_slice() {
+            [ConditionElement::Clause(clause)] => write!(f, "({clause})"),
+            [_, _, ..] => {
+                f.write_char('(')?;
+                fmt_chain(elements, " and ", f)?;
+                f.write_char(')')
+            }
+            _ => fmt_call("all", elements, f),
+        },
+        Condition::Any(elements) if elements.len() >= 2 => {
+            f.write_char('(')?;
+            fmt_chain(elements, " or ", f)?;
+            f.write_char(')')
+        }
+        Condition::Any(elements) => fmt_call("any", elements, f),
+        Condition::Not(inner) => {
+            f.write_str("not ")?;
+            match inner.as_ref() {
+                Condition::All(elements) => match elements.as_slice() {
+                    [ConditionElement::Clause(clause)] => clause.fmt(f),
+                    _ => fmt_operand(inner, f),
+                },
+                inner => fmt_operand(inner, f),
+            }
+        }
+    }
+}
+
+fn fmt_element(element: &ConditionElement, f: &mut Formatter<'_>) -> fmt::Result {
+    match element {
+        ConditionElement::Clause(clause) => clause.fmt(f),
+        ConditionElement::Condition(condition) => fmt_operand(condition, f),
+    }
+}
+
+fn fmt_chain(elements: &[ConditionElement], separator: &str, f: &mut Formatter<'_>) -> fmt::Result {
+    for (i, element) in elements.iter().enumerate() {
+        if i > 0 {
+            f.write_str(separator)?;
+        }
+        fmt_element(element, f)?;
+    }
+    Ok(())
+}
+
+fn fmt_call(name: &str, elements: &[ConditionElement], f: &mut Formatter<'_>) -> fmt::Result {
+    write!(f, "{name}(")?;
+    fmt_chain(elements, ", ", f)?;
+    f.write_char(')')
+}
+
+impl Display for ConditionClause {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let column_name = JsonPathText(self);
+        let value = Literal(&self.value);
+        let operator = match self.operator {
+            Operator::IsNull => return write!(f, "{column_name} is null"),
+            Operator::IsNotNull => return write!(f, "{column_name} is not null"),
+            Operator::Between => {
+                if let Some((low, high)) = between_bounds(&self.value) {
+                    return write!(
+                        f,
+                        "{column_name} between {} and {}",
+                        Literal(&low),
+                        Literal(&high)
+                    );
+                }
+                "between"
+            }
+            Operator::Equal => "=",
+            Operator::NotEqual => "!=",
+            Operator::Less => "<",
+            Operator::LessOrEqual => "<=",
+            Operator::Greater => ">",
+            Operator::GreaterOrEqual => ">=",
+            Operator::IsIn => "in",
+            Operator::NotIn => "not in",
+            Operator::Contains => "contains",
+            Operator::ContainsIgnoreCase => "contains_ignore_case",
+            Operator::StartsWith => "starts_with",
+            Operator::EndsWith => "ends_with",
+            Operator::EqualIgnoreCase => "eq_ignore_case",
+            Operator::JsonContains => "json_contains",
+            Operator::ArrayContains => "array_contains",
+            Operator::ArrayOverlaps => "array_overlaps",
+            Operator::ArrayLength => "array_length",
+        };
+        write!(f, "{column_name} {operator} {value}")
+    }
+}
+
+/// Formats the column of a clause, followed by its Json path.
+struct JsonPathText<'a>(&'a ConditionClause);
+
+impl Display for JsonPathText<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.column_name)?;
+        for key in &self.0.json_path {
+            f.write_char('.')?;
+            match !key.is_empty() && key.chars().all(is_word_char) {
+                true => f.write_str(key)?,
+                false => fmt_string(key, f)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Splits a two-element list into the bounds of a `between` clause.
+fn between_bounds(
+    value: &ConditionClauseValue,
+) -> Option<(ConditionClauseValue, ConditionClauseValue)> {
+    fn pair<T: Clone>(
+        values: &[T],
+        wrap: fn(T) -> ConditionClauseValue,
+    ) -> Option<(ConditionClauseValue, ConditionClauseValue)> {
+        match values {
+            [low, high] => Some((wrap(low.clone()), wrap(high.clone()))),
+            _ => None,
+        }
+    }
+
+    match value {
+        ConditionClauseValue::U8Vec(values) => pair(values, ConditionClauseValue::U8),
+        ConditionClauseValue::I32Vec(values) => pair(values, ConditionClauseValue::I32),
+        ConditionClauseValue::I64Vec(values) => pair(values, ConditionClauseValue::I64),
+        ConditionClauseValue::UuidVec(values) => pair(values, ConditionClauseValue::Uuid),
+        ConditionClauseValue::StringVec(values) => pair(values, ConditionClauseValue::String),
+        _ => None,
+    }
+}
+
+/// Formats a clause value in the textual syntax.
+struct Literal<'a>(&'a ConditionClauseValue);
+
+impl Display for Literal<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            ConditionClauseValue::Bool(value) => write!(f, "{value}"),
+            ConditionClauseValue::U8(value) => write!(f, "{value}u8"),
+            ConditionClauseValue::U16(value) => write!(f, "{value}u16"),
+            ConditionClauseValue::U32(value) => write!(f, "{value}u32"),
+            ConditionClauseValue::U64(value) => write!(f, "{value}u64"),
+            ConditionClauseValue::U128(value) => write!(f, "{value}u128"),
+            ConditionClauseValue::I8(value) => write!(f, "{value}i8"),
+            ConditionClauseValue::I16(value) => write!(f, "{value}i16"),
+            ConditionClauseValue::I32(value) => write!(f, "{value}i32"),
+            ConditionClauseValue::I64(value) => write!(f, "{value}"),
+            ConditionClauseValue::I128(value) => write!(f, "{value}i128"),
+            ConditionClauseValue::F32(value) if value.is_finite() => write!(f, "{value:?}f32"),
+            ConditionClauseValue::F32(value) => fmt_typed_string("f32", &value.to_string(), f),
+            ConditionClauseValue::F64(value) if value.is_finite() => write!(f, "{value:?}"),
+            ConditionClauseValue::F64(value) => fmt_typed_string("f64", &value.to_string(), f),
+            ConditionClauseValue::Decimal(value) => write!(f, "{value}dec"),
+            ConditionClauseValue::String(value) => fmt_string(value, f),
+            ConditionClauseValue::Json(value) => fmt_typed_string("json", &value.to_string(), f),
+            ConditionClauseValue::Uuid(value) => fmt_typed_string("uuid", &value.to_string(), f),
+            ConditionClauseValue::PrimitiveDateTime(value) => {
+                let text = format!("{}T{}", DateText(value.date()), TimeText(value.time()));
+                fmt_typed_string("local_datetime", &text, f)
+            }
+            ConditionClauseValue::OffsetDateTime(value) => {
+                let text = value.format(&Rfc3339).map_err(|_| fmt::Error)?;
+                fmt_typed_string("datetime", &text, f)
+            }
+            ConditionClauseValue::Date(value) => {
+                fmt_typed_string("date", &DateText(*value).to_string(), f)
+            }
+            ConditionClauseValue::Time(value) => {
+                fmt_typed_string("time", &TimeText(*value).to_string(), f)
+            }
+            ConditionClauseValue::Duration(TimeDuration(value)) => {
+                fmt_typed_string("duration", &IsoDuration(*value).to_string(), f)
+            }
+            ConditionClauseValue::RelativeTime(value) => value.fmt(f),
+            ConditionClauseValue::Placeholder(name) => write!(f, "${name}"),
+            ConditionClauseValue::U8Vec(values) => fmt_list("u8", values, f),
+            ConditionClauseValue::I32Vec(values) => fmt_list("i32", values, f),
+            ConditionClauseValue::I64Vec(values) => fmt_list("", values, f),
+            ConditionClauseValue::UuidVec(values) => {
+                let values = values.iter().map(|uuid| StringText(uuid.to_string()));
+                fmt_list("uuid", values, f)
+            }
+            ConditionClauseValue::StringVec(values) if values.is_empty() => f.write_str("string[]"),
+            ConditionClauseValue::StringVec(values) => {
+                fmt_list("", values.iter().map(StringText), f)
+            }
+        }
+    }
+}
+
+fn fmt_list<T: Display>(
+    prefix: &str,
+    values: impl IntoIterator<Item = T>,
+    f: &mut Formatter<'_>,
+) -> fmt::Result {
+    write!(f, "{prefix}[")?;
+    for (i, value) in values.into_iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        value.fmt(f)?;
+    }
+    f.write_char(']')
+}
+
+fn fmt_typed_string(prefix: &str, text: &str, f: &mut Formatter<'_>) -> fmt::Result {
+    f.write_str(prefix)?;
+    fmt_string(text, f)
+}
+
+fn fmt_string(text: &str, f: &mut Formatter<'_>) -> fmt::Result {
+    f.write_char('"')?;
+    for c in text.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+struct StringText<S>(S);
+
+impl<S: AsRef<str>> Display for StringText<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_string(self.0.as_ref(), f)
+    }
+}
+
+struct DateText(time::Date);
+
+impl Display for DateText {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (year, month, day) = self.0.to_calendar_date();
+        write!(f, "{year:04}-{:02}-{day:02}", month as u8)
+    }
+}
+
+/// Formats a time as `hh:mm:ss`, followed by the fraction of the second if there is one.
+pub(crate) struct TimeText(pub time::Time);
+
+impl Display for TimeText {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (hour, minute, second, nanosecond) = self.0.as_hms_nano();
+        write!(f, "{hour:02}:{minute:02}:{second:02}")?;
+        fmt_fraction(nanosecond, f)
+    }
+}
+
+/// Formats a duration in the ISO 8601 form accepted by [`parse_iso_duration`].
+pub(crate) struct IsoDuration(pub time::Duration);
+
+impl Display for IsoDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.0.is_negative() {
+            f.write_char('-')?;
+        }
+        let duration = self.0.abs();
+        let total_seconds = duration.whole_seconds();
+        let nanoseconds = duration.subsec_nanoseconds() as u32;
+        let days = total_seconds / 86_400;
+        let hours = total_seconds % 86_400 / 3_600;
+        let minutes = total_seconds % 3_600 / 60;
+        let seconds = total_seconds % 60;
+
+        f.write_char('P')?;
+        if days != 0 {
+            write!(f, "{days}D")?;
+        }
+        let has_seconds = seconds != 0 || nanoseconds != 0;
+        if hours == 0 && minutes == 0 && !has_seconds && days != 0 {
+            return Ok(());
+        }
+        f.write_char('T')?;
+        if hours != 0 {
+            write!(f, "{hours}H")?;
+        }
+        if minutes != 0 {
+            write!(f, "{minutes}M")?;
+        }
+        if has_seconds || (hours == 0 && minutes == 0) {
+            write!(f, "{seconds}")?;
+            fmt_fraction(nanoseconds, f)?;
+            f.write_char('S')?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes `.nnn` with trailing zeros removed, or nothing for whole seconds.
+fn fmt_fraction(nanoseconds: u32, f: &mut Formatter<'_>) -> fmt::Result {
+    if nanoseconds == 0 {
+        return Ok(());
+    }
+    let fraction = format!("{nanoseconds:09}");
+    write!(f, ".{}", fraction.trim_end_matches('0'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::condition::Condition;
+    use assertr::prelude::*;
+
+    fn assert_round_trip(condition: Condition) {
+        let text = condition.to_string();
+        assert_that(Condition::parse(&text)).is_equal_to(Ok(condition));
+    }
+
+    #[test]
+    fn parses_example_filter() {
+        let condition =
+            Condition::parse(r#"status = "open" and (priority >= 3 or assignee is null)"#);
+
+        assert_that(condition).is_equal_to(Ok(Condition::all()
+            .eq("status", "open")
+            .and(Condition::any().gte("priority", 3i64).is_null("assignee"))));
+    }
+
+    #[test]
+    fn and_binds_stronger_than_or() {
+        let condition = Condition::parse("a = 1 or b = 2 and not c = 3");
+
+        assert_that(condition).is_equal_to(Ok(Condition::any().eq("a", 1i64).or(Condition::all()
+            .eq("b", 2i64)
+            .and(Condition::negate(Condition::all().eq("c", 3i64))))));
+    }
+
+    #[test]
+    fn parses_typed_values() {
+        let condition = Condition::parse(
+            r#"a in i32[1, 2] and b between "a" and "m" or c = duration"PT1H30M""#,
+        );
+
+        assert_that(condition.map(|condition| condition.to_string())).is_equal_to(Ok(
+            r#"(a in i32[1, 2] and b between "a" and "m") or c = duration"PT1H30M""#.to_owned(),
+        ));
+    }
+
+    #[test]
+    fn prints_and_reparses_conditions() {
+        assert_round_trip(
+            Condition::parse(r#"status = "open" and (priority >= 3 or assignee is null)"#).unwrap(),
+        );
+        assert_round_trip(Condition::all());
+        assert_round_trip(Condition::any());
+        assert_round_trip(Condition::any().eq("a", 1i8));
+        let mut nested = Condition::all();
+        nested.push_condition(Condition::all().eq("a", 1u64));
+        assert_round_trip(nested);
+        assert_round_trip(!(Condition::all().eq("a", 1u16).lt("b", -2.5f64)));
+        assert_round_trip(
+            Condition::all()
+                .in_("ids", vec![1u8, 2u8])
+                .not_in("names", vec!["x \"y\"".to_owned()])
+                .between("score", 1i32, 5i32)
+                .eq("id", uuid::Uuid::nil())
+                .eq("data", serde_json::json!({"a": [1, 2]}))
+                .gte(
+                    "since",
+                    time::Date::from_calendar_date(2024, time::Month::March, 1).unwrap(),
+                )
+                .lt("until", time::OffsetDateTime::UNIX_EPOCH)
+                .gte("opens", time::Time::from_hms_milli(8, 30, 0, 250).unwrap())
+                .eq(
+                    "local",
+                    time::OffsetDateTime::UNIX_EPOCH
+                        .date()
+                        .with_hms_nano(1, 2, 3, 400)
+                        .unwrap(),
+                )
+                .gt("elapsed", time::Duration::seconds(-90_061))
+                .ne("ratio", f64::INFINITY)
+                .lte("price", rust_decimal::Decimal::new(-1999, 2))
+                .gte(
+                    "created",
+                    RelativeTime::new(TimeAnchor::Now, -time::Duration::days(7)),
+                )
+                .lt("due", RelativeTime::from(TimeAnchor::EndOfMonth))
+                .eq(
+                    "tenant_id",
+                    ConditionClauseValue::Placeholder("tenant_id".to_owned()),
+                )
+                .contains_ignore_case("title", "Foo")
+                .json_path("metadata", ["priority"], Operator::GreaterOrEqual, 2)
+                .json_contains("metadata", serde_json::json!({"tags": ["urgent"]}))
+                .array_overlaps("tags", vec!["a".to_owned(), "b".to_owned()])
+                .array_length("tags", 0)
+                .is_not_null("deleted_at"),
+        );
+    }
+
+    #[test]
+    fn parses_json_paths() {
+        let condition = Condition::parse_with_fields(
+            r#"metadata.customer.country = "DE" and metadata.tags json_contains "urgent""#,
+            |name| name == "metadata",
+        );
+
+        assert_that(condition).is_equal_to(Ok(Condition::all()
+            .json_path("metadata", ["customer", "country"], Operator::Equal, "DE")
+            .json_path("metadata", ["tags"], Operator::JsonContains, "urgent")));
+        assert_that(Condition::parse_with_fields("typo.country = 1", |name| {
+            name == "metadata"
+        }))
+        .is_equal_to(Err(ConditionParseError::new("unknown field `typo`", 0..4)));
+    }
+
+    #[test]
+    fn quotes_json_keys_that_are_not_words() {
+        let condition = Condition::all()
+            .json_path(
+                "metadata",
+                ["customer", "order-id", "first name", "a.b", ""],
+                Operator::Equal,
+                "x",
+            )
+            .json_path("metadata", ["plain_key", "0"], Operator::Equal, 1i64);
+
+        assert_that(condition.to_string()).is_equal_to(
+            r#"metadata.customer."order-id"."first name"."a.b"."" = "x" and metadata.plain_key.0 = 1"#
+                .to_owned(),
+        );
+        assert_round_trip(condition);
+    }
+
+    #[test]
+    fn parses_date_and_decimal_lists_and_ranges() {
+        let condition = Condition::parse(
+            r#"due between date"2024-01-01" and date"2024-12-31" and price in decimal[1.5dec, 2, "3.25"] and opens in [time"08:30:00"] and at between datetime"2024-01-01T00:00:00Z" and datetime"2024-02-01T00:00:00Z""#,
+        );
+
+        let expected = Condition::all()
+            .between("due", "2024-01-01".to_owned(), "2024-12-31".to_owned())
+            .in_(
+                "price",
+                vec!["1.5".to_owned(), "2".to_owned(), "3.25".to_owned()],
+            )
+            .in_("opens", vec!["08:30:00".to_owned()])
+            .between(
+                "at",
+                "2024-01-01T00:00:00Z".to_owned(),
+                "2024-02-01T00:00:00Z".to_owned(),
+            );
+        assert_that(condition).is_equal_to(Ok(expected.clone()));
+        assert_round_trip(expected);
+        assert_that(Condition::parse(r#"due between date"2024-01-01" and 3"#)).is_equal_to(Err(
+            ConditionParseError::new(
+                "`between` bounds must have the same type, one of u8, i32, i64, uuid, string, decimal, date, datetime or time",
+                12..34,
+            ),
+        ));
+        assert_that(Condition::parse(r#"due in date["tomorrow"]"#))
+            .is_equal_to(Err(ConditionParseError::new("expected a date", 12..22)));
+    }
+
+    #[test]
+    fn reports_unknown_fields_with_their_span() {
+        let condition = Condition::parse_with_fields("a = 1 and typo = 2", |name| name == "a");
+
+        assert_that(condition).is_equal_to(Err(ConditionParseError::new(
+            "unknown field `typo`",
+            10..14,
+        )));
+    }
+
+    #[test]
+    fn reports_syntax_errors_with_their_span() {
+        assert_that(Condition::parse("a = 1 and (b = 2")).is_equal_to(Err(
+            ConditionParseError::new("expected `and`, `or` or `)`, found end of input", 16..16),
+        ));
+        assert_that(Condition::parse("a = 300u8")).is_equal_to(Err(ConditionParseError::new(
+            "`300u8` is not a valid u8",
+            4..9,
+        )));
+        assert_that(Condition::parse("a ~ 1")).is_equal_to(Err(ConditionParseError::new(
+            "unexpected character `~`",
+            2..3,
+        )));
+        assert_that(Condition::parse("a = $1")).is_equal_to(Err(ConditionParseError::new(
+            "expected a placeholder name after `$`",
+            4..6,
+        )));
+    }
+
+    #[test]
+    fn parses_iso_durations() {
+        assert_that(parse_iso_duration("P1W2DT3H4M5.5S")).is_equal_to(Ok(time::Duration::new(
+            9 * 86_400 + 3 * 3_600 + 4 * 60 + 5,
+            500_000_000,
+        )));
+        assert_that(parse_iso_duration("-PT0S")).is_equal_to(Ok(time::Duration::ZERO));
+        assert_that(parse_iso_duration("P1M")).is_err();
+        assert_that(parse_iso_duration("PT")).is_err();
+        assert_that(IsoDuration(time::Duration::days(7)).to_string()).is_equal_to("P7D".to_owned());
+    }
+}
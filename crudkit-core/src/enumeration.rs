@@ -0,0 +1,57 @@
+//! Rust enums as field values.
+//!
+//! Fieldless enums deriving `CkEnum` (see `crudkit_core_macros::CkEnum`) are stored in
+//! `Value::Enum` by the serde names of their variants. Storage backends persist these names, e.g.
+//! in a text column or a native Postgres enum type whose labels are the serde names.
+
+use crate::condition::ConditionClauseValue;
+
+/// A variant of a [`CkEnum`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EnumVariant {
+    /// The serde name of the variant, e.g. `in_progress`. This is the stored value.
+    pub name: &'static str,
+
+    /// Human-readable name of the variant, e.g. `In progress`.
+    pub label: &'static str,
+}
+
+/// A fieldless enum usable as a field value.
+///
+/// Implemented by the `CkEnum` derive macro.
+pub trait CkEnum: Sized + 'static {
+    /// All variants, in declaration order.
+    fn variants() -> &'static [EnumVariant];
+
+    /// The serde name of this variant.
+    fn variant_name(&self) -> &'static str;
+
+    /// The variant with the given serde name.
+    fn from_variant_name(name: &str) -> Option<Self>;
+
+    /// The label of this variant.
+    fn variant_label(&self) -> &'static str {
+        let name = self.variant_name();
+        Self::variants()
+            .iter()
+            .find(|variant| variant.name == name)
+            .map_or(name, |variant| variant.label)
+    }
+}
+
+impl<E: CkEnum> From<E> for ConditionClauseValue {
+    fn from(value: E) -> Self {
+        Self::String(value.variant_name().to_owned())
+    }
+}
+
+impl<E: CkEnum> From<Vec<E>> for ConditionClauseValue {
+    fn from(values: Vec<E>) -> Self {
+        Self::StringVec(
+            values
+                .iter()
+                .map(|value| value.variant_name().to_owned())
+                .collect(),
+        )
+    }
+}
@@ -8,6 +8,7 @@
 //! - **`id`**: Type-safe entity identifiers with composite primary key support
 //! - **`resource`**: Resource naming types
 //! - **`condition`**: Query filtering DSL
+//! - **`enumeration`**: Rust enums as field values
 //! - **`validation`**: Entity validation framework with severity levels
 //! - **`collaboration`**: Types for multi-user collaboration via WebSocket
 //!
@@ -17,11 +18,13 @@
 
 pub mod collaboration;
 pub mod condition;
+pub mod enumeration;
 pub mod id;
 pub mod resource;
 pub mod validation;
 
 // Re-export commonly used types at crate root.
+pub use enumeration::{CkEnum, EnumVariant};
 pub use id::{HasId, Id, IdField, IdValue, SerializableId, SerializableIdEntry};
 pub use resource::ResourceName;
 pub use validation::{
@@ -34,7 +37,7 @@ use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::fmt::Debug;
 use std::hash::Hash;
-use time::format_description::well_known::Rfc3339;
+use time::format_description::well_known::{Iso8601, Rfc3339};
 use utoipa::openapi::Type;
 use utoipa::ToSchema;
 
@@ -104,6 +107,130 @@ pub enum Order {
     Desc,
 }
 
+impl Order {
+    /// The opposite direction.
+    pub fn reversed(self) -> Self {
+        match self {
+            Order::Asc => Order::Desc,
+            Order::Desc => Order::Asc,
+        }
+    }
+}
+
+/// Where null values are placed when ordering.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, ToSchema, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NullsOrder {
+    First,
+    Last,
+}
+
+/// How text values are compared when ordering. Only applies to text fields.
+#[derive(Default, PartialEq, Eq, Hash, Clone, Debug, ToSchema, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TextOrder {
+    /// The collation of the column.
+    #[default]
+    Default,
+    /// Compares lowercased values.
+    CaseInsensitive,
+    /// Compares using the named collation, e.g. `und-x-icu` or `de-DE-x-icu` in Postgres.
+    /// Servers only accept the collations their resource allows.
+    Collation(String),
+}
+
+impl TextOrder {
+    pub fn is_default(&self) -> bool {
+        matches!(self, TextOrder::Default)
+    }
+}
+
+/// How a field is ordered: its direction, where nulls go and how text is compared.
+///
+/// Deserializes from a plain [`Order`] as well, so `{"name": "asc"}` stays a valid ordering.
+#[derive(Default, PartialEq, Eq, Hash, Clone, Debug, ToSchema, Serialize, Deserialize)]
+#[serde(from = "OrderSpecRepr")]
+pub struct OrderSpec {
+    pub order: Order,
+
+    /// Placement of nulls. `None` leaves it to the database, which in Postgres places nulls
+    /// last in ascending and first in descending order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<NullsOrder>,
+
+    #[serde(default, skip_serializing_if = "TextOrder::is_default")]
+    pub text: TextOrder,
+}
+
+impl OrderSpec {
+    pub fn asc() -> Self {
+        Order::Asc.into()
+    }
+
+    pub fn desc() -> Self {
+        Order::Desc.into()
+    }
+
+    pub fn nulls_first(mut self) -> Self {
+        self.nulls = Some(NullsOrder::First);
+        self
+    }
+
+    pub fn nulls_last(mut self) -> Self {
+        self.nulls = Some(NullsOrder::Last);
+        self
+    }
+
+    pub fn case_insensitive(mut self) -> Self {
+        self.text = TextOrder::CaseInsensitive;
+        self
+    }
+
+    pub fn collation(mut self, collation: impl Into<String>) -> Self {
+        self.text = TextOrder::Collation(collation.into());
+        self
+    }
+
+    /// This ordering in the opposite direction, keeping the placement of nulls and the text
+    /// comparison.
+    pub fn reversed(mut self) -> Self {
+        self.order = self.order.reversed();
+        self
+    }
+}
+
+impl From<Order> for OrderSpec {
+    fn from(order: Order) -> Self {
+        Self {
+            order,
+            nulls: None,
+            text: TextOrder::Default,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OrderSpecRepr {
+    Order(Order),
+    Spec {
+        order: Order,
+        #[serde(default)]
+        nulls: Option<NullsOrder>,
+        #[serde(default)]
+        text: TextOrder,
+    },
+}
+
+impl From<OrderSpecRepr> for OrderSpec {
+    fn from(repr: OrderSpecRepr) -> Self {
+        match repr {
+            OrderSpecRepr::Order(order) => order.into(),
+            OrderSpecRepr::Spec { order, nulls, text } => Self { order, nulls, text },
+        }
+    }
+}
+
 // ============================================================================
 // Value system
 // ============================================================================
@@ -142,10 +269,14 @@ pub enum ValueKind {
     F32,
     F64,
     String,
+    Enum,
     Json,
+    Decimal,
     Uuid,
     PrimitiveDateTime,
     OffsetDateTime,
+    Date,
+    Time,
     Duration,
     /// Homogeneous array (element type known from field metadata).
     Array,
@@ -238,15 +369,21 @@ pub enum Value {
 
     // Common types.
     String(String),
+    /// The serde name of a variant of a `CkEnum`.
+    Enum(String),
 
     // Ecosystem support.
     // -- serde
     Json(serde_json::Value),
+    // -- rust_decimal
+    Decimal(rust_decimal::Decimal),
     // -- uuid
     Uuid(uuid::Uuid),
     // -- time
     PrimitiveDateTime(time::PrimitiveDateTime),
     OffsetDateTime(time::OffsetDateTime),
+    Date(time::Date),
+    Time(time::Time),
     Duration(TimeDuration),
 
     // Collections.
@@ -362,6 +499,7 @@ impl Value {
         I128, i128;
         F32, f32;
         F64, f64;
+        Decimal, rust_decimal::Decimal;
         Uuid, uuid::Uuid;
     }
 
@@ -369,6 +507,7 @@ impl Value {
 
     impl_as_ref! {
         String, String;
+        Enum, String;
         Json, serde_json::Value;
         Duration, TimeDuration;
         Array, Vec<Value>;
@@ -393,10 +532,27 @@ impl Value {
         }
     }
 
+    pub fn as_date(&self) -> Option<time::Date> {
+        match self {
+            Self::Date(v) => Some(*v),
+            Self::String(s) => time::Date::parse(s, &Iso8601::DEFAULT).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_time(&self) -> Option<time::Time> {
+        match self {
+            Self::Time(v) => Some(*v),
+            Self::String(s) => time::Time::parse(s, &Iso8601::DEFAULT).ok(),
+            _ => None,
+        }
+    }
+
     // === Taking ownership (consuming accessors) ===
 
     impl_take! {
         String, String;
+        Enum, String;
         Json, serde_json::Value;
         Duration, TimeDuration;
         Array, Vec<Value>;
@@ -419,11 +575,15 @@ impl Value {
         I128, i128;
         F32, f32;
         F64, f64;
+        Decimal, rust_decimal::Decimal;
         Uuid, uuid::Uuid;
         PrimitiveDateTime, time::PrimitiveDateTime;
         OffsetDateTime, time::OffsetDateTime;
+        Date, time::Date;
+        Time, time::Time;
 
         String, &String;
+        Enum, &String;
         Json, &serde_json::Value;
         Duration, &TimeDuration;
         Array, &Vec<Value>;
@@ -467,16 +627,21 @@ pub struct Saved<T> {
     /// The saved entity.
     pub entity: T,
 
-    /// Non-critical validation violations (warnings) associated with this entity.
+    /// Non-critical validation violations (warnings and infos) associated with this entity.
     /// Empty if no violations exist.
     #[schema(value_type = Object)]
     pub violations: PartialSerializableAggregateViolations,
 }
 
 impl<T> Saved<T> {
-    /// Returns true if there are any validation violations (warnings) for this entity.
+    /// Returns true if there are any validation errors (warnings) for this entity.
     pub fn has_validation_errors(&self) -> bool {
-        !self.violations.is_empty()
+        self.violations.count_by_kind().0 > 0
+    }
+
+    /// Returns true if there are any validation infos for this entity.
+    pub fn has_validation_infos(&self) -> bool {
+        self.violations.count_by_kind().1 > 0
     }
 }
 
@@ -523,6 +688,24 @@ mod tests {
     use super::*;
     use assertr::prelude::*;
 
+    #[test]
+    fn order_spec_deserializes_from_plain_order_and_object() {
+        let plain: OrderSpec = serde_json::from_str(r#""desc""#).unwrap();
+        let full: OrderSpec = serde_json::from_str(
+            r#"{"order": "asc", "nulls": "first", "text": {"collation": "de-DE-x-icu"}}"#,
+        )
+        .unwrap();
+        let case_insensitive = OrderSpec::asc().case_insensitive().nulls_last();
+
+        assert_that(plain).is_equal_to(OrderSpec::desc());
+        assert_that(full).is_equal_to(OrderSpec::asc().nulls_first().collation("de-DE-x-icu"));
+        assert_that(serde_json::to_string(&OrderSpec::desc()).unwrap())
+            .is_equal_to(r#"{"order":"desc"}"#.to_owned());
+        assert_that(serde_json::to_string(&case_insensitive).unwrap())
+            .is_equal_to(r#"{"order":"asc","nulls":"last","text":"case_insensitive"}"#.to_owned());
+        assert_that(case_insensitive.reversed().order).is_equal_to(Order::Desc);
+    }
+
     #[test]
     fn delete_many_result_serializes_correctly() {
         let result = DeletedMany {
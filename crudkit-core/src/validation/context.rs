@@ -37,4 +37,7 @@ pub enum ValidationTrigger {
 
     /// Validation triggered by a global/aggregate validation run.
     GlobalValidation,
+
+    /// Validation triggered by revalidating all persisted entities, e.g. after validators changed.
+    Revalidation,
 }
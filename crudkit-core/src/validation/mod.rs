@@ -10,6 +10,7 @@ pub use context::{CrudAction, ValidationContext, ValidationTrigger, When};
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use utoipa::ToSchema;
 
 use crate::id::{Id, SerializableId};
 use crate::resource::ResourceName;
@@ -90,6 +91,18 @@ impl ViolationsByValidator {
     pub fn has_critical_violations(&self) -> bool {
         self.has_any_violations_of(Severity::Critical)
     }
+
+    /// Whether any violation is an error, ignoring infos.
+    pub fn has_errors(&self) -> bool {
+        self.violations_by_validator
+            .values()
+            .any(|violations| violations.has_errors())
+    }
+
+    /// Whether any violation is an info.
+    pub fn has_infos(&self) -> bool {
+        self.has_any_violations_of(Severity::Info)
+    }
 }
 
 impl Default for ViolationsByValidator {
@@ -178,6 +191,47 @@ impl Default for ViolationsByResource {
     }
 }
 
+/// Selects persisted violations of one resource. Unset criteria select everything.
+#[derive(Debug, Clone, Default, PartialEq, Eq, ToSchema, Serialize, Deserialize)]
+pub struct ViolationFilter {
+    /// Only violations of the entity with this id.
+    #[serde(default)]
+    pub entity_id: Option<SerializableId>,
+
+    /// Only violations of one of these severities.
+    #[serde(default)]
+    pub severities: Vec<Severity>,
+
+    /// Only violations reported by the validator with this name, of any version.
+    #[serde(default)]
+    pub validator_name: Option<String>,
+}
+
+impl ViolationFilter {
+    /// Whether the persisted `violation` of entity `entity_id`, reported by `validator`, is selected.
+    pub fn matches(
+        &self,
+        entity_id: &SerializableId,
+        validator: &OwnedValidatorInfo,
+        violation: &Violation,
+    ) -> bool {
+        self.entity_id.as_ref().is_none_or(|id| id == entity_id)
+            && (self.severities.is_empty() || self.severities.contains(&violation.severity()))
+            && self
+                .validator_name
+                .as_ref()
+                .is_none_or(|name| name.as_str() == validator.validator_name)
+    }
+}
+
+/// A persisted violation, together with the entity it concerns and the validator reporting it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersistedViolation {
+    pub entity_id: SerializableId,
+    pub validator: OwnedValidatorInfo,
+    pub violation: Violation,
+}
+
 // ------------------------------------------------------------------------------------------------
 // validator-info erased alternatives.
 // ------------------------------------------------------------------------------------------------
@@ -282,12 +336,58 @@ impl PartialSerializableAggregateViolations {
             self.by_entity.is_empty() || self.by_entity.iter().all(|(_id, v)| v.is_empty());
         general_empty && create_empty && by_entity_empty
     }
+
+    /// Returns the number of errors and the number of infos, in that order.
+    pub fn count_by_kind(&self) -> (usize, usize) {
+        self.general
+            .iter()
+            .chain(self.create.iter())
+            .chain(self.by_entity.iter().map(|(_id, v)| v))
+            .fold((0, 0), |(errors, infos), violations| {
+                (
+                    errors + violations.number_of_errors(),
+                    infos + violations.number_of_infos(),
+                )
+            })
+    }
+
+    /// Returns all field-scoped violations, keyed by the name of each field they concern.
+    pub fn violations_by_field(&self) -> HashMap<String, Vec<Violation>> {
+        let mut by_field = HashMap::<String, Vec<Violation>>::new();
+        let all = self
+            .general
+            .iter()
+            .chain(self.create.iter())
+            .chain(self.by_entity.iter().map(|(_id, v)| v));
+        for violation in all.flat_map(|violations| violations.iter()) {
+            for field in violation.fields() {
+                by_field
+                    .entry(field.clone())
+                    .or_default()
+                    .push(violation.clone());
+            }
+        }
+        by_field
+    }
+
+    /// Returns the messages of all field-scoped violations, keyed by the name of each field they
+    /// concern.
+    pub fn messages_by_field(&self) -> HashMap<String, Vec<String>> {
+        self.violations_by_field()
+            .into_iter()
+            .map(|(field, violations)| {
+                let messages = violations.into_iter().map(Violation::into_message);
+                (field, messages.collect())
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::violation::Violations;
-    use super::PartialSerializableAggregateViolations;
+    use super::validator::ValidatorInfo;
+    use super::violation::{Severity, Violation, Violations};
+    use super::{PartialSerializableAggregateViolations, ViolationFilter};
     use crate::id::{IdValue, SerializableId, SerializableIdEntry};
     use crate::resource::ResourceName;
     use assertr::prelude::*;
@@ -317,4 +417,88 @@ mod tests {
 
         assert_that(&json).is_equal_to(r#"{"foo":{"general":null,"create":null,"by_entity":[[[["bar",{"I32":1}]],{"violations":[]}]]}}"#);
     }
+
+    #[test]
+    fn count_errors_and_infos_separately() {
+        let partial = PartialSerializableAggregateViolations {
+            general: None,
+            create: Some(Violations {
+                violations: vec![
+                    Violation::info("Description is shorter than recommended"),
+                    Violation::major("Title is missing"),
+                ],
+            }),
+            by_entity: vec![(
+                SerializableId(vec![SerializableIdEntry {
+                    field_name: "id".into(),
+                    value: IdValue::I32(1),
+                }]),
+                Violations {
+                    violations: vec![Violation::info("Consider adding tags")],
+                },
+            )],
+        };
+
+        assert_that(partial.count_by_kind()).is_equal_to((1, 2));
+    }
+
+    #[test]
+    fn collect_messages_of_field_scoped_violations() {
+        let partial = PartialSerializableAggregateViolations {
+            general: None,
+            create: Some(Violations {
+                violations: vec![
+                    Violation::critical("Title is missing")
+                        .for_field("title")
+                        .with_code("title_missing"),
+                    Violation::major("Start must be before end").for_fields(["start", "end"]),
+                    Violation::major("Too many articles"),
+                ],
+            }),
+            by_entity: Vec::new(),
+        };
+
+        let messages = partial.messages_by_field();
+
+        assert_that(messages.len()).is_equal_to(3);
+        assert_that(&messages["title"]).is_equal_to(&vec!["Title is missing".to_owned()]);
+        assert_that(&messages["end"]).is_equal_to(&vec!["Start must be before end".to_owned()]);
+    }
+
+    #[test]
+    fn filter_violations_by_entity_severity_and_validator() {
+        let id = |value| {
+            SerializableId(vec![SerializableIdEntry {
+                field_name: "id".into(),
+                value: IdValue::I32(value),
+            }])
+        };
+        let validator = ValidatorInfo::new("title", 2);
+        let violation = Violation::major("Title is missing");
+
+        let matches = |filter: ViolationFilter| filter.matches(&id(1), &validator, &violation);
+
+        assert_that(matches(ViolationFilter::default())).is_true();
+        assert_that(matches(ViolationFilter {
+            entity_id: Some(id(1)),
+            severities: vec![Severity::Critical, Severity::Major],
+            validator_name: Some("title".to_owned()),
+        }))
+        .is_true();
+        assert_that(matches(ViolationFilter {
+            entity_id: Some(id(2)),
+            ..Default::default()
+        }))
+        .is_false();
+        assert_that(matches(ViolationFilter {
+            severities: vec![Severity::Info],
+            ..Default::default()
+        }))
+        .is_false();
+        assert_that(matches(ViolationFilter {
+            validator_name: Some("tags".to_owned()),
+            ..Default::default()
+        }))
+        .is_false();
+    }
 }
@@ -1,54 +1,232 @@
 //! Violation types and collections.
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 use std::vec;
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ToSchema, Serialize, Deserialize)]
 pub enum Severity {
-    // TODO: Information?
+    /// A hint, never blocking an operation and not counted as an error.
+    Info,
     Major,
     Critical,
 }
 
+impl Severity {
+    /// Whether violations of this severity are errors. Only `Info` is not.
+    pub fn is_error(&self) -> bool {
+        !matches!(self, Severity::Info)
+    }
+}
+
+/// A typed parameter of a violation message, e.g. the `min` in "needs at least {min} characters".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ViolationParam {
+    String(String),
+    Integer(i64),
+    Decimal(rust_decimal::Decimal),
+    Bool(bool),
+}
+
+impl Display for ViolationParam {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ViolationParam::String(value) => f.write_str(value),
+            ViolationParam::Integer(value) => write!(f, "{value}"),
+            ViolationParam::Decimal(value) => write!(f, "{value}"),
+            ViolationParam::Bool(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<String> for ViolationParam {
+    fn from(value: String) -> Self {
+        ViolationParam::String(value)
+    }
+}
+
+impl From<&str> for ViolationParam {
+    fn from(value: &str) -> Self {
+        ViolationParam::String(value.to_owned())
+    }
+}
+
+impl From<i32> for ViolationParam {
+    fn from(value: i32) -> Self {
+        ViolationParam::Integer(value.into())
+    }
+}
+
+impl From<i64> for ViolationParam {
+    fn from(value: i64) -> Self {
+        ViolationParam::Integer(value)
+    }
+}
+
+impl From<u32> for ViolationParam {
+    fn from(value: u32) -> Self {
+        ViolationParam::Integer(value.into())
+    }
+}
+
+impl From<rust_decimal::Decimal> for ViolationParam {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        ViolationParam::Decimal(value)
+    }
+}
+
+impl From<f64> for ViolationParam {
+    /// Converts to a decimal, falling back to the textual form for values like `NaN`.
+    fn from(value: f64) -> Self {
+        rust_decimal::Decimal::try_from(value).map_or_else(
+            |_| ViolationParam::String(value.to_string()),
+            ViolationParam::Decimal,
+        )
+    }
+}
+
+impl From<bool> for ViolationParam {
+    fn from(value: bool) -> Self {
+        ViolationParam::Bool(value)
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
-pub enum Violation {
-    // TODO: Information(String)?
-    Major(String),
-    Critical(String),
+pub struct Violation {
+    pub severity: Severity,
+
+    pub message: String,
+
+    /// Names of the fields this violation concerns.
+    /// Empty if the violation concerns the entity as a whole.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+
+    /// Stable machine-readable code identifying the kind of violation, e.g. `"title_missing"`.
+    /// Also serves as the key under which clients look up a translated message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+
+    /// Parameters of the message, referenced as `{name}` in translated messages.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, ViolationParam>,
 }
 
 impl Violation {
+    pub fn new(severity: Severity, violation: impl Into<String>) -> Violation {
+        Violation {
+            severity,
+            message: violation.into(),
+            fields: Vec::new(),
+            code: None,
+            params: BTreeMap::new(),
+        }
+    }
+
+    pub fn info(violation: impl Into<String>) -> Violation {
+        Violation::new(Severity::Info, violation)
+    }
+
     pub fn major(violation: impl Into<String>) -> Violation {
-        Violation::Major(violation.into())
+        Violation::new(Severity::Major, violation)
     }
 
     pub fn critical(violation: impl Into<String>) -> Violation {
-        Violation::Critical(violation.into())
+        Violation::new(Severity::Critical, violation)
+    }
+
+    /// Marks this violation as concerning the field named `field`, in addition to any already
+    /// referenced fields.
+    pub fn for_field(mut self, field: impl Into<String>) -> Violation {
+        self.fields.push(field.into());
+        self
+    }
+
+    /// Marks this violation as concerning all given `fields`, in addition to any already
+    /// referenced fields.
+    pub fn for_fields<S: Into<String>>(mut self, fields: impl IntoIterator<Item = S>) -> Violation {
+        self.fields.extend(fields.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn with_code(mut self, code: impl Into<String>) -> Violation {
+        self.code = Some(code.into());
+        self
+    }
+
+    pub fn with_param(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<ViolationParam>,
+    ) -> Violation {
+        self.params.insert(name.into(), value.into());
+        self
     }
 
     pub fn severity(&self) -> Severity {
-        match self {
-            Violation::Major(_) => Severity::Major,
-            Violation::Critical(_) => Severity::Critical,
-        }
+        self.severity
     }
 
     pub fn is_of_severity(&self, severity: Severity) -> bool {
         self.severity() == severity
     }
 
-    pub fn message(&self) -> &str {
-        match self {
-            Violation::Major(msg) => msg.as_str(),
-            Violation::Critical(msg) => msg.as_str(),
+    pub fn is_error(&self) -> bool {
+        self.severity().is_error()
+    }
+
+    /// Whether this violation concerns the field named `field`.
+    pub fn concerns(&self, field: &str) -> bool {
+        self.fields.iter().any(|it| it == field)
+    }
+
+    pub fn fields(&self) -> &[String] {
+        &self.fields
+    }
+
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    pub fn param(&self, name: &str) -> Option<&ViolationParam> {
+        self.params.get(name)
+    }
+
+    /// Formats a message `template`, e.g. a translation looked up by [`Violation::code`],
+    /// replacing each `{name}` with the parameter of that name. Unknown names are kept as is.
+    pub fn format_message(&self, template: &str) -> String {
+        let mut message = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            message.push_str(&rest[..start]);
+            let placeholder = &rest[start..];
+            match placeholder
+                .find('}')
+                .and_then(|end| Some((end, self.param(&placeholder[1..end])?)))
+            {
+                Some((end, param)) => {
+                    message.push_str(&param.to_string());
+                    rest = &placeholder[end + 1..];
+                }
+                None => {
+                    message.push('{');
+                    rest = &placeholder[1..];
+                }
+            }
         }
+        message.push_str(rest);
+        message
+    }
+
+    pub fn message(&self) -> &str {
+        self.message.as_str()
     }
+
     pub fn into_message(self) -> String {
-        match self {
-            Violation::Major(msg) => msg,
-            Violation::Critical(msg) => msg,
-        }
+        self.message
     }
 }
 
@@ -101,6 +279,26 @@ impl Violations {
     pub fn has_critical_violations(&self) -> bool {
         self.has_any_violations_of(Severity::Critical)
     }
+
+    /// Whether any violation is an error, ignoring infos.
+    pub fn has_errors(&self) -> bool {
+        self.violations.iter().any(Violation::is_error)
+    }
+
+    pub fn number_of_errors(&self) -> usize {
+        self.violations.iter().filter(|v| v.is_error()).count()
+    }
+
+    pub fn number_of_infos(&self) -> usize {
+        self.len() - self.number_of_errors()
+    }
+
+    /// All violations concerning the field named `field`.
+    pub fn of_field<'a>(&'a self, field: &'a str) -> impl Iterator<Item = &'a Violation> {
+        self.violations
+            .iter()
+            .filter(move |violation| violation.concerns(field))
+    }
 }
 
 impl Default for Violations {
@@ -125,3 +323,44 @@ impl IntoIterator for Violations {
         self.violations.into_iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Violation, ViolationParam};
+    use assertr::prelude::*;
+
+    #[test]
+    fn format_message_replaces_known_params() {
+        let violation = Violation::major("Title is too short")
+            .with_code("title_too_short")
+            .with_param("min", 5)
+            .with_param("field", "Titel");
+
+        assert_that(violation.format_message("{field} braucht mindestens {min} Zeichen {unknown}"))
+            .is_equal_to("Titel braucht mindestens 5 Zeichen {unknown}".to_owned());
+    }
+
+    #[test]
+    fn float_params_become_decimals() {
+        assert_that(ViolationParam::from(0.5).to_string()).is_equal_to("0.5".to_owned());
+        assert_that(ViolationParam::from(f64::NAN))
+            .is_equal_to(ViolationParam::String("NaN".to_owned()));
+    }
+
+    #[test]
+    fn params_are_serialized_typed() {
+        let violation = Violation::info("Consider a longer description").with_param("min", 20);
+
+        let json = serde_json::to_string(&violation).unwrap();
+
+        assert_that(json.as_str()).is_equal_to(
+            r#"{"severity":"Info","message":"Consider a longer description","params":{"min":{"Integer":20}}}"#,
+        );
+        assert_that(
+            serde_json::from_str::<Violation>(&json)
+                .unwrap()
+                .param("min"),
+        )
+        .is_equal_to(Some(&ViolationParam::Integer(20)));
+    }
+}
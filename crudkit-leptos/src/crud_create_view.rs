@@ -2,7 +2,9 @@ use crate::crud_fields::CrudFields;
 use crate::crud_instance::CrudInstanceContext;
 use crate::crud_instance_config::{CreateElements, FieldRendererRegistry};
 use crate::crud_leave_modal::CrudLeaveModal;
+use crate::debounce::{Debouncer, LIVE_VALIDATION_DEBOUNCE};
 use crate::ReactiveField;
+use crudkit_core::validation::violation::Violation;
 use crudkit_core::{Saved, Value};
 use crudkit_core::id::{SerializableId, SerializableIdEntry};
 use crudkit_web::prelude::*;
@@ -100,6 +102,9 @@ pub fn CrudCreateView(
     // The state of the `input` signal should be considered to be erroneous if at least one field is contained in this error list.
     let (_input_errors, set_input_errors) = signal(HashMap::<DynCreateField, String>::new());
 
+    // The violations rejecting the last save, by the name of the field they concern.
+    let (field_violations, set_field_violations) = signal(HashMap::<String, Vec<Violation>>::new());
+
     let (user_wants_to_leave, set_user_wants_to_leave) = signal(false);
     let (show_leave_modal, set_show_leave_modal) = signal(false);
 
@@ -162,12 +167,49 @@ pub fn CrudCreateView(
                         "Could not create entity due to error: {}",
                         request_error.to_string()
                     );
+                    if let RequestError::UnprocessableEntity(info) = &request_error {
+                        set_field_violations.set(info.violations_by_field());
+                    }
                     on_entity_creation_failed.run(CrudOperationError::from(request_error));
                 }
             }
         }
     });
 
+    // Validate the input on the server, without creating anything, once the user stops editing.
+    let validate_action = Action::new_local(move |create_model: &DynCreateModel| {
+        let create_model = create_model.clone();
+        let data_provider = data_provider.get_untracked();
+        async move {
+            data_provider
+                .validate_create(DynValidateCreate {
+                    entity: create_model,
+                })
+                .await
+        }
+    });
+
+    let validation_debouncer = Debouncer::new(LIVE_VALIDATION_DEBOUNCE);
+    Effect::new(move |prev: Option<()>| {
+        let create_model = input.get();
+        // The initial input was not edited by the user.
+        if prev.is_some() {
+            validation_debouncer.schedule(move || {
+                validate_action.dispatch(create_model);
+            });
+        }
+    });
+
+    let validate_action_value = validate_action.value();
+    Effect::new(move |_prev| match validate_action_value.get() {
+        Some(Ok(violations)) => set_field_violations.set(violations.violations_by_field()),
+        Some(Err(request_error)) => tracing::warn!(
+            "Could not validate entity due to error: {}",
+            request_error.to_string()
+        ),
+        None => {}
+    });
+
     let save = Callback::new(move |then| {
         save_action.dispatch((input.get(), then));
     });
@@ -182,6 +224,9 @@ pub fn CrudCreateView(
                     set_input_errors.update(|errors| {
                         errors.remove(&field);
                     });
+                    set_field_violations.update(|violations| {
+                        violations.remove(field.name().as_ref());
+                    });
                     signals.update_value(|map| {
                         map.get(&field).expect("field must be present").set(value);
                     });
@@ -207,6 +252,7 @@ pub fn CrudCreateView(
                         mode=FieldMode::Editable
                         value_changed=value_changed
                         on_tab_selection=on_tab_selected
+                        field_violations=field_violations
                     />
                 }.into_any()
             }
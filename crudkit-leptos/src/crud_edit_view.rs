@@ -6,8 +6,10 @@ use crate::crud_instance::CrudInstanceContext;
 use crate::crud_instance_config::{FieldRendererRegistry, UpdateElements};
 use crate::crud_leave_modal::CrudLeaveModal;
 use crate::crud_table::NoDataAvailable;
+use crate::debounce::{Debouncer, LIVE_VALIDATION_DEBOUNCE};
 use crate::ReactiveField;
 use crudkit_core::condition::{merge_conditions, TryIntoAllEqualCondition};
+use crudkit_core::validation::violation::Violation;
 use crudkit_core::{Saved, Value};
 use crudkit_core::id::SerializableId;
 use crudkit_web::prelude::*;
@@ -151,6 +153,9 @@ pub fn CrudEditView(
     // The state of the `input` signal should be considered to be erroneous if at least one field is contained in this error list.
     let (_input_errors, set_input_errors) = signal(HashMap::<DynUpdateField, String>::new());
 
+    // The violations of the last save, by the name of the field they concern.
+    let (field_violations, set_field_violations) = signal(HashMap::<String, Vec<Violation>>::new());
+
     let (user_wants_to_leave, set_user_wants_to_leave) = signal(false);
     let (show_leave_modal, set_show_leave_modal) = signal(false);
 
@@ -217,6 +222,7 @@ pub fn CrudEditView(
             match result {
                 Ok(saved) => {
                     set_entity.set(Ok(saved.entity.clone()));
+                    set_field_violations.set(saved.violations.violations_by_field());
                     on_entity_updated.run(saved);
                     match and_then {
                         Then::DoNothing => {}
@@ -225,7 +231,14 @@ pub fn CrudEditView(
                     }
                 }
                 Err(request_error) => {
-                    set_entity.set(Err(NoDataAvailable::RequestFailed(request_error.clone())));
+                    // Rejected by validation: Keep the form, highlighting the offending fields.
+                    match &request_error {
+                        RequestError::UnprocessableEntity(info) => {
+                            set_field_violations.set(info.violations_by_field())
+                        }
+                        _ => set_entity
+                            .set(Err(NoDataAvailable::RequestFailed(request_error.clone()))),
+                    }
                     tracing::warn!(
                         "Could not update entity due to error: {}",
                         request_error.to_string()
@@ -236,6 +249,54 @@ pub fn CrudEditView(
         }
     });
 
+    // Validate the input on the server, without updating anything, once the user stops editing.
+    let validate_action = Action::new_local(move |entity: &DynUpdateModel| {
+        let entity: DynUpdateModel = entity.clone();
+        let data_provider = data_provider.get_untracked();
+        async move {
+            let id_condition = id
+                .get_untracked()
+                .0
+                .into_iter()
+                .try_into_all_equal_condition()
+                .map_err(|e| {
+                    RequestError::BadRequest(format!(
+                        "ID contains unsupported field types: {:?}",
+                        e
+                    ))
+                })?;
+            data_provider
+                .validate_update(DynValidateUpdate {
+                    entity,
+                    condition: merge_conditions(
+                        instance_ctx.base_condition.get_untracked(),
+                        Some(id_condition),
+                    ),
+                })
+                .await
+        }
+    });
+
+    let validation_debouncer = Debouncer::new(LIVE_VALIDATION_DEBOUNCE);
+    Effect::new(move |_prev| {
+        // Only validate edits, not the entity as it was loaded or saved.
+        if let Some(entity) = input.get().filter(|_| input_changed.get()) {
+            validation_debouncer.schedule(move || {
+                validate_action.dispatch(entity);
+            });
+        }
+    });
+
+    let validate_action_value = validate_action.value();
+    Effect::new(move |_prev| match validate_action_value.get() {
+        Some(Ok(violations)) => set_field_violations.set(violations.violations_by_field()),
+        Some(Err(request_error)) => tracing::warn!(
+            "Could not validate entity due to error: {}",
+            request_error.to_string()
+        ),
+        None => {}
+    });
+
     let trigger_save = move || {
         // Button is disabled when input is None, so this guard is defensive.
         if let Some(entity) = input.get() {
@@ -275,6 +336,9 @@ pub fn CrudEditView(
                     set_input_errors.update(|errors| {
                         errors.remove(&field);
                     });
+                    set_field_violations.update(|violations| {
+                        violations.remove(field.name().as_ref());
+                    });
                     signals.with_untracked(|signals| {
                         signals.update_value(|map| {
                             map.get(&field).expect("field must be present").set(value);
@@ -353,6 +417,7 @@ pub fn CrudEditView(
                         mode=FieldMode::Editable
                         value_changed=value_changed
                         on_tab_selection=on_tab_selected
+                        field_violations=field_violations
                     />
                 }.into_any()
             }
@@ -1,7 +1,9 @@
 use crate::crud_instance_config::FieldRendererRegistry;
 use crate::fields::default_field_renderer;
+use crate::violation_translator::ViolationTranslator;
 use crate::ReactiveField;
 use crudkit_core::Value;
+use crudkit_core::validation::violation::Violation;
 use crudkit_web::prelude::*;
 use crudkit_web::{FieldMode, FieldOptions};
 use leptos::prelude::*;
@@ -16,7 +18,24 @@ pub fn CrudField<F: TypeErasedField>(
     signals: StoredValue<HashMap<F, ReactiveField>>,
     value: ReactiveField,
     value_changed: Callback<(F, Result<Value, String>)>,
+    /// Violations by field name. Fields with violations are highlighted.
+    #[prop(into, optional)]
+    field_violations: MaybeProp<HashMap<String, Vec<Violation>>>,
 ) -> impl IntoView {
+    let translator = use_context::<ViolationTranslator>().unwrap_or_default();
+    let field_name = field.name();
+    let messages = Signal::derive(move || {
+        field_violations.get().and_then(|violations| {
+            let violations = violations.get(field_name.as_ref())?;
+            Some(
+                violations
+                    .iter()
+                    .map(|it| translator.translate(it))
+                    .collect::<Vec<_>>(),
+            )
+        })
+    });
+
     let field_clone = field.clone();
     let value_changed = Callback::new(move |result| match result {
         Ok(new) => value_changed.run((field_clone.clone(), Ok(new))),
@@ -43,5 +62,15 @@ pub fn CrudField<F: TypeErasedField>(
     // This additional closure is required so that each custom field, which may be another
     // crud instance, or, in general, anything that might `provide_context(T)`, have their
     // own context to do so in and not override sibling data.
-    (move || field_renderer.run()).into_any()
+    view! {
+        <div class="crud-field-scope" class:crud-field-invalid=move || messages.with(Option::is_some)>
+            {move || field_renderer.run()}
+            {move || messages.get().map(|messages| view! {
+                <ul class="crud-field-violations">
+                    {messages.into_iter().map(|message| view! { <li>{message}</li> }).collect_view()}
+                </ul>
+            })}
+        </div>
+    }
+    .into_any()
 }
@@ -2,6 +2,7 @@ use crate::crud_field::CrudField;
 use crate::crud_instance_config::FieldRendererRegistry;
 use crate::ReactiveField;
 use crudkit_core::Value;
+use crudkit_core::validation::violation::Violation;
 use crudkit_web::prelude::*;
 use crudkit_web::{FieldMode, TabId};
 use leptonic::components::prelude::*;
@@ -16,6 +17,9 @@ pub fn CrudFields<F: TypeErasedField>(
     mode: FieldMode,
     value_changed: Callback<(F, Result<Value, String>)>,
     on_tab_selection: Callback<TabId>,
+    /// Violations by field name. Fields with violations are highlighted.
+    #[prop(into, optional)]
+    field_violations: MaybeProp<HashMap<String, Vec<Violation>>>,
 ) -> impl IntoView {
     move || {
         elements
@@ -33,6 +37,7 @@ pub fn CrudFields<F: TypeErasedField>(
                                     mode=mode
                                     value_changed=value_changed
                                     on_tab_selection=on_tab_selection
+                                    field_violations=field_violations
                                 />
                             }
                                 .into_any(),
@@ -58,6 +63,7 @@ pub fn CrudFields<F: TypeErasedField>(
                                                         mode=mode
                                                         value_changed=value_changed
                                                         on_tab_selection=on_tab_selection
+                                                        field_violations=field_violations
                                                     />
                                                 </Tab>
                                             }
@@ -75,6 +81,7 @@ pub fn CrudFields<F: TypeErasedField>(
                                         mode=mode
                                         value_changed=value_changed
                                         on_tab_selection=on_tab_selection
+                                        field_violations=field_violations
                                     />
                                 </Card>
                             }
@@ -95,6 +102,7 @@ pub fn CrudFields<F: TypeErasedField>(
                                 }
                             })
                             value_changed=value_changed
+                            field_violations=field_violations
                         />
                     }
                         .into_any(),
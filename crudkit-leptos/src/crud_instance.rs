@@ -11,7 +11,7 @@ use crate::crud_instance_mgr::{CrudInstanceMgrContext, InstanceState};
 use crate::crud_list_view::CrudListView;
 use crate::crud_read_view::CrudReadView;
 use crudkit_core::condition::{Condition, ConditionClause, ConditionElement};
-use crudkit_core::{Deleted, DeletedMany, Order};
+use crudkit_core::{Deleted, DeletedMany, OrderSpec};
 use crudkit_core::id::{SerializableId, SerializableIdEntry};
 use crudkit_web::prelude::*;
 use crudkit_web::request_error::CrudOperationError;
@@ -54,8 +54,8 @@ pub struct CrudInstanceContext {
     set_items_per_page: WriteSignal<ItemsPerPage>,
 
     /// How data should be ordered when querying data for the ist view.
-    pub order_by: ReadSignal<IndexMap<DynReadField, Order>>,
-    set_order_by: WriteSignal<IndexMap<DynReadField, Order>>,
+    pub order_by: ReadSignal<IndexMap<DynReadField, OrderSpec>>,
+    set_order_by: WriteSignal<IndexMap<DynReadField, OrderSpec>>,
 
     /// Configuration of a parent, if present.
     pub parent: StoredValue<Option<CrudParentConfig>>,
@@ -116,7 +116,7 @@ impl CrudInstanceContext {
     // TODO: Why is this here and CrudInstanceConfig#update_order_by exists?
     pub fn oder_by(&self, field: DynReadField, options: OrderByUpdateOptions) {
         self.set_order_by
-            .update(|order_by: &mut IndexMap<DynReadField, Order>| {
+            .update(|order_by: &mut IndexMap<DynReadField, OrderSpec>| {
                 let prev = order_by.get(&field).cloned();
                 tracing::debug!(?field, ?options, "order by");
                 if !options.append {
@@ -125,16 +125,24 @@ impl CrudInstanceContext {
                 order_by.insert(
                     field,
                     match prev {
-                        Some(order) => match order {
-                            Order::Asc => Order::Desc,
-                            Order::Desc => Order::Asc,
-                        },
-                        None => Order::Asc,
+                        Some(spec) => spec.reversed(),
+                        None => OrderSpec::asc(),
                     },
                 );
             })
     }
 
+    /// Changes how an ordered field is ordered, e.g. where its nulls go. Does nothing if the
+    /// list is not ordered by `field`.
+    pub fn update_order_spec(&self, field: &DynReadField, update: impl FnOnce(&mut OrderSpec)) {
+        self.set_order_by
+            .update(|order_by: &mut IndexMap<DynReadField, OrderSpec>| {
+                if let Some(spec) = order_by.get_mut(field) {
+                    update(spec);
+                }
+            })
+    }
+
     pub fn tab_selected(&self, tab_id: TabId) {
         tracing::info!(?tab_id, "tab_selected");
     }
@@ -264,6 +272,7 @@ pub fn CrudInstance(
                 Some(Condition::All(vec![ConditionElement::Clause(
                     ConditionClause {
                         column_name: parent.referencing_field.to_string(),
+                        json_path: Vec::new(),
                         operator: crudkit_core::condition::Operator::Equal,
                         value: clause_value,
                     },
@@ -2,7 +2,7 @@ use crate::crud_action::{CrudAction, CrudEntityAction};
 use crate::fields::FieldRenderer;
 use crate::ReactiveField;
 use crudkit_core::condition::Condition;
-use crudkit_core::{Order, Saved};
+use crudkit_core::{OrderSpec, Saved};
 use crudkit_web::prelude::*;
 use crudkit_web::reqwest_executor::ReqwestExecutor;
 use crudkit_web::view::SerializableCrudView;
@@ -117,11 +117,13 @@ pub struct CrudInstanceConfig {
     pub list_columns: Vec<Header>,
     pub create_elements: CreateElements,
     pub elements: UpdateElements,
-    pub order_by: IndexMap<DynReadField, Order>,
+    pub order_by: IndexMap<DynReadField, OrderSpec>,
     /// The number of items shown per page in the list view.
     pub items_per_page: ItemsPerPage,
     /// The current page to display, e.g. `Page::first()`. One-based index.
     pub page_nr: PageNr,
+    /// Condition applied to every request of this instance. May use placeholders like
+    /// `$tenant_id`, which the server resolves for the calling user.
     pub base_condition: Option<Condition>,
 
     /* Immutable data */
@@ -170,7 +172,7 @@ pub(crate) struct CrudMutableInstanceConfig {
     pub headers: Vec<Header>,
     pub create_elements: CreateElements,
     pub elements: UpdateElements,
-    pub order_by: IndexMap<DynReadField, Order>,
+    pub order_by: IndexMap<DynReadField, OrderSpec>,
     pub items_per_page: ItemsPerPage,
     pub page: PageNr,
     pub base_condition: Option<Condition>,
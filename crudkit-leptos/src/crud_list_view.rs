@@ -4,7 +4,7 @@ use crate::crud_instance::CrudInstanceContext;
 use crate::crud_instance_config::{FieldRendererRegistry, Header};
 use crate::crud_pagination::CrudPagination;
 use crate::crud_table::{CrudTable, NoDataAvailable};
-use crudkit_core::Order;
+use crudkit_core::OrderSpec;
 use crudkit_web::prelude::*;
 use crudkit_web::request_error::RequestError;
 use indexmap::IndexMap;
@@ -84,7 +84,7 @@ impl CrudListViewContext {
 pub fn CrudListView(
     #[prop(into)] data_provider: Signal<DynCrudRestDataProvider>,
     #[prop(into)] headers: Signal<Vec<Header>>,
-    #[prop(into)] order_by: Signal<IndexMap<DynReadField, Order>>,
+    #[prop(into)] order_by: Signal<IndexMap<DynReadField, OrderSpec>>,
     #[prop(into)] field_renderer_registry: Signal<FieldRendererRegistry<DynReadField>>,
     #[prop(into)] actions: Signal<Vec<CrudAction>>,
 ) -> impl IntoView {
@@ -4,7 +4,7 @@ use crate::crud_list_view::CrudListViewContext;
 use crate::crud_table_body::CrudTableBody;
 use crate::crud_table_footer::CrudTableFooter;
 use crate::crud_table_header::CrudTableHeader;
-use crudkit_core::Order;
+use crudkit_core::OrderSpec;
 use crudkit_web::prelude::{DynReadField, DynReadModel};
 use crudkit_web::request_error::RequestError;
 use indexmap::IndexMap;
@@ -22,7 +22,7 @@ pub enum NoDataAvailable {
 #[component]
 pub fn CrudTable(
     #[prop(into)] headers: Signal<Vec<Header>>,
-    #[prop(into)] order_by: Signal<IndexMap<DynReadField, Order>>,
+    #[prop(into)] order_by: Signal<IndexMap<DynReadField, OrderSpec>>,
     #[prop(into)] data: Signal<Result<Arc<Vec<DynReadModel>>, NoDataAvailable>>,
     #[prop(into)] field_renderer_registry: Signal<FieldRendererRegistry<DynReadField>>,
     #[prop(into)] read_allowed: Signal<bool>,
@@ -1,7 +1,7 @@
 use crate::crud_instance::CrudInstanceContext;
 use crate::crud_instance_config::Header;
 use crate::crud_list_view::CrudListViewContext;
-use crudkit_core::Order;
+use crudkit_core::{NullsOrder, Order, OrderSpec, TextOrder, ValueKind};
 use crudkit_web::OrderByUpdateOptions;
 use crudkit_web::prelude::*;
 use indexmap::IndexMap;
@@ -13,7 +13,7 @@ use std::borrow::Cow;
 #[component]
 pub fn CrudTableHeader(
     #[prop(into)] headers: Signal<Vec<Header>>,
-    #[prop(into)] order_by: Signal<IndexMap<DynReadField, Order>>,
+    #[prop(into)] order_by: Signal<IndexMap<DynReadField, OrderSpec>>,
     // Whether an action column should be displayed.
     #[prop(into)] with_actions: Signal<bool>,
     /// Recommended to be set to false when the table body does not actually display content.
@@ -28,6 +28,24 @@ pub fn CrudTableHeader(
         tracing::info!("update order of {:?}", field);
         instance_ctx.oder_by(field, OrderByUpdateOptions { append: false });
     };
+    // Cycles through database default, nulls first and nulls last.
+    let cycle_nulls_of_field = move |field: DynReadField| {
+        instance_ctx.update_order_spec(&field, |spec| {
+            spec.nulls = match spec.nulls {
+                None => Some(NullsOrder::First),
+                Some(NullsOrder::First) => Some(NullsOrder::Last),
+                Some(NullsOrder::Last) => None,
+            };
+        });
+    };
+    let toggle_case_insensitivity_of_field = move |field: DynReadField| {
+        instance_ctx.update_order_spec(&field, |spec| {
+            spec.text = match spec.text {
+                TextOrder::Default => TextOrder::CaseInsensitive,
+                TextOrder::CaseInsensitive | TextOrder::Collation(_) => TextOrder::Default,
+            };
+        });
+    };
     let select_all = Callback::new(move |()| {
         list_ctx.toggle_select_all();
     });
@@ -44,12 +62,19 @@ pub fn CrudTableHeader(
                         move || {
                             let field_clone = field.clone();
                             let update_order = Callback::new(move |()| { update_order_of_field(field_clone.clone()); });
+                            let field_clone = field.clone();
+                            let cycle_nulls = Callback::new(move |()| { cycle_nulls_of_field(field_clone.clone()); });
+                            let field_clone = field.clone();
+                            let toggle_case_insensitivity = Callback::new(move |()| { toggle_case_insensitivity_of_field(field_clone.clone()); });
                             view! {
                                 <HeaderCell
                                     name=options.display_name.clone()
                                     order=order_by.read().get(&field).cloned()
                                     ordering_allowed=options.ordering_allowed
+                                    is_text=field.value_kind() == ValueKind::String
                                     update_order
+                                    cycle_nulls
+                                    toggle_case_insensitivity
                                     apply_min_width_class=options.min_width
                                 />
                             }
@@ -75,12 +100,43 @@ fn SelectAll(all_selected: Signal<bool>, select_all: Callback<()>) -> impl IntoV
     }
 }
 
+/// A button in a header cell changing how the column is ordered, without toggling its direction.
+#[component]
+fn OrderOption(
+    title: &'static str,
+    active: bool,
+    on_click: Callback<()>,
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <button
+            type="button"
+            class="crud-order-option"
+            class:active=active
+            title=title
+            // The header cell reacts to presses, which must not reverse the order.
+            on:pointerdown=|ev| ev.stop_propagation()
+            on:pointerup=|ev| ev.stop_propagation()
+            on:click=move |ev| {
+                ev.stop_propagation();
+                on_click.run(());
+            }
+        >
+            {children()}
+        </button>
+    }
+}
+
 #[component]
 fn HeaderCell(
     name: Cow<'static, str>,
-    order: Option<Order>,
+    order: Option<OrderSpec>,
     ordering_allowed: bool,
+    /// Whether the column holds text, which can be ordered case-insensitively.
+    is_text: bool,
     update_order: Callback<()>,
+    cycle_nulls: Callback<()>,
+    toggle_case_insensitivity: Callback<()>,
     apply_min_width_class: bool,
 ) -> impl IntoView {
     let name_clone = name.clone();
@@ -90,6 +146,29 @@ fn HeaderCell(
             update_order.run(())
         }
     });
+    let options = order.clone().map(|spec| {
+        let (nulls_title, nulls_sign) = match spec.nulls {
+            None => ("Nulls: database default", "&empty;"),
+            Some(NullsOrder::First) => ("Nulls first", "&empty;&uarr;"),
+            Some(NullsOrder::Last) => ("Nulls last", "&empty;&darr;"),
+        };
+        view! {
+            <span class="crud-order-options">
+                <OrderOption title=nulls_title active=spec.nulls.is_some() on_click=cycle_nulls>
+                    <SafeHtml<String> html=nulls_sign/>
+                </OrderOption>
+                {is_text.then(|| view! {
+                    <OrderOption
+                        title="Ignore case"
+                        active=!spec.text.is_default()
+                        on_click=toggle_case_insensitivity
+                    >
+                        "Aa"
+                    </OrderOption>
+                })}
+            </span>
+        }
+    });
     view! {
         <TableHeaderCell
             class:crud-column-ordered=order.is_some()
@@ -99,9 +178,9 @@ fn HeaderCell(
         >
             { name }
             <span class="crud-order-by-sign" class:active=order.is_some()>
-                <SafeHtml<String> html=match order {
-                    Some(order) => {
-                        match order {
+                <SafeHtml<String> html=match order.as_ref() {
+                    Some(spec) => {
+                        match spec.order {
                             Order::Asc => "&uarr;",
                             Order::Desc => "&darr;",
                         }
@@ -109,6 +188,7 @@ fn HeaderCell(
                     None => "&nbsp;",
                 }/>
             </span>
+            { options }
         </TableHeaderCell>
     }
 }
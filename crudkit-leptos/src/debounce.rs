@@ -0,0 +1,37 @@
+use leptos::prelude::*;
+use std::time::Duration;
+
+/// Delay after the last input change before the input is validated on the server.
+pub const LIVE_VALIDATION_DEBOUNCE: Duration = Duration::from_millis(400);
+
+/// Runs a callback only after no further callback was scheduled for `delay`.
+///
+/// Scheduling a callback cancels the one still pending. A pending callback is also cancelled when
+/// the owning reactive scope is cleaned up.
+#[derive(Debug, Clone, Copy)]
+pub struct Debouncer {
+    delay: Duration,
+    pending: StoredValue<Option<TimeoutHandle>>,
+}
+
+impl Debouncer {
+    pub fn new(delay: Duration) -> Self {
+        let pending = StoredValue::new(Option::<TimeoutHandle>::None);
+        on_cleanup(move || {
+            if let Some(handle) = pending.try_get_value().flatten() {
+                handle.clear();
+            }
+        });
+        Self { delay, pending }
+    }
+
+    pub fn schedule(&self, callback: impl FnOnce() + 'static) {
+        if let Some(handle) = self.pending.get_value() {
+            handle.clear();
+        }
+        match set_timeout_with_handle(callback, self.delay) {
+            Ok(handle) => self.pending.set_value(Some(handle)),
+            Err(err) => tracing::error!(?err, "Could not schedule debounced callback."),
+        }
+    }
+}
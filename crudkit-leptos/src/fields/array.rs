@@ -0,0 +1,245 @@
+use crate::fields::optional::OptionalInput;
+use crate::fields::render_label;
+use crate::fields::select::label_of;
+use crudkit_core::condition::ConditionClauseValue;
+use crudkit_core::{EnumVariant, Value, ValueKind};
+use crudkit_web::{FieldMode, FieldOptions};
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// Returns the text shown for an element. Enum elements show the label of their variant.
+fn element_text(element: &Value, variants: &'static [EnumVariant]) -> String {
+    match element {
+        Value::Enum(name) => label_of(variants, name),
+        Value::String(text) => text.clone(),
+        other => match ConditionClauseValue::try_from(other.clone()).map(|value| value.to_json()) {
+            Ok(Ok(serde_json::Value::String(text))) => text,
+            Ok(Ok(json)) => json.to_string(),
+            _ => format!("{other:?}"),
+        },
+    }
+}
+
+/// The elements as chips, followed by an input adding elements if `set` is given.
+///
+/// Elements are entered as text and parsed as `element_kind`. Enum elements are chosen from
+/// their variants instead.
+#[component]
+fn ArrayInput(
+    id: String,
+    element_kind: ValueKind,
+    variants: &'static [EnumVariant],
+    #[prop(into)] get: Signal<Vec<Value>>,
+    #[prop(into, optional)] set: Option<Callback<Vec<Value>>>,
+    #[prop(into)] disabled: Signal<bool>,
+) -> impl IntoView {
+    let text = RwSignal::new(String::new());
+    let error = RwSignal::new(None::<String>);
+
+    let add = move |input: String| {
+        let Some(set) = set else {
+            return;
+        };
+        let input = input.trim();
+        if input.is_empty() {
+            return;
+        }
+        match ConditionClauseValue::String(input.to_owned()).into_value_of_kind(element_kind) {
+            Ok(element) => {
+                let mut elements = get.get_untracked();
+                elements.push(element);
+                set.run(elements);
+                text.set(String::new());
+                error.set(None);
+            }
+            Err(reason) => error.set(Some(reason)),
+        }
+    };
+    let remove = move |index: usize| {
+        if let Some(set) = set {
+            let mut elements = get.get_untracked();
+            if index < elements.len() {
+                elements.remove(index);
+                set.run(elements);
+            }
+        }
+    };
+
+    let adder = match element_kind {
+        ValueKind::Enum => view! {
+            <select
+                id=id
+                class="crud-input-field"
+                prop:value=move || {
+                    get.track();
+                    ""
+                }
+                disabled=move || disabled.get()
+                on:change=move |ev| add(event_target_value(&ev))
+            >
+                <option value="">"Add..."</option>
+                {variants
+                    .iter()
+                    .map(|variant| view! { <option value=variant.name>{variant.label}</option> })
+                    .collect_view()}
+            </select>
+        }
+        .into_any(),
+
+        _ => view! {
+            <div class="crud-array-adder">
+                <input
+                    id=id
+                    class="crud-input-field"
+                    type="text"
+                    placeholder="Add..."
+                    prop:value=move || text.get()
+                    disabled=move || disabled.get()
+                    on:input=move |ev| text.set(event_target_value(&ev))
+                    on:keydown=move |ev: leptos::ev::KeyboardEvent| {
+                        if ev.key() == "Enter" {
+                            ev.prevent_default();
+                            add(text.get_untracked());
+                        }
+                    }
+                />
+                <button
+                    type="button"
+                    class="crud-array-add"
+                    disabled=move || disabled.get()
+                    on:click=move |_| add(text.get_untracked())
+                >
+                    "Add"
+                </button>
+            </div>
+        }
+        .into_any(),
+    };
+
+    view! {
+        <div class="crud-array">
+            <div class="crud-array-elements">
+                {move || {
+                    get.get()
+                        .iter()
+                        .enumerate()
+                        .map(|(index, element)| view! {
+                            <span class="crud-array-element">
+                                {element_text(element, variants)}
+                                {set.is_some().then(|| view! {
+                                    <button
+                                        type="button"
+                                        class="crud-array-remove"
+                                        disabled=move || disabled.get()
+                                        on:click=move |_| remove(index)
+                                    >
+                                        "×"
+                                    </button>
+                                })}
+                            </span>
+                        })
+                        .collect_view()
+                }}
+            </div>
+            {set.is_some().then_some(adder)}
+            {move || error.get().map(|reason| view! { <div class="crud-array-error">{reason}</div> })}
+        </div>
+    }
+}
+
+/// Array field component that handles both required and optional array fields.
+/// The signal always holds Option<Vec<Value>> - Some(elements) for values, None for null.
+#[component]
+pub fn CrudArrayField(
+    id: String,
+    field_options: FieldOptions,
+    field_mode: FieldMode,
+    element_kind: ValueKind,
+    variants: &'static [EnumVariant],
+    #[prop(into)] value: Signal<Option<Vec<Value>>>,
+    is_optional: bool,
+    value_changed: Callback<Result<Value, Arc<dyn std::error::Error>>>,
+) -> impl IntoView {
+    let elements = Signal::derive(move || value.get().unwrap_or_default());
+    let set_elements =
+        Callback::new(move |elements: Vec<Value>| value_changed.run(Ok(Value::Array(elements))));
+
+    match field_mode {
+        FieldMode::Display => {
+            move || match value.get() {
+                Some(elements) => view! {
+                    <span class="crud-array-elements">
+                        {elements
+                            .iter()
+                            .map(|element| view! {
+                                <span class="crud-array-element">{element_text(element, variants)}</span>
+                            })
+                            .collect_view()}
+                    </span>
+                }
+                .into_any(),
+                None => view! { "-" }.into_any(),
+            }
+        }
+        .into_any(),
+
+        FieldMode::Readable if is_optional => view! {
+            {render_label(field_options.label.clone())}
+            <OptionalInput
+                get=value
+                set={move |_: Option<Vec<Value>>| {}}
+                disabled=true
+                default_provider={move || Vec::new()}
+                input_renderer={move |_disabled_or_null| view! {
+                    <ArrayInput id=id.clone() element_kind variants get=elements disabled=true />
+                }}
+            />
+        }
+        .into_any(),
+
+        FieldMode::Readable => view! {
+            {render_label(field_options.label.clone())}
+            <ArrayInput id=id.clone() element_kind variants get=elements disabled=true />
+        }
+        .into_any(),
+
+        FieldMode::Editable if is_optional => {
+            let disabled = field_options.disabled;
+            view! {
+                {render_label(field_options.label.clone())}
+                <OptionalInput
+                    get=value
+                    set={move |opt: Option<Vec<Value>>| {
+                        value_changed.run(Ok(opt.map(Value::Array).unwrap_or(Value::Null)));
+                    }}
+                    disabled
+                    default_provider={move || Vec::new()}
+                    input_renderer={move |disabled_or_null| view! {
+                        <ArrayInput
+                            id=id.clone()
+                            element_kind
+                            variants
+                            get=elements
+                            set=set_elements
+                            disabled=disabled_or_null
+                        />
+                    }}
+                />
+            }
+        }
+        .into_any(),
+
+        FieldMode::Editable => view! {
+            {render_label(field_options.label.clone())}
+            <ArrayInput
+                id=id.clone()
+                element_kind
+                variants
+                get=elements
+                set=set_elements
+                disabled=field_options.disabled
+            />
+        }
+        .into_any(),
+    }
+}
@@ -0,0 +1,137 @@
+use crate::fields::optional::OptionalInput;
+use crate::fields::render_label;
+use crudkit_core::Value;
+use crudkit_web::{DateTimeDisplay, FieldMode, FieldOptions};
+use leptonic::Out;
+use leptos::prelude::*;
+use std::sync::Arc;
+use time::Date;
+use time::format_description::well_known::Iso8601;
+use time::macros::format_description;
+
+/// Returns a default Date (today, in UTC).
+fn default_date() -> Date {
+    time::OffsetDateTime::now_utc().date()
+}
+
+/// Date picker, using the browser's native `<input type="date">`.
+#[component]
+fn DateInput(
+    id: String,
+    #[prop(into)] get: Signal<Option<Date>>,
+    #[prop(into, optional)] set: Option<Out<Date>>,
+    #[prop(into)] disabled: Signal<bool>,
+) -> impl IntoView {
+    let text = move || {
+        get.get()
+            .map(|date| {
+                date.format(format_description!("[year]-[month]-[day]"))
+                    .expect("infallible with valid format")
+            })
+            .unwrap_or_default()
+    };
+
+    view! {
+        <input
+            id=id
+            class="crud-input-field"
+            type="date"
+            prop:value=text
+            disabled=move || disabled.get()
+            on:change=move |ev| {
+                // Cleared inputs report an empty value, which leaves the field unchanged.
+                let parsed = Date::parse(&event_target_value(&ev), &Iso8601::DEFAULT);
+                if let (Some(set), Ok(date)) = (set, parsed) {
+                    set.set(date);
+                }
+            }
+        />
+    }
+}
+
+/// Date field component that handles both required and optional date fields.
+/// The signal always holds Option<Date> - Some(value) for values, None for null.
+#[component]
+pub fn CrudDateField(
+    id: String,
+    field_options: FieldOptions,
+    field_mode: FieldMode,
+    #[prop(into)] value: Signal<Option<Date>>,
+    is_optional: bool,
+    value_changed: Callback<Result<Value, Arc<dyn std::error::Error>>>,
+) -> impl IntoView {
+    match field_mode {
+        FieldMode::Display => match field_options.date_time_display {
+            DateTimeDisplay::IsoUtc => {
+                move || match value.get() {
+                    Some(date) => view! { {date.format(format_description!("[year]-[month]-[day]")).expect("infallible with valid format")} }.into_any(),
+                    None => view! { "-" }.into_any(),
+                }
+            }
+            .into_any(),
+            DateTimeDisplay::LocalizedLocal => {
+                move || match value.get() {
+                    // TODO: Use icu4x formatting using the current users locale!
+                    Some(date) => view! { {date.format(format_description!("[day].[month].[year]")).expect("infallible with valid format")} }.into_any(),
+                    None => view! { "-" }.into_any(),
+                }
+            }
+            .into_any(),
+        },
+
+        FieldMode::Readable if is_optional => view! {
+            {render_label(field_options.label.clone())}
+            <OptionalInput
+                get=value
+                set={move |_: Option<Date>| {}}
+                disabled=true
+                default_provider=default_date
+                input_renderer={move |_disabled_or_null| view! {
+                    <DateInput id=id.clone() get=value disabled=true />
+                }}
+            />
+        }
+        .into_any(),
+
+        FieldMode::Readable => view! {
+            {render_label(field_options.label.clone())}
+            <DateInput id=id.clone() get=value disabled=true />
+        }
+        .into_any(),
+
+        FieldMode::Editable if is_optional => {
+            let disabled = field_options.disabled;
+            view! {
+                {render_label(field_options.label.clone())}
+                <OptionalInput
+                    get=value
+                    set={move |opt: Option<Date>| {
+                        value_changed.run(Ok(opt.map(Value::Date).unwrap_or(Value::Null)));
+                    }}
+                    disabled
+                    default_provider=default_date
+                    input_renderer={move |disabled_or_null| view! {
+                        <DateInput
+                            id=id.clone()
+                            get=value
+                            set=Callback::new(move |date: Date| value_changed.run(Ok(Value::Date(date))))
+                            disabled=disabled_or_null
+                        />
+                    }}
+                />
+            }
+        }
+        .into_any(),
+
+        FieldMode::Editable => view! {
+            {render_label(field_options.label.clone())}
+            <DateInput
+                id=id.clone()
+                get=value
+                set=Callback::new(move |date: Date| value_changed.run(Ok(Value::Date(date))))
+                disabled=field_options.disabled
+            />
+        }
+        .into_any(),
+    }
+}
@@ -0,0 +1,243 @@
+use crate::fields::optional::OptionalInput;
+use crate::fields::render_label;
+use crudkit_core::Value;
+use crudkit_web::{FieldMode, FieldOptions};
+use leptonic::components::input::TextInput;
+use leptos::prelude::*;
+use rust_decimal::{Decimal, RoundingStrategy};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// How decimal values are shown and entered.
+///
+/// Input accepts the configured separators, e.g. `1.234,5` for German users. Grouping
+/// separators and whitespace are ignored while parsing.
+///
+/// Decimal fields without a custom renderer use the format provided through `provide_context`,
+/// e.g. `provide_context(DecimalFormat::for_locale("de-DE"))`, or `DecimalFormat::default()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecimalFormat {
+    /// Number of fractional digits shown. Values and input are rounded to this scale, half away
+    /// from zero. `None` keeps the scale of the value.
+    pub scale: Option<u32>,
+    pub decimal_separator: char,
+    pub grouping_separator: Option<char>,
+}
+
+impl Default for DecimalFormat {
+    fn default() -> Self {
+        Self {
+            scale: None,
+            decimal_separator: '.',
+            grouping_separator: None,
+        }
+    }
+}
+
+impl DecimalFormat {
+    /// The separators used in the given BCP 47 locale, e.g. `de-DE` or `fr`.
+    /// Unknown locales use `.` and `,` as in English.
+    pub fn for_locale(locale: &str) -> Self {
+        let mut subtags = locale.split(['-', '_']).map(str::to_ascii_lowercase);
+        let language = subtags.next().unwrap_or_default();
+        let region = subtags.next();
+        let (decimal_separator, grouping_separator) = match (language.as_str(), region.as_deref()) {
+            ("de", Some("ch" | "li")) => ('.', '\''),
+            ("de" | "es" | "it" | "nl" | "pt" | "da" | "id" | "tr" | "el", _) => (',', '.'),
+            ("fr" | "sv" | "fi" | "nb" | "no" | "cs" | "sk" | "pl" | "ru" | "uk", _) => {
+                (',', '\u{a0}')
+            }
+            _ => ('.', ','),
+        };
+        Self {
+            scale: None,
+            decimal_separator,
+            grouping_separator: Some(grouping_separator),
+        }
+    }
+
+    pub fn with_scale(mut self, scale: u32) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    pub fn format(&self, value: Decimal) -> String {
+        let plain = match self.scale {
+            Some(scale) => format!("{:.*}", scale as usize, self.round(value)),
+            None => value.to_string(),
+        };
+        let (sign, unsigned) = match plain.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", plain.as_str()),
+        };
+        let (integer, fraction) = match unsigned.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+
+        let mut formatted = sign.to_owned();
+        for (i, digit) in integer.chars().enumerate() {
+            let remaining = integer.len() - i;
+            let separator = self
+                .grouping_separator
+                .filter(|_| i > 0 && remaining % 3 == 0);
+            if let Some(separator) = separator {
+                formatted.push(separator);
+            }
+            formatted.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            formatted.push(self.decimal_separator);
+            formatted.push_str(fraction);
+        }
+        formatted
+    }
+
+    /// Parses `text`, rounded to the scale.
+    pub fn parse(&self, text: &str) -> Result<Decimal, rust_decimal::Error> {
+        self.parse_unrounded(text).map(|value| self.round(value))
+    }
+
+    fn parse_unrounded(&self, text: &str) -> Result<Decimal, rust_decimal::Error> {
+        let normalized = text
+            .chars()
+            .filter(|c| !c.is_whitespace() && Some(*c) != self.grouping_separator)
+            .map(|c| match c == self.decimal_separator {
+                true => '.',
+                false => c,
+            })
+            .collect::<String>();
+        Decimal::from_str(&normalized)
+    }
+
+    fn round(&self, value: Decimal) -> Decimal {
+        match self.scale {
+            Some(scale) => {
+                value.round_dp_with_strategy(scale, RoundingStrategy::MidpointAwayFromZero)
+            }
+            None => value,
+        }
+    }
+}
+
+/// Decimal field component that handles both required and optional decimal fields.
+/// The signal always holds Option<Decimal> - Some(value) for values, None for null.
+#[component]
+pub fn CrudDecimalField(
+    id: String,
+    field_options: FieldOptions,
+    field_mode: FieldMode,
+    #[prop(into)] value: Signal<Option<Decimal>>,
+    is_optional: bool,
+    value_changed: Callback<Result<Value, Arc<dyn std::error::Error>>>,
+    #[prop(optional)] format: DecimalFormat,
+) -> impl IntoView {
+    let format_value = move |value: Option<Decimal>| match value {
+        Some(value) => format.format(value),
+        None => String::new(),
+    };
+
+    // The text currently entered. Kept while it denotes the current value exactly, so that
+    // typing `1,5` is not replaced with `1,50` midway. Input exceeding the scale is replaced
+    // with the rounded value that is submitted.
+    let text = RwSignal::new(format_value(value.get_untracked()));
+    Effect::new(move |_| {
+        let current = value.get();
+        if format.parse_unrounded(&text.get_untracked()).ok() != current {
+            text.set(format_value(current));
+        }
+    });
+    let set_text = move |new: String| match format.parse_unrounded(&new) {
+        Ok(exact) => {
+            let decimal = format.round(exact);
+            text.set(match decimal == exact {
+                true => new,
+                false => format.format(decimal),
+            });
+            value_changed.run(Ok(Value::Decimal(decimal)));
+        }
+        Err(err) => {
+            text.set(new);
+            value_changed.run(Err(Arc::new(err)));
+        }
+    };
+
+    match field_mode {
+        FieldMode::Display => {
+            move || match value.get() {
+                Some(v) => view! { {format.format(v)} }.into_any(),
+                None => view! { "-" }.into_any(),
+            }
+        }
+        .into_any(),
+
+        FieldMode::Readable if is_optional => view! {
+            {render_label(field_options.label.clone())}
+            <OptionalInput
+                get=value
+                set={move |_: Option<Decimal>| {}}
+                disabled=true
+                default_provider={move || Decimal::ZERO}
+                input_renderer={move |_disabled_or_null| view! {
+                    <TextInput
+                        attr:id=id.clone()
+                        attr:class="crud-input-field"
+                        disabled=true
+                        get=text
+                    />
+                }}
+            />
+        }
+        .into_any(),
+
+        FieldMode::Readable => view! {
+            {render_label(field_options.label.clone())}
+            <TextInput
+                attr:id=id.clone()
+                attr:class="crud-input-field"
+                disabled=true
+                get=text
+            />
+        }
+        .into_any(),
+
+        FieldMode::Editable if is_optional => {
+            let disabled = field_options.disabled;
+            view! {
+                {render_label(field_options.label.clone())}
+                <OptionalInput
+                    get=value
+                    set={move |opt: Option<Decimal>| {
+                        value_changed.run(Ok(opt.map(Value::Decimal).unwrap_or(Value::Null)));
+                    }}
+                    disabled
+                    default_provider={move || Decimal::ZERO}
+                    input_renderer={move |disabled_or_null| view! {
+                        <TextInput
+                            attr:id=id.clone()
+                            attr:class="crud-input-field"
+                            attr:inputmode="decimal"
+                            disabled=disabled_or_null
+                            get=text
+                            set=set_text
+                        />
+                    }}
+                />
+            }
+        }
+        .into_any(),
+
+        FieldMode::Editable => view! {
+            {render_label(field_options.label.clone())}
+            <TextInput
+                attr:id=id.clone()
+                attr:class="crud-input-field"
+                attr:inputmode="decimal"
+                disabled=field_options.disabled
+                get=text
+                set=set_text
+            />
+        }
+        .into_any(),
+    }
+}
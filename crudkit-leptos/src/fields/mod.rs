@@ -1,15 +1,20 @@
 use crate::crud_field_label::CrudFieldLabelOpt;
+use crate::fields::array::CrudArrayField;
 use crate::fields::boolean::CrudBoolField;
+use crate::fields::date::CrudDateField;
 use crate::fields::date_time::CrudPrimitiveDateTimeField;
+use crate::fields::decimal::{CrudDecimalField, DecimalFormat};
 use crate::fields::duration::CrudDurationField;
 use crate::fields::json::CrudJsonField;
 use crate::fields::number::{
     CrudF32Field, CrudF64Field, CrudI128Field, CrudI16Field, CrudI32Field, CrudI64Field,
     CrudI8Field, CrudU128Field, CrudU16Field, CrudU32Field, CrudU64Field, CrudU8Field,
 };
+use crate::fields::select::{CrudEnumField, EnumFieldStyle};
 use crate::fields::string::CrudStringField;
+use crate::fields::time::CrudTimeField;
 use crate::fields::uuid::CrudUuidField;
-use crate::fields::validation_status::CrudValidationStatusField;
+use crate::fields::validation_status::{CrudValidationStatusField, ValidationStatusKind};
 use crate::ReactiveField;
 use crudkit_core::{Value, ValueKind};
 use crudkit_web::prelude::*;
@@ -22,8 +27,11 @@ use std::fmt::Debug;
 use std::sync::Arc;
 use ::uuid::Uuid;
 
+pub mod array;
 pub mod boolean;
+pub mod date;
 pub mod date_time;
+pub mod decimal;
 pub mod duration;
 pub mod json;
 pub mod number;
@@ -31,6 +39,7 @@ pub mod optional;
 pub mod select;
 pub mod string;
 pub mod text;
+pub mod time;
 pub mod uuid;
 pub mod validation_status;
 
@@ -107,6 +116,41 @@ impl<F: TypeErasedField> FieldRenderer<F> {
         )
     }
 
+    /// Creates a `FieldRenderer` that uses the predefined `CrudValidationStatusField` component
+    /// to render a boolean field indicating "validation infos exist" as an info sign when `true`.
+    /// Infos are hints and, unlike errors, nothing is shown when none exist.
+    ///
+    /// # Usage
+    ///
+    /// In a crud instance configuration, use it like this:
+    /// ```ignore
+    /// read_field_renderer: FieldRendererRegistry::builder()
+    ///     .register(
+    ///         ReadUser::HasValidationInfos,
+    ///         FieldRenderer::for_validation_info_status(),
+    ///     )
+    ///     .build(),
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// When used on any field not of type `bool`!
+    pub fn for_validation_info_status() -> FieldRenderer<F> {
+        FieldRenderer::new(
+            move |_signals, _field, field_mode, field_options, value, _value_changed| {
+                view! {
+                    <CrudValidationStatusField
+                        id=format!("f{}", Uuid::new_v4())
+                        field_options
+                        field_mode
+                        value=Signal::derive(move || value.value.get().expect_bool())
+                        kind=ValidationStatusKind::Infos
+                    />
+                }
+            },
+        )
+    }
+
     pub fn for_void() -> FieldRenderer<F> {
         FieldRenderer::new(
             move |_signals, _field, _field_mode, _field_options, _value, _value_changed| (),
@@ -347,6 +391,32 @@ impl<F: TypeErasedField> FieldRenderer<F> {
         )
     }
 
+    /// Renders a decimal field, showing and accepting values as described by `format`.
+    ///
+    /// # Usage
+    ///
+    /// ```ignore
+    /// FieldRenderer::for_decimal(DecimalFormat::for_locale("de-DE").with_scale(2))
+    /// ```
+    pub fn for_decimal(format: DecimalFormat) -> FieldRenderer<F> {
+        FieldRenderer::new(
+            move |_signals, field: F, field_mode, field_options, value, value_changed| {
+                let typed_value = Signal::derive(move || value.value.get().as_decimal());
+                view! {
+                    <CrudDecimalField
+                        id=format!("f{}", Uuid::new_v4())
+                        field_options=field_options
+                        field_mode=field_mode
+                        value=typed_value
+                        is_optional=field.is_optional()
+                        value_changed=value_changed
+                        format=format
+                    />
+                }
+            },
+        )
+    }
+
     pub fn for_string() -> FieldRenderer<F> {
         FieldRenderer::new(
             move |_signals, field: F, field_mode, field_options, value, value_changed| {
@@ -365,6 +435,28 @@ impl<F: TypeErasedField> FieldRenderer<F> {
         )
     }
 
+    /// Renders an enum field, offering the field's variants as described by `style`.
+    /// Display mode shows the label of the current variant as a badge.
+    pub fn for_enum(style: EnumFieldStyle) -> FieldRenderer<F> {
+        FieldRenderer::new(
+            move |_signals, field: F, field_mode, field_options, value, value_changed| {
+                let typed_value = Signal::derive(move || value.value.get().as_enum().cloned());
+                view! {
+                    <CrudEnumField
+                        id=format!("f{}", Uuid::new_v4())
+                        field_options=field_options
+                        field_mode=field_mode
+                        variants=field.enum_variants()
+                        style=style
+                        value=typed_value
+                        is_optional=field.is_optional()
+                        value_changed=value_changed
+                    />
+                }
+            },
+        )
+    }
+
     pub fn for_json() -> FieldRenderer<F> {
         FieldRenderer::new(
             move |_signals, field: F, field_mode, field_options, value, value_changed| {
@@ -428,6 +520,42 @@ impl<F: TypeErasedField> FieldRenderer<F> {
         )
     }
 
+    pub fn for_date() -> FieldRenderer<F> {
+        FieldRenderer::new(
+            move |_signals, field: F, field_mode, field_options, value, value_changed| {
+                let typed_value = Signal::derive(move || value.value.get().as_date());
+                view! {
+                    <CrudDateField
+                        id=format!("f{}", Uuid::new_v4())
+                        field_options=field_options
+                        field_mode=field_mode
+                        value=typed_value
+                        is_optional=field.is_optional()
+                        value_changed=value_changed
+                    />
+                }
+            },
+        )
+    }
+
+    pub fn for_time() -> FieldRenderer<F> {
+        FieldRenderer::new(
+            move |_signals, field: F, field_mode, field_options, value, value_changed| {
+                let typed_value = Signal::derive(move || value.value.get().as_time());
+                view! {
+                    <CrudTimeField
+                        id=format!("f{}", Uuid::new_v4())
+                        field_options=field_options
+                        field_mode=field_mode
+                        value=typed_value
+                        is_optional=field.is_optional()
+                        value_changed=value_changed
+                    />
+                }
+            },
+        )
+    }
+
     pub fn for_duration() -> FieldRenderer<F> {
         FieldRenderer::new(
             move |_signals, field: F, field_mode, field_options, value, value_changed| {
@@ -446,10 +574,24 @@ impl<F: TypeErasedField> FieldRenderer<F> {
         )
     }
 
+    /// Renders an array field as a list of its elements, which are added and removed
+    /// individually.
     pub fn for_array() -> FieldRenderer<F> {
         FieldRenderer::new(
-            move |_signals, _field, _field_mode, _field_options, _value, _value_changed| {
-                view! { "TODO: Render Array" }
+            move |_signals, field: F, field_mode, field_options, value, value_changed| {
+                let typed_value = Signal::derive(move || value.value.get().take_array());
+                view! {
+                    <CrudArrayField
+                        id=format!("f{}", Uuid::new_v4())
+                        field_options=field_options
+                        field_mode=field_mode
+                        element_kind=field.element_kind().unwrap_or(ValueKind::String)
+                        variants=field.enum_variants()
+                        value=typed_value
+                        is_optional=field.is_optional()
+                        value_changed=value_changed
+                    />
+                }
             },
         )
     }
@@ -499,11 +641,17 @@ pub(crate) fn default_field_renderer<F: TypeErasedField>(kind: ValueKind) -> Fie
         ValueKind::I128 => FieldRenderer::for_i128(),
         ValueKind::F32 => FieldRenderer::for_f32(),
         ValueKind::F64 => FieldRenderer::for_f64(),
+        ValueKind::Decimal => {
+            FieldRenderer::for_decimal(use_context::<DecimalFormat>().unwrap_or_default())
+        }
         ValueKind::String => FieldRenderer::for_string(),
+        ValueKind::Enum => FieldRenderer::for_enum(EnumFieldStyle::Select),
         ValueKind::Json => FieldRenderer::for_json(),
         ValueKind::Uuid => FieldRenderer::for_uuid(),
         ValueKind::PrimitiveDateTime => FieldRenderer::for_primitive_date_time(),
         ValueKind::OffsetDateTime => FieldRenderer::for_offset_date_time(),
+        ValueKind::Date => FieldRenderer::for_date(),
+        ValueKind::Time => FieldRenderer::for_time(),
         ValueKind::Duration => FieldRenderer::for_duration(),
         ValueKind::Array => FieldRenderer::for_array(),
         ValueKind::Other => FieldRenderer::for_other(),
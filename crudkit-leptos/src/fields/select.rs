@@ -1,118 +1,187 @@
-//#[component]
-//pub fn CrudSelectField<FV: FieldValue>(
-//    id: String,
-//    field_config: Option<Box<dyn SelectConfigTrait>>,
-//    field_options: FieldOptions,
-//    field_mode: FieldMode,
-//    #[prop(into)] value: Signal<Box<dyn CrudSelectableTrait>>,
-//    value_changed: Callback<Result<Value, Arc<dyn std::error::Error>>>,
-//) -> impl IntoView {
-//    match field_mode {
-//        FieldMode::Display => view! {
-//            <span>
-//                { move || format!("{:?}", value.read()) }
-//            </span>
-//        }.into_any(),
-//        FieldMode::Readable => view! {
-//            <div class="crud-field">
-//                {render_label(field_options.label.clone())}
-//                {match field_config {
-//                    None => {
-//                        view! {
-//                            <Alert variant=AlertVariant::Danger>
-//                                <AlertTitle slot>"Config error"</AlertTitle>
-//                                <AlertContent slot>"Missing a field_config entry for this field."</AlertContent>
-//                            </Alert>
-//                        }.into_any()
-//                    }
-//                    Some(field_config) => {
-//                        field_config.render_select(
-//                            value,
-//                            Callback::new(move |o| { value_changed.run(Ok(Value::Select(o))) }),
-//                        )
-//                    }
-//                }}
-//
-//            </div>
-//        }.into_any(),
-//        FieldMode::Editable => view! {
-//            <div class="crud-field">
-//                {render_label(field_options.label.clone())}
-//                {match field_config {
-//                    None => view! {
-//                        <Alert variant=AlertVariant::Danger>
-//                            <AlertTitle slot>"Config error"</AlertTitle>
-//                            <AlertContent slot>"Missing a field_config entry for this field."</AlertContent>
-//                        </Alert>
-//                    }.into_any(),
-//                    Some(field_config) =>
-//                        field_config.render_select(
-//                            value,
-//                            Callback::new(move |o| { value_changed.run(Ok(Value::Select(o))) }),
-//                        )
-//                }}
-//            </div>
-//        }.into_any(),
-//    }
-//}
-//
-//#[component]
-//pub fn CrudOptionalSelectField(
-//    id: String,
-//    field_config: Option<Box<dyn SelectConfigTrait>>,
-//    field_options: FieldOptions,
-//    field_mode: FieldMode,
-//    #[prop(into)] value: Signal<Option<Box<dyn CrudSelectableTrait>>>,
-//    value_changed: Callback<Result<Value, Arc<dyn std::error::Error>>>,
-//) -> impl IntoView {
-//    match field_mode {
-//        FieldMode::Display => { move || format!("{:?}", value.get()) }.into_any(),
-//        FieldMode::Readable => view! {
-//            <div class="crud-field">
-//                {render_label(field_options.label.clone())}
-//                {match field_config {
-//                    None => {
-//                        view! {
-//                            <Alert variant=AlertVariant::Danger>
-//                                <AlertTitle slot>"Config error"</AlertTitle>
-//                                <AlertContent slot>"Missing a field_config entry for this field."</AlertContent>
-//                            </Alert>
-//                        }.into_any()
-//                    }
-//                    Some(field_config) => {
-//                        field_config.render_optional_select(
-//                            value,
-//                            Callback::new(move |o| { value_changed.run(Ok(Value::OptionalSelect(o))) }),
-//                        )
-//                    }
-//                }}
-//
-//            </div>
-//        }
-//            .into_any(),
-//        FieldMode::Editable => view! {
-//            <div class="crud-field">
-//                {render_label(field_options.label.clone())}
-//                {match field_config {
-//                    None => {
-//                        view! {
-//                            <Alert variant=AlertVariant::Danger>
-//                                <AlertTitle slot>"Config error"</AlertTitle>
-//                                <AlertContent slot>"Missing a field_config entry for this field."</AlertContent>
-//                            </Alert>
-//                        }.into_any()
-//                    }
-//                    Some(field_config) => {
-//                        field_config.render_optional_select(
-//                            value,
-//                            Callback::new(move |o| { value_changed.run(Ok(Value::OptionalSelect(o))) }),
-//                        )
-//                    }
-//                }}
-//
-//            </div>
-//        }
-//            .into_any(),
-//    }
-//}
-//
+use crate::fields::optional::OptionalInput;
+use crate::fields::render_label;
+use crudkit_core::{EnumVariant, Value};
+use crudkit_web::{FieldMode, FieldOptions};
+use leptonic::Out;
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// How the variants of an enum field are offered for selection.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EnumFieldStyle {
+    /// A dropdown, suited for many variants.
+    #[default]
+    Select,
+
+    /// One radio button per variant, suited for few variants.
+    Radio,
+}
+
+/// Returns the label of the variant named `name`, falling back to the name itself.
+pub(crate) fn label_of(variants: &'static [EnumVariant], name: &str) -> String {
+    variants
+        .iter()
+        .find(|variant| variant.name == name)
+        .map_or_else(|| name.to_owned(), |variant| variant.label.to_owned())
+}
+
+/// Variant selection, using either a native `<select>` or a group of radio buttons.
+#[component]
+fn EnumInput(
+    id: String,
+    variants: &'static [EnumVariant],
+    style: EnumFieldStyle,
+    #[prop(into)] get: Signal<Option<String>>,
+    #[prop(into, optional)] set: Option<Out<String>>,
+    #[prop(into)] disabled: Signal<bool>,
+) -> impl IntoView {
+    match style {
+        EnumFieldStyle::Select => view! {
+            <select
+                id=id
+                class="crud-input-field"
+                prop:value=move || get.get().unwrap_or_default()
+                disabled=move || disabled.get()
+                on:change=move |ev| {
+                    if let Some(set) = set {
+                        set.set(event_target_value(&ev));
+                    }
+                }
+            >
+                {variants
+                    .iter()
+                    .map(|variant| view! {
+                        <option
+                            value=variant.name
+                            selected=move || get.get().as_deref() == Some(variant.name)
+                        >
+                            {variant.label}
+                        </option>
+                    })
+                    .collect_view()}
+            </select>
+        }
+        .into_any(),
+
+        EnumFieldStyle::Radio => view! {
+            <div id=id.clone() class="crud-radio-group">
+                {variants
+                    .iter()
+                    .map(|variant| view! {
+                        <label class="crud-radio">
+                            <input
+                                type="radio"
+                                name=id.clone()
+                                value=variant.name
+                                prop:checked=move || get.get().as_deref() == Some(variant.name)
+                                disabled=move || disabled.get()
+                                on:change=move |_ev| {
+                                    if let Some(set) = set {
+                                        set.set(variant.name.to_owned());
+                                    }
+                                }
+                            />
+                            {variant.label}
+                        </label>
+                    })
+                    .collect_view()}
+            </div>
+        }
+        .into_any(),
+    }
+}
+
+/// Enum field component that handles both required and optional enum fields.
+/// The signal always holds Option<String> - Some(variant name) for values, None for null.
+#[component]
+pub fn CrudEnumField(
+    id: String,
+    field_options: FieldOptions,
+    field_mode: FieldMode,
+    variants: &'static [EnumVariant],
+    style: EnumFieldStyle,
+    #[prop(into)] value: Signal<Option<String>>,
+    is_optional: bool,
+    value_changed: Callback<Result<Value, Arc<dyn std::error::Error>>>,
+) -> impl IntoView {
+    let default_variant = move || {
+        variants
+            .first()
+            .map(|variant| variant.name.to_owned())
+            .unwrap_or_default()
+    };
+
+    match field_mode {
+        FieldMode::Display => {
+            move || match value.get() {
+                Some(name) => view! {
+                    <span class="crud-enum-badge" data-value=name.clone()>
+                        {label_of(variants, &name)}
+                    </span>
+                }
+                .into_any(),
+                None => view! { "-" }.into_any(),
+            }
+        }
+        .into_any(),
+
+        FieldMode::Readable if is_optional => view! {
+            {render_label(field_options.label.clone())}
+            <OptionalInput
+                get=value
+                set={move |_: Option<String>| {}}
+                disabled=true
+                default_provider=default_variant
+                input_renderer={move |_disabled_or_null| view! {
+                    <EnumInput id=id.clone() variants style get=value disabled=true />
+                }}
+            />
+        }
+        .into_any(),
+
+        FieldMode::Readable => view! {
+            {render_label(field_options.label.clone())}
+            <EnumInput id=id.clone() variants style get=value disabled=true />
+        }
+        .into_any(),
+
+        FieldMode::Editable if is_optional => {
+            let disabled = field_options.disabled;
+            view! {
+                {render_label(field_options.label.clone())}
+                <OptionalInput
+                    get=value
+                    set={move |opt: Option<String>| {
+                        value_changed.run(Ok(opt.map(Value::Enum).unwrap_or(Value::Null)));
+                    }}
+                    disabled
+                    default_provider=default_variant
+                    input_renderer={move |disabled_or_null| view! {
+                        <EnumInput
+                            id=id.clone()
+                            variants
+                            style
+                            get=value
+                            set=Callback::new(move |name: String| value_changed.run(Ok(Value::Enum(name))))
+                            disabled=disabled_or_null
+                        />
+                    }}
+                />
+            }
+        }
+        .into_any(),
+
+        FieldMode::Editable => view! {
+            {render_label(field_options.label.clone())}
+            <EnumInput
+                id=id.clone()
+                variants
+                style
+                get=value
+                set=Callback::new(move |name: String| value_changed.run(Ok(Value::Enum(name))))
+                disabled=field_options.disabled
+            />
+        }
+        .into_any(),
+    }
+}
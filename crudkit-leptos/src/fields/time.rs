@@ -0,0 +1,138 @@
+use crate::fields::optional::OptionalInput;
+use crate::fields::render_label;
+use crudkit_core::Value;
+use crudkit_web::{DateTimeDisplay, FieldMode, FieldOptions};
+use leptonic::Out;
+use leptos::prelude::*;
+use std::sync::Arc;
+use time::Time;
+use time::format_description::well_known::Iso8601;
+use time::macros::format_description;
+
+/// Returns a default Time (midnight).
+fn default_time() -> Time {
+    Time::MIDNIGHT
+}
+
+/// Time picker, using the browser's native `<input type="time">`, with a precision of seconds.
+#[component]
+fn TimeInput(
+    id: String,
+    #[prop(into)] get: Signal<Option<Time>>,
+    #[prop(into, optional)] set: Option<Out<Time>>,
+    #[prop(into)] disabled: Signal<bool>,
+) -> impl IntoView {
+    let text = move || {
+        get.get()
+            .map(|time| {
+                time.format(format_description!("[hour]:[minute]:[second]"))
+                    .expect("infallible with valid format")
+            })
+            .unwrap_or_default()
+    };
+
+    view! {
+        <input
+            id=id
+            class="crud-input-field"
+            type="time"
+            step="1"
+            prop:value=text
+            disabled=move || disabled.get()
+            on:change=move |ev| {
+                // Cleared inputs report an empty value, which leaves the field unchanged.
+                let parsed = Time::parse(&event_target_value(&ev), &Iso8601::DEFAULT);
+                if let (Some(set), Ok(time)) = (set, parsed) {
+                    set.set(time);
+                }
+            }
+        />
+    }
+}
+
+/// Time field component that handles both required and optional time fields.
+/// The signal always holds Option<Time> - Some(value) for values, None for null.
+#[component]
+pub fn CrudTimeField(
+    id: String,
+    field_options: FieldOptions,
+    field_mode: FieldMode,
+    #[prop(into)] value: Signal<Option<Time>>,
+    is_optional: bool,
+    value_changed: Callback<Result<Value, Arc<dyn std::error::Error>>>,
+) -> impl IntoView {
+    match field_mode {
+        FieldMode::Display => match field_options.date_time_display {
+            DateTimeDisplay::IsoUtc => {
+                move || match value.get() {
+                    Some(time) => view! { {time.format(format_description!("[hour]:[minute]:[second]")).expect("infallible with valid format")} }.into_any(),
+                    None => view! { "-" }.into_any(),
+                }
+            }
+            .into_any(),
+            DateTimeDisplay::LocalizedLocal => {
+                move || match value.get() {
+                    // TODO: Use icu4x formatting using the current users locale!
+                    Some(time) => view! { {time.format(format_description!("[hour]:[minute]")).expect("infallible with valid format")} }.into_any(),
+                    None => view! { "-" }.into_any(),
+                }
+            }
+            .into_any(),
+        },
+
+        FieldMode::Readable if is_optional => view! {
+            {render_label(field_options.label.clone())}
+            <OptionalInput
+                get=value
+                set={move |_: Option<Time>| {}}
+                disabled=true
+                default_provider=default_time
+                input_renderer={move |_disabled_or_null| view! {
+                    <TimeInput id=id.clone() get=value disabled=true />
+                }}
+            />
+        }
+        .into_any(),
+
+        FieldMode::Readable => view! {
+            {render_label(field_options.label.clone())}
+            <TimeInput id=id.clone() get=value disabled=true />
+        }
+        .into_any(),
+
+        FieldMode::Editable if is_optional => {
+            let disabled = field_options.disabled;
+            view! {
+                {render_label(field_options.label.clone())}
+                <OptionalInput
+                    get=value
+                    set={move |opt: Option<Time>| {
+                        value_changed.run(Ok(opt.map(Value::Time).unwrap_or(Value::Null)));
+                    }}
+                    disabled
+                    default_provider=default_time
+                    input_renderer={move |disabled_or_null| view! {
+                        <TimeInput
+                            id=id.clone()
+                            get=value
+                            set=Callback::new(move |time: Time| value_changed.run(Ok(Value::Time(time))))
+                            disabled=disabled_or_null
+                        />
+                    }}
+                />
+            }
+        }
+        .into_any(),
+
+        FieldMode::Editable => view! {
+            {render_label(field_options.label.clone())}
+            <TimeInput
+                id=id.clone()
+                get=value
+                set=Callback::new(move |time: Time| value_changed.run(Ok(Value::Time(time))))
+                disabled=field_options.disabled
+            />
+        }
+        .into_any(),
+    }
+}
@@ -4,28 +4,54 @@ use leptonic::components::icon::Icon;
 use leptonic::prelude::icondata;
 use leptos::prelude::*;
 
+/// The kind of violations a validation status field reports.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationStatusKind {
+    /// Errors, shown as an exclamation mark or, when none exist, a checkmark.
+    #[default]
+    Errors,
+
+    /// Infos, shown as an info sign or, when none exist, not at all.
+    Infos,
+}
+
+fn render_status(kind: ValidationStatusKind, present: bool) -> AnyView {
+    match (kind, present) {
+        (ValidationStatusKind::Errors, true) => view! {
+            <span class="crud-validation-status crud-validation-error">
+                <Icon icon=icondata::BsExclamationTriangleFill/>
+            </span>
+        }
+        .into_any(),
+        (ValidationStatusKind::Errors, false) => {
+            view! { <Icon icon=icondata::BsCheck/> }.into_any()
+        }
+        (ValidationStatusKind::Infos, true) => view! {
+            <span class="crud-validation-status crud-validation-info">
+                <Icon icon=icondata::BsInfoCircleFill/>
+            </span>
+        }
+        .into_any(),
+        (ValidationStatusKind::Infos, false) => ().into_any(),
+    }
+}
+
 /// Validation status field component that displays a checkmark or exclamation mark
-/// based on whether validation errors exist.
+/// based on whether validation errors exist, or an info sign if infos exist.
 #[component]
 pub fn CrudValidationStatusField(
     id: String,
     field_options: FieldOptions,
     field_mode: FieldMode,
     #[prop(into)] value: Signal<bool>,
+    #[prop(optional)] kind: ValidationStatusKind,
 ) -> impl IntoView {
     match field_mode {
-        FieldMode::Display => (move || match value.get() {
-            true => view! { <Icon icon=icondata::BsExclamationTriangleFill/> },
-            false => view! { <Icon icon=icondata::BsCheck/> },
-        })
-        .into_any(),
+        FieldMode::Display => (move || render_status(kind, value.get())).into_any(),
         FieldMode::Readable | FieldMode::Editable => view! {
             {render_label(field_options.label.clone())}
             <div id=id.clone() class="crud-input-field">
-                {move || match value.get() {
-                    true => view! { <Icon icon=icondata::BsExclamationTriangleFill/> },
-                    false => view! { <Icon icon=icondata::BsCheck/> },
-                }}
+                {move || render_status(kind, value.get())}
             </div>
         }
         .into_any(),
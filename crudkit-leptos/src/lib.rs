@@ -22,7 +22,9 @@ pub mod crud_table;
 pub mod crud_table_body;
 pub mod crud_table_footer;
 pub mod crud_table_header;
+pub mod debounce;
 pub mod fields;
+pub mod violation_translator;
 
 /*
 * Reexport common modules.
@@ -56,6 +58,7 @@ pub mod prelude {
     // (both crudkit_core and crudkit_web export Model).
     pub use crudkit_web::Model;
 
+    pub use crudkit_core_macros::CkEnum;
     pub use crudkit_core_macros::CkId;
     pub use crudkit_web_macros::{CkActionPayload, CkField, CkResource};
 
@@ -65,6 +68,7 @@ pub mod prelude {
     };
     pub use super::crud_instance::CrudInstance;
     pub use super::crud_instance_config::{CreateElements, CrudInstanceConfig, CrudParentConfig};
+    pub use super::violation_translator::ViolationTranslator;
     pub use super::ReactiveField;
 }
 
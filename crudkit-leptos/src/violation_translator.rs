@@ -0,0 +1,48 @@
+use crudkit_core::validation::violation::Violation;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+/// Translates violations into the user's locale.
+///
+/// Provide one through `provide_context` above your crud instances. Without one, or when the
+/// translator knows no message for a violation, its plain message is shown.
+///
+/// # Usage
+///
+/// ```ignore
+/// provide_context(ViolationTranslator::new(move |violation| {
+///     let template = catalog.get(locale.get_untracked(), violation.code()?)?;
+///     Some(violation.format_message(template))
+/// }));
+/// ```
+#[derive(Clone)]
+pub struct ViolationTranslator {
+    translate: Arc<dyn Fn(&Violation) -> Option<String> + Send + Sync>,
+}
+
+impl ViolationTranslator {
+    pub fn new(translate: impl Fn(&Violation) -> Option<String> + Send + Sync + 'static) -> Self {
+        Self {
+            translate: Arc::new(translate),
+        }
+    }
+
+    /// Returns the translated message of `violation`, falling back to its plain message.
+    pub fn translate(&self, violation: &Violation) -> String {
+        (self.translate)(violation).unwrap_or_else(|| violation.message().to_owned())
+    }
+}
+
+impl Default for ViolationTranslator {
+    /// A translator showing the plain message of each violation.
+    fn default() -> Self {
+        Self::new(|_violation| None)
+    }
+}
+
+impl Debug for ViolationTranslator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ViolationTranslator")
+            .finish_non_exhaustive()
+    }
+}
@@ -7,10 +7,12 @@
 //! both `derive-model` (storage-agnostic) and storage-specific crates like
 //! `crudkit-sea-orm-macros`.
 
+use crudkit_core_macro_util::{ValueKind, classify_base_type, path_to_string, strip_option_type};
 use darling::*;
 use proc_macro2::{Ident, TokenStream};
-use quote::{format_ident, quote};
+use quote::{ToTokens, format_ident, quote};
 use syn::DeriveInput;
+use syn::ext::IdentExt;
 
 // ============== Field Configuration ==============
 
@@ -28,6 +30,8 @@ pub struct ModelFieldConfig {
     pub optional: bool,
     /// Fields with use_default will use `Default::default()` for excluded fields.
     pub use_default: bool,
+    /// Checks declared with `#[ck_validate(...)]`, one entry per attribute.
+    pub validations: Vec<FieldValidation>,
 }
 
 /// Metadata about a field for storage-specific implementations.
@@ -55,11 +59,222 @@ impl From<&ModelFieldConfig> for FieldInfo {
     }
 }
 
+// ============== Field Validation Parsing ==============
+
+/// Bounds of a `length(min = .., max = ..)` check, both inclusive.
+#[derive(Debug, Clone, Default, FromMeta)]
+pub struct LengthBounds {
+    pub min: Option<usize>,
+    pub max: Option<usize>,
+}
+
+/// A number given as integer or float literal, optionally negated.
+///
+/// Kept as written, so that it can be parsed into the numeric type of the checked field.
+#[derive(Debug, Clone)]
+pub struct Number(pub String);
+
+impl FromMeta for Number {
+    fn from_expr(expr: &syn::Expr) -> Result<Self> {
+        match expr {
+            syn::Expr::Unary(syn::ExprUnary {
+                op: syn::UnOp::Neg(_),
+                expr,
+                ..
+            }) => Self::from_expr(expr).map(|Number(number)| match number.strip_prefix('-') {
+                Some(positive) => Number(positive.to_owned()),
+                None => Number(format!("-{number}")),
+            }),
+            syn::Expr::Lit(lit) => Self::from_value(&lit.lit),
+            _ => Err(Error::unexpected_expr_type(expr)),
+        }
+    }
+
+    fn from_value(value: &syn::Lit) -> Result<Self> {
+        match value {
+            syn::Lit::Int(lit) => Ok(Number(lit.base10_digits().to_owned())),
+            syn::Lit::Float(lit) if lit.base10_digits().contains(['e', 'E']) => {
+                Err(Error::custom("use a number without exponent").with_span(lit))
+            }
+            syn::Lit::Float(lit) => Ok(Number(lit.base10_digits().to_owned())),
+            _ => Err(Error::unexpected_lit_type(value)),
+        }
+    }
+}
+
+/// Bounds of a `range(min = .., max = ..)` check, both inclusive.
+#[derive(Debug, Clone, Default, FromMeta)]
+pub struct RangeBounds {
+    pub min: Option<Number>,
+    pub max: Option<Number>,
+}
+
+/// Severity of the violations reported by the checks of one `#[ck_validate(...)]` attribute.
+#[derive(Debug, Clone, Copy, Default, FromMeta)]
+pub enum ValidationSeverity {
+    #[default]
+    #[darling(rename = "critical")]
+    Critical,
+    #[darling(rename = "major")]
+    Major,
+    #[darling(rename = "info")]
+    Info,
+}
+
+/// The checks of one `#[ck_validate(...)]` field attribute.
+#[derive(Debug, Clone, FromMeta)]
+pub struct FieldValidation {
+    pub length: Option<LengthBounds>,
+    pub regex: Option<String>,
+    pub range: Option<RangeBounds>,
+    #[darling(default)]
+    pub severity: ValidationSeverity,
+}
+
+/// Moves the `#[ck_validate(...)]` attributes of each field into its parsed `validations`.
+fn extract_validations(fields: &mut [ModelFieldConfig]) -> Result<()> {
+    let mut errors = Error::accumulator();
+    for field in fields.iter_mut() {
+        let (validate_attrs, attrs) = std::mem::take(&mut field.attrs)
+            .into_iter()
+            .partition(|attr: &syn::Attribute| attr.path().is_ident("ck_validate"));
+        field.attrs = attrs;
+        for attr in validate_attrs {
+            if field.exclude {
+                errors.push(
+                    Error::custom("`ck_validate` has no effect on excluded fields")
+                        .with_span(&attr),
+                );
+                continue;
+            }
+            let Some(validation) = errors.handle(FieldValidation::from_meta(&attr.meta)) else {
+                continue;
+            };
+            let invalid_regex = validation
+                .regex
+                .as_deref()
+                .and_then(|pattern| regex::Regex::new(pattern).err());
+            if let Some(err) = invalid_regex {
+                errors.push(Error::custom(format!("invalid regex: {err}")).with_span(&attr));
+                continue;
+            }
+            let invalid_bound = validation
+                .range
+                .as_ref()
+                .and_then(|bounds| invalid_range_bound(&field.ty, bounds));
+            if let Some(err) = invalid_bound {
+                errors.push(Error::custom(err).with_span(&attr));
+                continue;
+            }
+            field.validations.push(validation);
+        }
+    }
+    errors.finish()
+}
+
+/// Parses the bounds of a `range` check into the numeric type of the field, like
+/// `check_range` does at runtime, returning the error for the first bound that does not fit.
+///
+/// Fields of types not recognized here are only checked at runtime.
+fn invalid_range_bound(ty: &syn::Type, bounds: &RangeBounds) -> Option<String> {
+    let syn::Type::Path(type_path) = strip_option_type(ty) else {
+        return None;
+    };
+    let type_name = path_to_string(&type_path.path);
+    let fits = |number: &str| match classify_base_type(&type_name) {
+        ValueKind::I8 => number.parse::<i8>().is_ok(),
+        ValueKind::I16 => number.parse::<i16>().is_ok(),
+        ValueKind::I32 => number.parse::<i32>().is_ok(),
+        ValueKind::I64 => number.parse::<i64>().is_ok(),
+        ValueKind::U8 => number.parse::<u8>().is_ok(),
+        ValueKind::U16 => number.parse::<u16>().is_ok(),
+        ValueKind::U32 => number.parse::<u32>().is_ok(),
+        ValueKind::U64 => number.parse::<u64>().is_ok(),
+        ValueKind::F32 => number.parse::<f32>().is_ok(),
+        ValueKind::F64 => number.parse::<f64>().is_ok(),
+        ValueKind::Decimal => number.parse::<rust_decimal::Decimal>().is_ok(),
+        _ => true,
+    };
+    [&bounds.min, &bounds.max]
+        .into_iter()
+        .flatten()
+        .find(|Number(number)| !fits(number))
+        .map(|Number(number)| format!("range bound {number} is not a valid {type_name}"))
+}
+
+fn quote_option<T: ToTokens>(value: Option<T>) -> TokenStream {
+    match value {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    }
+}
+
+/// Generates the `validate_fields` method running all `#[ck_validate(...)]` checks, if any.
+fn generate_validate_fields(fields: &[ModelFieldConfig]) -> TokenStream {
+    let checks = fields.iter().flat_map(|field| {
+        let ident = field.ident.as_ref().expect("Expected a named field");
+        let name = ident.unraw().to_string();
+        field.validations.iter().flat_map(move |validation| {
+            let severity = match validation.severity {
+                ValidationSeverity::Critical => quote! { Critical },
+                ValidationSeverity::Major => quote! { Major },
+                ValidationSeverity::Info => quote! { Info },
+            };
+            let severity =
+                quote! { crudkit_rs::crudkit_validation::violation::Severity::#severity };
+            let length = validation.length.as_ref().map(|bounds| {
+                let min = quote_option(bounds.min);
+                let max = quote_option(bounds.max);
+                quote! {
+                    crudkit_rs::field_validation::check_length(
+                        &mut violations, #name, #severity, &self.#ident, #min, #max,
+                    );
+                }
+            });
+            let regex = validation.regex.as_ref().map(|pattern| {
+                quote! {
+                    {
+                        static REGEX: std::sync::LazyLock<crudkit_rs::field_validation::Regex> =
+                            std::sync::LazyLock::new(|| {
+                                crudkit_rs::field_validation::Regex::new(#pattern)
+                                    .expect("pattern was checked by the derive")
+                            });
+                        crudkit_rs::field_validation::check_regex(
+                            &mut violations, #name, #severity, &self.#ident, &REGEX,
+                        );
+                    }
+                }
+            });
+            let range = validation.range.as_ref().map(|bounds| {
+                let min = quote_option(bounds.min.as_ref().map(|Number(min)| min));
+                let max = quote_option(bounds.max.as_ref().map(|Number(max)| max));
+                quote! {
+                    crudkit_rs::field_validation::check_range(
+                        &mut violations, #name, #severity, &self.#ident, #min, #max,
+                    );
+                }
+            });
+            [length, regex, range].into_iter().flatten()
+        })
+    });
+    let checks: Vec<TokenStream> = checks.collect();
+    if checks.is_empty() {
+        return TokenStream::new();
+    }
+    quote! {
+        fn validate_fields(&self) -> crudkit_rs::crudkit_validation::violation::Violations {
+            let mut violations = crudkit_rs::crudkit_validation::violation::Violations::empty();
+            #(#checks)*
+            violations
+        }
+    }
+}
+
 // ============== Create Model Attribute Parsing ==============
 
 /// Internal field config for create model with darling parsing.
 #[derive(Debug, Clone, FromField)]
-#[darling(attributes(ck_create_model), forward_attrs(schema, serde))]
+#[darling(attributes(ck_create_model), forward_attrs(schema, serde, ck_validate))]
 struct CreateModelFieldConfigInternal {
     attrs: Vec<syn::Attribute>,
     ident: Option<Ident>,
@@ -80,6 +295,7 @@ impl From<CreateModelFieldConfigInternal> for ModelFieldConfig {
             exclude: config.exclude.unwrap_or(false),
             optional: config.optional.unwrap_or(false),
             use_default: config.use_default.unwrap_or(false),
+            validations: Vec::new(),
         }
     }
 }
@@ -104,7 +320,7 @@ impl CreateModelInputInternal {
 
 /// Internal field config for update model with darling parsing.
 #[derive(Debug, Clone, FromField)]
-#[darling(attributes(ck_update_model), forward_attrs(schema, serde))]
+#[darling(attributes(ck_update_model), forward_attrs(schema, serde, ck_validate))]
 struct UpdateModelFieldConfigInternal {
     attrs: Vec<syn::Attribute>,
     ident: Option<Ident>,
@@ -125,6 +341,7 @@ impl From<UpdateModelFieldConfigInternal> for ModelFieldConfig {
             exclude: config.exclude.unwrap_or(false),
             optional: config.optional.unwrap_or(false),
             use_default: config.use_default.unwrap_or(false),
+            validations: Vec::new(),
         }
     }
 }
@@ -206,8 +423,11 @@ pub fn generate_create_model(input: &DeriveInput) -> darling::Result<CreateModel
     let parsed: CreateModelInputInternal = FromDeriveInput::from_derive_input(input)?;
 
     // Convert to shared config.
-    let fields: Vec<ModelFieldConfig> = parsed.fields().iter().cloned().map(Into::into).collect();
+    let mut fields: Vec<ModelFieldConfig> =
+        parsed.fields().iter().cloned().map(Into::into).collect();
+    extract_validations(&mut fields)?;
     let field_infos: Vec<FieldInfo> = fields.iter().map(Into::into).collect();
+    let validate_fields = generate_validate_fields(&fields);
 
     let model_fields = generate_model_fields(fields.iter());
 
@@ -225,6 +445,8 @@ pub fn generate_create_model(input: &DeriveInput) -> darling::Result<CreateModel
     let trait_impls = quote! {
         impl crudkit_rs::data::Model for #create_model_name {
             type Field = #field_enum_name;
+
+            #validate_fields
         }
 
         impl crudkit_rs::data::CreateModel for #create_model_name {}
@@ -246,8 +468,11 @@ pub fn generate_update_model(input: &DeriveInput) -> darling::Result<UpdateModel
     let parsed: UpdateModelInputInternal = FromDeriveInput::from_derive_input(input)?;
 
     // Convert to shared config.
-    let fields: Vec<ModelFieldConfig> = parsed.fields().iter().cloned().map(Into::into).collect();
+    let mut fields: Vec<ModelFieldConfig> =
+        parsed.fields().iter().cloned().map(Into::into).collect();
+    extract_validations(&mut fields)?;
     let field_infos: Vec<FieldInfo> = fields.iter().map(Into::into).collect();
+    let validate_fields = generate_validate_fields(&fields);
 
     let model_fields = generate_model_fields(fields.iter());
 
@@ -265,6 +490,8 @@ pub fn generate_update_model(input: &DeriveInput) -> darling::Result<UpdateModel
     let trait_impls = quote! {
         impl crudkit_rs::data::Model for #update_model_name {
             type Field = #field_enum_name;
+
+            #validate_fields
         }
     };
 
@@ -275,3 +502,128 @@ pub fn generate_update_model(input: &DeriveInput) -> darling::Result<UpdateModel
         fields: field_infos,
     })
 }
+
+/// Generates the `validate_fields` method of the model the create and update models are derived
+/// from, running the `#[ck_validate(...)]` checks of all of its fields.
+///
+/// Used by the `CkField` derive, so that persisted entities are checked like created ones.
+pub fn generate_entity_validate_fields(
+    fields: impl Iterator<Item = (Ident, syn::Type, Vec<syn::Attribute>)>,
+) -> darling::Result<TokenStream> {
+    let mut fields: Vec<ModelFieldConfig> = fields
+        .map(|(ident, ty, attrs)| ModelFieldConfig {
+            attrs,
+            ident: Some(ident),
+            ty,
+            vis: syn::Visibility::Inherited,
+            exclude: false,
+            optional: false,
+            use_default: false,
+            validations: Vec::new(),
+        })
+        .collect();
+    extract_validations(&mut fields)?;
+    Ok(generate_validate_fields(&fields))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_create_model(input: DeriveInput) -> String {
+        let output = generate_create_model(&input).expect("valid input");
+        output.trait_impls.to_string()
+    }
+
+    #[test]
+    fn expands_checks_into_validate_fields() {
+        let expanded = expand_create_model(syn::parse_quote! {
+            struct Article {
+                #[ck_validate(length(min = 3, max = 120))]
+                #[ck_validate(regex = "^[a-z-]+$", severity = "major")]
+                slug: String,
+                #[ck_validate(range(min = -1, max = 0.5))]
+                price: rust_decimal::Decimal,
+                #[ck_create_model(exclude)]
+                id: i64,
+            }
+        });
+
+        assert!(expanded.contains("fn validate_fields"));
+        assert!(expanded.contains(
+            "check_length (& mut violations , \"slug\" , crudkit_rs :: crudkit_validation :: \
+             violation :: Severity :: Critical , & self . slug , Some (3usize) , Some (120usize) ,)"
+        ));
+        assert!(expanded.contains("Severity :: Major , & self . slug , & REGEX ,"));
+        assert!(expanded.contains("& self . price , Some (\"-1\") , Some (\"0.5\") ,"));
+    }
+
+    #[test]
+    fn expands_no_validate_fields_without_checks() {
+        let expanded = expand_create_model(syn::parse_quote! {
+            struct Article {
+                title: String,
+            }
+        });
+
+        assert!(!expanded.contains("validate_fields"));
+    }
+
+    #[test]
+    fn rejects_checks_on_excluded_fields_and_invalid_regexes() {
+        let excluded: DeriveInput = syn::parse_quote! {
+            struct Article {
+                #[ck_create_model(exclude)]
+                #[ck_validate(length(max = 3))]
+                id: i64,
+            }
+        };
+        let invalid_regex: DeriveInput = syn::parse_quote! {
+            struct Article {
+                #[ck_validate(regex = "(")]
+                title: String,
+            }
+        };
+
+        assert!(generate_create_model(&excluded).is_err());
+        assert!(generate_create_model(&invalid_regex).is_err());
+    }
+
+    #[test]
+    fn rejects_range_bounds_not_fitting_the_field_type() {
+        let fractional: DeriveInput = syn::parse_quote! {
+            struct Article {
+                #[ck_validate(range(min = 0.5))]
+                quantity: i32,
+            }
+        };
+        let negative: DeriveInput = syn::parse_quote! {
+            struct Article {
+                #[ck_validate(range(min = -1, max = 10))]
+                stock: Option<u32>,
+            }
+        };
+        let overflowing: DeriveInput = syn::parse_quote! {
+            struct Article {
+                #[ck_validate(range(max = 256))]
+                rating: u8,
+            }
+        };
+
+        assert!(generate_create_model(&fractional).is_err());
+        assert!(generate_create_model(&negative).is_err());
+        assert!(generate_create_model(&overflowing).is_err());
+    }
+
+    #[test]
+    fn expands_entity_checks() {
+        let attrs: Vec<syn::Attribute> = vec![syn::parse_quote!(#[ck_validate(range(min = 0))])];
+        let fields = [(format_ident!("quantity"), syn::parse_quote!(i32), attrs)];
+
+        let expanded = generate_entity_validate_fields(fields.into_iter())
+            .expect("valid checks")
+            .to_string();
+
+        assert!(expanded.contains("& self . quantity , Some (\"0\") , None ,"));
+    }
+}
@@ -19,6 +19,8 @@ use syn::DeriveInput;
 /// - `#[ck_create_model(exclude)]` - Exclude field from CreateModel
 /// - `#[ck_create_model(optional)]` - Wrap field in Option
 /// - `#[ck_create_model(use_default)]` - Use Default::default() for excluded fields (storage-specific)
+/// - `#[ck_validate(length(min = 3, max = 120), regex = "...", range(min = 0))]` - Check the field on create,
+///   reporting field-scoped violations. Add `severity = "major"` or `"info"` to not block (default: critical)
 pub fn expand_derive_create_model(input: DeriveInput) -> syn::Result<TokenStream> {
     let output = crudkit_rs_macros_core::generate_create_model(&input)?;
 
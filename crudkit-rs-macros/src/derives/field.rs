@@ -1,6 +1,10 @@
 //! Implementation of the `CkField` derive macro.
 
-use crudkit_core_macro_util::{classify_base_type, path_to_string, strip_option_path, ValueKindExt};
+use crudkit_core_macro_util::{
+    array_element_type, classify_base_type, is_option_path, is_ordered_float, path_to_string,
+    strip_option_path, strip_option_type, to_screaming_snake_case, ValueKind, ValueKindExt,
+};
+use crudkit_rs_macros_core::generate_entity_validate_fields;
 use darling::*;
 use proc_macro2::{Ident, Span, TokenStream};
 use proc_macro_error::abort;
@@ -9,13 +13,43 @@ use quote::{format_ident, quote};
 use syn::{spanned::Spanned, DeriveInput};
 
 #[derive(Debug, FromField)]
-#[darling(attributes(ck_field))]
+#[darling(attributes(ck_field), forward_attrs(ck_validate))]
 struct MyFieldReceiver {
+    attrs: Vec<syn::Attribute>,
+
     ident: Option<syn::Ident>,
 
     ty: syn::Type,
 
     convert_ccv: Option<String>,
+
+    /// Whether the field holds a `CkEnum`, or a `Vec` of them, set with `#[ck_field(enum)]`.
+    #[darling(default, rename = "enum")]
+    is_enum: bool,
+}
+
+impl MyFieldReceiver {
+    /// The element type of a `Vec` field stored as an array, e.g. `String` for `Vec<String>`.
+    fn array_element_type(&self) -> Option<&syn::Type> {
+        array_element_type(&self.ty, self.is_enum)
+    }
+
+    fn value_kind(&self) -> ValueKind {
+        match (self.array_element_type(), self.is_enum) {
+            (Some(_), _) => ValueKind::Array,
+            (None, true) => ValueKind::Enum,
+            (None, false) => classify_field_type(&self.ty),
+        }
+    }
+
+    /// The kind of the field's values, or of the elements of an array field.
+    fn element_kind(&self) -> ValueKind {
+        match (self.is_enum, self.array_element_type()) {
+            (true, _) => ValueKind::Enum,
+            (false, Some(element_ty)) => classify_field_type(element_ty),
+            (false, None) => classify_field_type(&self.ty),
+        }
+    }
 }
 
 #[derive(Debug, FromDeriveInput)]
@@ -76,15 +110,70 @@ pub fn expand_derive_field(input: DeriveInput) -> syn::Result<TokenStream> {
             .iter()
             .zip(column_variants.iter())
             .map(|(field, variant)| {
-                let fun_name = match &field.convert_ccv {
-                    Some(fun_name) => Ident::new(fun_name.as_str(), field.ident.span()),
-                    None => convert_field_type_to_function_name(&field.ty),
+                let conversion = match (&field.convert_ccv, field.array_element_type()) {
+                    (Some(fun_name), _) => {
+                        let fun_name = Ident::new(fun_name.as_str(), field.ident.span());
+                        quote! { value.#fun_name() }
+                    }
+                    (None, Some(element_ty)) => {
+                        let convert_element = match field.is_enum {
+                            true => quote! {
+                                crudkit_rs::crudkit_condition::ConditionClauseValue::to_enum::<#element_ty>
+                            },
+                            false => {
+                                let fun_name = convert_field_type_to_function_name(element_ty);
+                                quote! { crudkit_rs::crudkit_condition::ConditionClauseValue::#fun_name }
+                            }
+                        };
+                        quote! { value.to_array(#convert_element) }
+                    }
+                    (None, None) if field.is_enum => {
+                        let enum_ty = strip_option_type(&field.ty);
+                        quote! { value.to_enum::<#enum_ty>() }
+                    }
+                    (None, None) => {
+                        let fun_name = convert_field_type_to_function_name(&field.ty);
+                        quote! { value.#fun_name() }
+                    }
                 };
                 quote! {
-                    #field_enum_name::#variant => value.#fun_name()
+                    #field_enum_name::#variant => #conversion
                 }
             });
 
+    let value_kind_arms = fields
+        .iter()
+        .zip(column_variants.iter())
+        .map(|(field, variant)| {
+            let kind = field.value_kind().value_variant_ident();
+            quote! { #field_enum_name::#variant => crudkit_rs::crudkit_core::ValueKind::#kind }
+        });
+
+    // Field values for in-memory condition evaluation.
+    let field_value_arms = fields.iter().map(|field| {
+        let ident = field.ident.as_ref().expect("Expected named field!");
+        let value_expr = generate_value_expr(ident, field);
+        quote! { stringify!(#ident) => Some(#value_expr) }
+    });
+
+    // Checks of the `#[ck_validate(...)]` attributes shared with the create and update models.
+    let validate_fields = generate_entity_validate_fields(fields.iter().map(|field| {
+        let ident = field.ident.clone().expect("Expected named field!");
+        (ident, field.ty.clone(), field.attrs.clone())
+    }))?;
+
+    // Typed entry points for the condition builder, checking values at compile time,
+    // e.g. `ArticleField::TITLE.eq("x")`.
+    let typed_field_consts = fields.iter().map(|field| {
+        let ident = field.ident.as_ref().expect("Expected named field!");
+        let const_ident = format_ident!("{}", to_screaming_snake_case(&ident.to_string()));
+        let value_ty = strip_option_type(&field.ty);
+        quote! {
+            pub const #const_ident: crudkit_rs::crudkit_condition::TypedField<#value_ty> =
+                crudkit_rs::crudkit_condition::TypedField::new(stringify!(#ident));
+        }
+    });
+
     Ok(quote! {
         #[doc = "Generated by the `CkField` derive macro (`crudkit-rs-macros`)."]
         #[derive(PartialEq, Eq, Hash, Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -92,6 +181,10 @@ pub fn expand_derive_field(input: DeriveInput) -> syn::Result<TokenStream> {
             #(#column_variants),*
         }
 
+        impl #field_enum_name {
+            #(#typed_field_consts)*
+        }
+
         // =========================================================================
         // Storage-agnostic traits (crudkit-rs)
         // =========================================================================
@@ -119,14 +212,112 @@ pub fn expand_derive_field(input: DeriveInput) -> syn::Result<TokenStream> {
                     #(#condition_value_converter_arms),*
                 }
             }
+
+            fn value_kind(&self) -> crudkit_rs::crudkit_core::ValueKind {
+                match self {
+                    #(#value_kind_arms),*
+                }
+            }
         }
 
         impl crudkit_rs::data::Model for #name {
             type Field = #field_enum_name;
+
+            #validate_fields
+        }
+
+        impl crudkit_rs::crudkit_condition::ConditionTarget for #name {
+            fn field_value(&self, field_name: &str) -> std::option::Option<crudkit_rs::crudkit_core::Value> {
+                match field_name {
+                    #(#field_value_arms,)*
+                    _ => None,
+                }
+            }
+
+            fn convert_clause_value(
+                &self,
+                field_name: &str,
+                value: crudkit_rs::crudkit_condition::ConditionClauseValue,
+            ) -> std::result::Result<crudkit_rs::crudkit_core::Value, String> {
+                match <#field_enum_name as crudkit_rs::data::FieldLookup>::from_name(field_name) {
+                    Some(field) => crudkit_rs::data::ConditionValueConverter::convert_condition_value(&field, value),
+                    None => Err(format!("Unknown field '{field_name}'.")),
+                }
+            }
         }
     })
 }
 
+/// Classifies the value type of a field, ignoring an `Option` wrapper.
+fn classify_field_type(ty: &syn::Type) -> ValueKind {
+    match strip_option_type(ty) {
+        syn::Type::Path(type_path) => classify_base_type(&path_to_string(&type_path.path)),
+        _ => ValueKind::Other,
+    }
+}
+
+/// Generates the expression reading a field of `self` as a `Value`.
+///
+/// Optional fields produce `Value::Null` when absent. Array fields produce a `Value::Array` of
+/// their elements. Types without a `Value` representation produce `Value::Void(())`.
+fn generate_value_expr(field_ident: &Ident, field: &MyFieldReceiver) -> TokenStream {
+    let is_optional = match &field.ty {
+        syn::Type::Path(type_path) => is_option_path(&type_path.path),
+        _ => false,
+    };
+
+    let wrap = |v: TokenStream| -> TokenStream {
+        match field.array_element_type() {
+            Some(element_ty) => {
+                let element = wrap_value(quote! { element }, element_ty, field.element_kind());
+                quote! { crudkit_rs::crudkit_core::Value::Array(#v.iter().map(|element| #element).collect()) }
+            }
+            None => wrap_value(v, strip_option_type(&field.ty), field.value_kind()),
+        }
+    };
+
+    match is_optional {
+        true => {
+            let some = wrap(quote! { v });
+            quote! {
+                match &self.#field_ident {
+                    Some(v) => #some,
+                    None => crudkit_rs::crudkit_core::Value::Null,
+                }
+            }
+        }
+        false => wrap(quote! { (&self.#field_ident) }),
+    }
+}
+
+/// Generates the expression converting `v`, a reference to a value of type `ty`, to a `Value`.
+fn wrap_value(v: TokenStream, ty: &syn::Type, kind: ValueKind) -> TokenStream {
+    let path_str = match ty {
+        syn::Type::Path(type_path) => path_to_string(&type_path.path),
+        _ => String::new(),
+    };
+
+    let variant = kind.value_variant_ident();
+    match kind {
+        ValueKind::Null | ValueKind::Void | ValueKind::Array | ValueKind::Other => {
+            quote! { crudkit_rs::crudkit_core::Value::Void(()) }
+        }
+        ValueKind::Enum => {
+            quote! { crudkit_rs::crudkit_core::Value::Enum(crudkit_rs::crudkit_core::CkEnum::variant_name(#v).to_owned()) }
+        }
+        ValueKind::String | ValueKind::Json => {
+            quote! { crudkit_rs::crudkit_core::Value::#variant(#v.clone()) }
+        }
+        ValueKind::Duration => {
+            quote! { crudkit_rs::crudkit_core::Value::Duration(crudkit_rs::crudkit_core::TimeDuration(#v.0)) }
+        }
+        _ if is_ordered_float(&path_str) => {
+            quote! { crudkit_rs::crudkit_core::Value::#variant((*#v).into()) }
+        }
+        _ => quote! { crudkit_rs::crudkit_core::Value::#variant(*#v) },
+    }
+}
+
 /// Converts a field type to the corresponding `ConditionClauseValue` method name.
 ///
 /// Uses the shared `ValueKind` classification from `crudkit_core_macros`.
@@ -169,9 +360,10 @@ fn convert_field_type_to_function_name(ty: &syn::Type) -> Ident {
             span,
             "crudkit-rs-macros: Unsupported type '{}' for condition value conversion.",
             inner_path_str;
-            help = "Supported types: bool, u8-u128, i8-i128, f32, f64, String, \
-                    serde_json::Value, uuid::Uuid, time::PrimitiveDateTime, \
-                    time::OffsetDateTime, TimeDuration, Vec<u8>";
+            help = "Supported types: bool, u8-u128, i8-i128, f32, f64, rust_decimal::Decimal, \
+                    String, serde_json::Value, uuid::Uuid, time::PrimitiveDateTime, \
+                    time::OffsetDateTime, time::Date, time::Time, TimeDuration, Vec<u8>, \
+                    Vec<T> of most of these for array columns";
         );
     });
 
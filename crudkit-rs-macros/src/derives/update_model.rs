@@ -18,6 +18,8 @@ use syn::DeriveInput;
 /// - `#[ck_update_model(exclude)]` - Exclude field from UpdateModel
 /// - `#[ck_update_model(optional)]` - Wrap field in Option
 /// - `#[ck_update_model(use_default)]` - Use Default::default() for excluded fields (storage-specific)
+/// - `#[ck_validate(length(min = 3, max = 120), regex = "...", range(min = 0))]` - Check the field on update,
+///   reporting field-scoped violations. Add `severity = "major"` or `"info"` to not block (default: critical)
 pub fn expand_derive_update_model(input: DeriveInput) -> syn::Result<TokenStream> {
     let output = crudkit_rs_macros_core::generate_update_model(&input)?;
 
@@ -30,7 +30,10 @@ mod derives;
 ///
 /// - `#[ck_id(id)]` - Mark field as part of primary key
 /// - `#[ck_field(convert_ccv = "fn_name")]` - Custom condition value converter function
-#[proc_macro_derive(CkField, attributes(ck_field, ck_id))]
+/// - `#[ck_field(enum)]` - Field holds a `CkEnum`, stored and compared by its variant names
+/// - `#[ck_validate(...)]` - Checks declared for the create and update models, also run on the
+///   model itself, see `CkCreateModel`
+#[proc_macro_derive(CkField, attributes(ck_field, ck_id, ck_validate))]
 #[proc_macro_error]
 pub fn derive_field(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -66,7 +69,9 @@ pub fn derive_resource_context(input: TokenStream) -> TokenStream {
 /// - `#[ck_create_model(exclude)]` - Exclude field from CreateModel
 /// - `#[ck_create_model(optional)]` - Wrap field in Option
 /// - `#[ck_create_model(use_default)]` - Use Default::default() for excluded fields (storage-specific)
-#[proc_macro_derive(CkCreateModel, attributes(ck_create_model))]
+/// - `#[ck_validate(length(min = 3, max = 120), regex = "...", range(min = 0))]` - Check the field on create,
+///   reporting field-scoped violations. Add `severity = "major"` or `"info"` to not block (default: critical)
+#[proc_macro_derive(CkCreateModel, attributes(ck_create_model, ck_validate))]
 pub fn derive_create_model(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     derives::expand_derive_create_model(input)
@@ -88,7 +93,9 @@ pub fn derive_create_model(input: TokenStream) -> TokenStream {
 /// - `#[ck_update_model(exclude)]` - Exclude field from UpdateModel
 /// - `#[ck_update_model(optional)]` - Wrap field in Option
 /// - `#[ck_update_model(use_default)]` - Use Default::default() for excluded fields (storage-specific)
-#[proc_macro_derive(CkUpdateModel, attributes(ck_update_model))]
+/// - `#[ck_validate(length(min = 3, max = 120), regex = "...", range(min = 0))]` - Check the field on update,
+///   reporting field-scoped violations. Add `severity = "major"` or `"info"` to not block (default: critical)
+#[proc_macro_derive(CkUpdateModel, attributes(ck_update_model, ck_validate))]
 pub fn derive_update_model(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     derives::expand_derive_update_model(input)
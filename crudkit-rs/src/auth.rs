@@ -4,6 +4,14 @@
 //! It supports any authentication provider (Keycloak, JWT, session, API key, etc.) as well as
 //! public resources that require no authentication.
 
+use time::UtcOffset;
+
+/// Name of the request header carrying the client's UTC offset, e.g. `+02:00` or `Z`.
+///
+/// Relative times in conditions (like `start_of_day`) are resolved in this time zone.
+/// Requests without the header are treated as UTC.
+pub const UTC_OFFSET_HEADER: &str = "x-utc-offset";
+
 /// Marker trait for types usable as authentication context.
 ///
 /// Any type satisfying `Clone + Send + Sync + 'static` qualifies.
@@ -82,26 +90,65 @@ pub struct RequestContext<A: Auth = NoAuth> {
     /// Lifetime hooks should check this field when implementing custom authorization
     /// logic (e.g., role or entity-ownership checks).
     pub auth: Option<A>,
+
+    /// The time zone of the client, used to resolve relative times in conditions.
+    ///
+    /// Defaults to UTC. See [`UTC_OFFSET_HEADER`].
+    pub utc_offset: UtcOffset,
 }
 
 impl<A: Auth> RequestContext<A> {
     /// Create a new request context with authentication data.
     pub fn authenticated(auth: A) -> Self {
-        Self { auth: Some(auth) }
+        Self {
+            auth: Some(auth),
+            utc_offset: UtcOffset::UTC,
+        }
     }
 
     /// Create a new request context without authentication data.
     ///
     /// Use this for public operations where authentication is not required.
     pub fn unauthenticated() -> Self {
-        Self { auth: None }
+        Self {
+            auth: None,
+            utc_offset: UtcOffset::UTC,
+        }
+    }
+
+    /// Set the time zone of the client.
+    pub fn with_utc_offset(mut self, utc_offset: UtcOffset) -> Self {
+        self.utc_offset = utc_offset;
+        self
     }
 }
 
 impl<A: Auth> Default for RequestContext<A> {
     fn default() -> Self {
-        Self { auth: None }
+        Self::unauthenticated()
+    }
+}
+
+/// Parse the value of the [`UTC_OFFSET_HEADER`], either `Z` or `+HH:MM` / `-HH:MM`.
+pub fn parse_utc_offset(value: &str) -> Result<UtcOffset, String> {
+    let invalid = || format!("Invalid UTC offset '{value}'. Expected 'Z' or e.g. '+02:00'.");
+    let value = value.trim();
+    if value.eq_ignore_ascii_case("z") {
+        return Ok(UtcOffset::UTC);
+    }
+    let (sign, rest) = match value.split_at_checked(1) {
+        Some(("+", rest)) => (1, rest),
+        Some(("-", rest)) => (-1, rest),
+        _ => return Err(invalid()),
+    };
+    let (hours, minutes) = rest.split_once(':').ok_or_else(invalid)?;
+    let is_two_digits = |part: &str| part.len() == 2 && part.bytes().all(|b| b.is_ascii_digit());
+    if !is_two_digits(hours) || !is_two_digits(minutes) {
+        return Err(invalid());
     }
+    let hours: i8 = hours.parse().map_err(|_| invalid())?;
+    let minutes: i8 = minutes.parse().map_err(|_| invalid())?;
+    UtcOffset::from_hms(sign * hours, sign * minutes, 0).map_err(|_| invalid())
 }
 
 /// Marker trait for auth types that require the Extension to be present.
@@ -10,6 +10,7 @@ use crudkit_core::validation::PartialSerializableAggregateViolations;
 use serde_json::json;
 use utoipa::ToSchema;
 
+use crate::condition_check::ConditionClauseError;
 use crate::error::CrudError;
 
 /// Error type for Axum HTTP responses.
@@ -36,6 +37,12 @@ pub enum AxumCrudError {
     /// Invalid query parameters (HTTP 400 Bad Request).
     BadRequest { reason: String },
 
+    /// The request's condition does not fit the resource (HTTP 400 Bad Request).
+    InvalidCondition {
+        reason: String,
+        errors: Vec<ConditionClauseError>,
+    },
+
     /// Authentication required (HTTP 401 Unauthorized).
     Unauthorized { reason: String },
 
@@ -50,6 +57,12 @@ pub enum AxumCrudError {
 
     /// Could not delete validations (HTTP 500 Internal Server Error).
     DeleteValidations { reason: String },
+
+    /// Could not read validations (HTTP 500 Internal Server Error).
+    ReadValidations { reason: String },
+
+    /// Could not revalidate (HTTP 500 Internal Server Error).
+    Revalidation { reason: String },
 }
 
 impl From<CrudError> for AxumCrudError {
@@ -68,6 +81,11 @@ impl From<CrudError> for AxumCrudError {
             CrudError::IntoCondition { .. } => Self::BadRequest {
                 reason: "Invalid query parameters".into(),
             },
+            CrudError::InvalidCondition { errors } => Self::InvalidCondition {
+                reason: "Invalid condition.".into(),
+                errors,
+            },
+            CrudError::InvalidOrderBy { reason } => Self::BadRequest { reason },
 
             // Server errors: use minimal generic messages.
             CrudError::Repository { .. } => Self::Repository {
@@ -82,6 +100,12 @@ impl From<CrudError> for AxumCrudError {
             CrudError::DeleteValidations { .. } => Self::DeleteValidations {
                 reason: "Could not delete validations.".into(),
             },
+            CrudError::ReadValidations { .. } => Self::ReadValidations {
+                reason: "Could not read validations.".into(),
+            },
+            CrudError::Revalidation { .. } => Self::Revalidation {
+                reason: "Could not revalidate.".into(),
+            },
         }
     }
 }
@@ -90,8 +114,10 @@ impl IntoResponse for AxumCrudError {
     fn into_response(self) -> Response {
         match self {
             Self::CriticalValidationErrors { reason, violations } => {
+                // `errors` lists the messages per concerned field, letting clients highlight them.
                 let body = Json(json!({
                     "error": reason,
+                    "errors": violations.messages_by_field(),
                     "violations": violations,
                 }));
                 (StatusCode::UNPROCESSABLE_ENTITY, body).into_response()
@@ -112,6 +138,13 @@ impl IntoResponse for AxumCrudError {
             Self::BadRequest { reason } => {
                 (StatusCode::BAD_REQUEST, Json(json!({"error": reason}))).into_response()
             }
+            Self::InvalidCondition { reason, errors } => {
+                let body = Json(json!({
+                    "error": reason,
+                    "errors": errors,
+                }));
+                (StatusCode::BAD_REQUEST, body).into_response()
+            }
             Self::Unauthorized { reason } => {
                 (StatusCode::UNAUTHORIZED, Json(json!({"error": reason}))).into_response()
             }
@@ -137,6 +170,16 @@ impl IntoResponse for AxumCrudError {
                 Json(json!({"error": reason})),
             )
                 .into_response(),
+            Self::ReadValidations { reason } => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({"error": reason})),
+            )
+                .into_response(),
+            Self::Revalidation { reason } => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({"error": reason})),
+            )
+                .into_response(),
         }
     }
 }
@@ -191,10 +234,11 @@ macro_rules! impl_add_crud_routes {
             pub mod [< axum_ $name _crud_routes >] {
                 use std::sync::Arc;
                 use crudkit_rs::prelude::*;
-                use crudkit_rs::auth::{AuthRequirement, CrudAuthPolicy, RequestContext};
+                use crudkit_rs::auth::{AuthRequirement, CrudAuthPolicy, RequestContext, UTC_OFFSET_HEADER};
                 use crudkit_core::{DeletedMany, Deleted, Saved};
+                use crudkit_core::validation::{PartialSerializableAggregateViolations, PersistedViolation};
                 use axum::{
-                    http::StatusCode,
+                    http::{HeaderMap, StatusCode},
                     response::{IntoResponse, Response},
                     routing::post,
                     Extension, Json, Router,
@@ -213,12 +257,14 @@ macro_rules! impl_add_crud_routes {
                 /// Check the authorization requirement and build a RequestContext.
                 ///
                 /// Returns `Ok(RequestContext)` if the requirement is satisfied,
-                /// or `Err(AxumCrudError)` with the Unauthorized variant.
+                /// or `Err(AxumCrudError)` with the Unauthorized variant. An invalid
+                /// UTC offset header results in the BadRequest variant.
                 fn check_auth_requirement(
                     auth_requirement: AuthRequirement,
                     auth: Option<Extension<Auth>>,
+                    headers: &HeaderMap,
                 ) -> Result<RequestContext<Auth>, AxumCrudError> {
-                    match auth_requirement {
+                    let request_context = match auth_requirement {
                         AuthRequirement::None => {
                             match auth {
                                 Some(Extension(a)) => Ok(RequestContext::authenticated(a)),
@@ -233,6 +279,17 @@ macro_rules! impl_add_crud_routes {
                                 }),
                             }
                         }
+                    }?;
+                    match headers.get(UTC_OFFSET_HEADER) {
+                        Some(value) => {
+                            let utc_offset = value
+                                .to_str()
+                                .map_err(|err| err.to_string())
+                                .and_then(crudkit_rs::auth::parse_utc_offset)
+                                .map_err(|reason| AxumCrudError::BadRequest { reason })?;
+                            Ok(request_context.with_utc_offset(utc_offset))
+                        }
+                        None => Ok(request_context),
                     }
                 }
 
@@ -268,6 +325,30 @@ macro_rules! impl_add_crud_routes {
                     tracing::debug!("Adding route: {}", path);
                     router = router.route(path.as_str(), post(update_one));
 
+                    let path = format!("{root}/{resource}/crud/validate-create");
+                    tracing::debug!("Adding route: {}", path);
+                    router = router.route(path.as_str(), post(validate_create));
+
+                    let path = format!("{root}/{resource}/crud/validate-update");
+                    tracing::debug!("Adding route: {}", path);
+                    router = router.route(path.as_str(), post(validate_update));
+
+                    let path = format!("{root}/{resource}/crud/read-violation-count");
+                    tracing::debug!("Adding route: {}", path);
+                    router = router.route(path.as_str(), post(read_violation_count));
+
+                    let path = format!("{root}/{resource}/crud/read-violations");
+                    tracing::debug!("Adding route: {}", path);
+                    router = router.route(path.as_str(), post(read_violations));
+
+                    let path = format!("{root}/{resource}/crud/revalidate");
+                    tracing::debug!("Adding route: {}", path);
+                    router = router.route(path.as_str(), post(revalidate));
+
+                    let path = format!("{root}/{resource}/crud/revalidation-progress");
+                    tracing::debug!("Adding route: {}", path);
+                    router = router.route(path.as_str(), post(revalidation_progress));
+
                     let path = format!("{root}/{resource}/crud/delete-by-id");
                     tracing::debug!("Adding route: {}", path);
                     router = router.route(path.as_str(), post(delete_by_id));
@@ -292,10 +373,11 @@ macro_rules! impl_add_crud_routes {
                 #[axum_macros::debug_handler]
                 async fn read_count(
                     auth: Option<Extension<Auth>>,
+                    headers: HeaderMap,
                     Extension(context): Extension<Arc<CrudContext<$resource_type>>>,
                     Json(body): Json<ReadCount>,
                 ) -> Response {
-                    let request_context = match check_auth_requirement(Policy::read_requirement(), auth) {
+                    let request_context = match check_auth_requirement(Policy::read_requirement(), auth, &headers) {
                         Ok(ctx) => ctx,
                         Err(err) => return err.into_response(),
                     };
@@ -320,10 +402,11 @@ macro_rules! impl_add_crud_routes {
                 #[axum_macros::debug_handler]
                 async fn read_one(
                     auth: Option<Extension<Auth>>,
+                    headers: HeaderMap,
                     Extension(context): Extension<Arc<CrudContext<$resource_type>>>,
                     Json(body): Json<ReadOne<$resource_type>>,
                 ) -> Response {
-                    let request_context = match check_auth_requirement(Policy::read_requirement(), auth) {
+                    let request_context = match check_auth_requirement(Policy::read_requirement(), auth, &headers) {
                         Ok(ctx) => ctx,
                         Err(err) => return err.into_response(),
                     };
@@ -348,10 +431,11 @@ macro_rules! impl_add_crud_routes {
                 #[axum_macros::debug_handler]
                 async fn read_many(
                     auth: Option<Extension<Auth>>,
+                    headers: HeaderMap,
                     Extension(context): Extension<Arc<CrudContext<$resource_type>>>,
                     Json(body): Json<ReadMany<$resource_type>>,
                 ) -> Response {
-                    let request_context = match check_auth_requirement(Policy::read_requirement(), auth) {
+                    let request_context = match check_auth_requirement(Policy::read_requirement(), auth, &headers) {
                         Ok(ctx) => ctx,
                         Err(err) => return err.into_response(),
                     };
@@ -376,10 +460,11 @@ macro_rules! impl_add_crud_routes {
                 #[axum_macros::debug_handler]
                 async fn create_one(
                     auth: Option<Extension<Auth>>,
+                    headers: HeaderMap,
                     Extension(context): Extension<Arc<CrudContext<$resource_type>>>,
                     Json(body): Json<CreateOne<CreateModel>>,
                 ) -> Response {
-                    let request_context = match check_auth_requirement(Policy::create_requirement(), auth) {
+                    let request_context = match check_auth_requirement(Policy::create_requirement(), auth, &headers) {
                         Ok(ctx) => ctx,
                         Err(err) => return err.into_response(),
                     };
@@ -404,10 +489,11 @@ macro_rules! impl_add_crud_routes {
                 #[axum_macros::debug_handler]
                 async fn update_one(
                     auth: Option<Extension<Auth>>,
+                    headers: HeaderMap,
                     Extension(context): Extension<Arc<CrudContext<$resource_type>>>,
                     Json(body): Json<UpdateOne<UpdateModel>>,
                 ) -> Response {
-                    let request_context = match check_auth_requirement(Policy::update_requirement(), auth) {
+                    let request_context = match check_auth_requirement(Policy::update_requirement(), auth, &headers) {
                         Ok(ctx) => ctx,
                         Err(err) => return err.into_response(),
                     };
@@ -423,6 +509,169 @@ macro_rules! impl_add_crud_routes {
                     }
                 }
 
+                /// Validate a would-be created entity without creating it.
+                #[utoipa::path(
+                    post,
+                    path = "/" $name "/crud/validate-create",
+                    request_body = ValidateCreate<CreateModel>,
+                )]
+                #[axum_macros::debug_handler]
+                async fn validate_create(
+                    auth: Option<Extension<Auth>>,
+                    headers: HeaderMap,
+                    Extension(context): Extension<Arc<CrudContext<$resource_type>>>,
+                    Json(body): Json<ValidateCreate<CreateModel>>,
+                ) -> Response {
+                    let request_context = match check_auth_requirement(Policy::create_requirement(), auth, &headers) {
+                        Ok(ctx) => ctx,
+                        Err(err) => return err.into_response(),
+                    };
+                    let result: Result<PartialSerializableAggregateViolations, AxumCrudError> = crudkit_rs::create::validate_create::<$resource_type>(request_context, context.clone(), body)
+                        .await
+                        .map_err(Into::into);
+                    match result {
+                        Ok(data) => (StatusCode::OK, Json(data)).into_response(),
+                        Err(err) => {
+                            tracing::error!(?err, "Could not perform CRUD operation: validate create.");
+                            err.into_response()
+                        },
+                    }
+                }
+
+                /// Validate an update of one entity without updating it.
+                #[utoipa::path(
+                    post,
+                    path = "/" $name "/crud/validate-update",
+                    request_body = ValidateUpdate<UpdateModel>,
+                )]
+                #[axum_macros::debug_handler]
+                async fn validate_update(
+                    auth: Option<Extension<Auth>>,
+                    headers: HeaderMap,
+                    Extension(context): Extension<Arc<CrudContext<$resource_type>>>,
+                    Json(body): Json<ValidateUpdate<UpdateModel>>,
+                ) -> Response {
+                    let request_context = match check_auth_requirement(Policy::update_requirement(), auth, &headers) {
+                        Ok(ctx) => ctx,
+                        Err(err) => return err.into_response(),
+                    };
+                    let result: Result<PartialSerializableAggregateViolations, AxumCrudError> = crudkit_rs::update::validate_update::<$resource_type>(request_context, context.clone(), body)
+                        .await
+                        .map_err(Into::into);
+                    match result {
+                        Ok(data) => (StatusCode::OK, Json(data)).into_response(),
+                        Err(err) => {
+                            tracing::error!(?err, "Could not perform CRUD operation: validate update.");
+                            err.into_response()
+                        },
+                    }
+                }
+
+                /// Retrieve the amount of persisted violations of this resource.
+                #[utoipa::path(
+                    post,
+                    path = "/" $name "/crud/read-violation-count",
+                    request_body = ReadViolationCount,
+                )]
+                #[axum_macros::debug_handler]
+                async fn read_violation_count(
+                    auth: Option<Extension<Auth>>,
+                    headers: HeaderMap,
+                    Extension(context): Extension<Arc<CrudContext<$resource_type>>>,
+                    Json(body): Json<ReadViolationCount>,
+                ) -> Response {
+                    let request_context = match check_auth_requirement(Policy::read_requirement(), auth, &headers) {
+                        Ok(ctx) => ctx,
+                        Err(err) => return err.into_response(),
+                    };
+                    let result: Result<u64, AxumCrudError> = crudkit_rs::violations::read_violation_count::<$resource_type>(request_context, context.clone(), body)
+                        .await
+                        .map_err(Into::into);
+                    match result {
+                        Ok(data) => (StatusCode::OK, Json(data)).into_response(),
+                        Err(err) => {
+                            tracing::error!(?err, "Could not perform CRUD operation: read violation count.");
+                            err.into_response()
+                        },
+                    }
+                }
+
+                /// Retrieve persisted violations of this resource.
+                #[utoipa::path(
+                    post,
+                    path = "/" $name "/crud/read-violations",
+                    request_body = ReadViolations,
+                )]
+                #[axum_macros::debug_handler]
+                async fn read_violations(
+                    auth: Option<Extension<Auth>>,
+                    headers: HeaderMap,
+                    Extension(context): Extension<Arc<CrudContext<$resource_type>>>,
+                    Json(body): Json<ReadViolations>,
+                ) -> Response {
+                    let request_context = match check_auth_requirement(Policy::read_requirement(), auth, &headers) {
+                        Ok(ctx) => ctx,
+                        Err(err) => return err.into_response(),
+                    };
+                    let result: Result<Vec<PersistedViolation>, AxumCrudError> = crudkit_rs::violations::read_violations::<$resource_type>(request_context, context.clone(), body)
+                        .await
+                        .map_err(Into::into);
+                    match result {
+                        Ok(data) => (StatusCode::OK, Json(data)).into_response(),
+                        Err(err) => {
+                            tracing::error!(?err, "Could not perform CRUD operation: read violations.");
+                            err.into_response()
+                        },
+                    }
+                }
+
+                /// Start revalidating all entities of this resource in the background.
+                /// Forbidden unless the resource's `before_revalidate` hook allows it.
+                /// Responds with `false` if a revalidation is already running.
+                #[utoipa::path(
+                    post,
+                    path = "/" $name "/crud/revalidate",
+                )]
+                #[axum_macros::debug_handler]
+                async fn revalidate(
+                    auth: Option<Extension<Auth>>,
+                    headers: HeaderMap,
+                    Extension(context): Extension<Arc<CrudContext<$resource_type>>>,
+                ) -> Response {
+                    let request_context = match check_auth_requirement(Policy::update_requirement(), auth, &headers) {
+                        Ok(ctx) => ctx,
+                        Err(err) => return err.into_response(),
+                    };
+                    let result: Result<bool, AxumCrudError> = crudkit_rs::revalidate::start_revalidation::<$resource_type>(request_context, &context)
+                        .await
+                        .map_err(Into::into);
+                    match result {
+                        Ok(started) => (StatusCode::OK, Json(started)).into_response(),
+                        Err(err) => {
+                            tracing::error!(?err, "Could not perform CRUD operation: revalidate.");
+                            err.into_response()
+                        },
+                    }
+                }
+
+                /// Retrieve the progress of the running or latest revalidation of this resource.
+                #[utoipa::path(
+                    post,
+                    path = "/" $name "/crud/revalidation-progress",
+                )]
+                #[axum_macros::debug_handler]
+                async fn revalidation_progress(
+                    auth: Option<Extension<Auth>>,
+                    headers: HeaderMap,
+                    Extension(context): Extension<Arc<CrudContext<$resource_type>>>,
+                ) -> Response {
+                    if let Err(err) = check_auth_requirement(Policy::read_requirement(), auth, &headers) {
+                        return err.into_response();
+                    }
+                    let progress = context.revalidation_state.progress();
+                    (StatusCode::OK, Json(progress)).into_response()
+                }
+
                 /// Delete one entity by id.
                 #[utoipa::path(
                     post,
@@ -432,10 +681,11 @@ macro_rules! impl_add_crud_routes {
                 #[axum_macros::debug_handler]
                 async fn delete_by_id(
                     auth: Option<Extension<Auth>>,
+                    headers: HeaderMap,
                     Extension(context): Extension<Arc<CrudContext<$resource_type>>>,
                     Json(body): Json<DeleteById>,
                 ) -> Response {
-                    let request_context = match check_auth_requirement(Policy::delete_requirement(), auth) {
+                    let request_context = match check_auth_requirement(Policy::delete_requirement(), auth, &headers) {
                         Ok(ctx) => ctx,
                         Err(err) => return err.into_response(),
                     };
@@ -460,10 +710,11 @@ macro_rules! impl_add_crud_routes {
                 #[axum_macros::debug_handler]
                 async fn delete_one(
                     auth: Option<Extension<Auth>>,
+                    headers: HeaderMap,
                     Extension(context): Extension<Arc<CrudContext<$resource_type>>>,
                     Json(body): Json<DeleteOne<$resource_type>>,
                 ) -> Response {
-                    let request_context = match check_auth_requirement(Policy::delete_requirement(), auth) {
+                    let request_context = match check_auth_requirement(Policy::delete_requirement(), auth, &headers) {
                         Ok(ctx) => ctx,
                         Err(err) => return err.into_response(),
                     };
@@ -488,10 +739,11 @@ macro_rules! impl_add_crud_routes {
                 #[axum_macros::debug_handler]
                 async fn delete_many(
                     auth: Option<Extension<Auth>>,
+                    headers: HeaderMap,
                     Extension(context): Extension<Arc<CrudContext<$resource_type>>>,
                     Json(body): Json<DeleteMany>,
                 ) -> Response {
-                    let request_context = match check_auth_requirement(Policy::delete_requirement(), auth) {
+                    let request_context = match check_auth_requirement(Policy::delete_requirement(), auth, &headers) {
                         Ok(ctx) => ctx,
                         Err(err) => return err.into_response(),
                     };
@@ -515,6 +767,12 @@ macro_rules! impl_add_crud_routes {
                         read_many,
                         create_one,
                         update_one,
+                        validate_create,
+                        validate_update,
+                        read_violation_count,
+                        read_violations,
+                        revalidate,
+                        revalidation_progress,
                         delete_by_id,
                         delete_one,
                         delete_many,
@@ -534,6 +792,14 @@ macro_rules! impl_add_crud_routes {
                         schemas(crudkit_rs::read::ReadOne<ResourceType>),
                         schemas(crudkit_rs::read::ReadMany<ResourceType>),
                         schemas(crudkit_rs::update::UpdateOne<UpdateModel>),
+                        schemas(crudkit_rs::create::ValidateCreate<CreateModel>),
+                        schemas(crudkit_rs::update::ValidateUpdate<UpdateModel>),
+                        schemas(crudkit_rs::violations::ReadViolationCount),
+                        schemas(crudkit_rs::violations::ReadViolations),
+                        schemas(crudkit_core::validation::ViolationFilter),
+                        schemas(crudkit_core::validation::violation::Severity),
+                        schemas(crudkit_rs::revalidate::RevalidationProgress),
+                        schemas(crudkit_rs::revalidate::RevalidationStatus),
                         schemas(crudkit_rs::delete::DeleteById),
                         schemas(crudkit_rs::delete::DeleteOne<ResourceType>),
                         schemas(crudkit_rs::delete::DeleteMany),
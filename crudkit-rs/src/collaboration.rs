@@ -25,6 +25,7 @@ pub(crate) async fn broadcast_creation_event<R: CrudResource>(
     context: &CrudContext<R>,
     serializable_id: SerializableId,
     with_validation_errors: bool,
+    with_validation_infos: bool,
 ) {
     if let Err(err) = context
         .collab_service
@@ -32,6 +33,7 @@ pub(crate) async fn broadcast_creation_event<R: CrudResource>(
             resource_name: R::TYPE.name().to_owned(),
             entity_id: serializable_id,
             with_validation_errors,
+            with_validation_infos,
         }))
         .await
     {
@@ -42,14 +44,16 @@ pub(crate) async fn broadcast_creation_event<R: CrudResource>(
 pub(crate) async fn broadcast_updated_event<R: CrudResource>(
     context: &Arc<CrudContext<R>>,
     serializable_id: SerializableId,
-    has_violations: bool,
+    has_errors: bool,
+    has_infos: bool,
 ) {
     if let Err(err) = context
         .collab_service
         .broadcast_json(CollabMessage::EntityUpdated(EntityUpdated {
             resource_name: R::TYPE.name().to_owned(),
             entity_id: serializable_id,
-            with_validation_errors: has_violations,
+            with_validation_errors: has_errors,
+            with_validation_infos: has_infos,
         }))
         .await
     {
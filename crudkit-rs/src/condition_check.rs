@@ -0,0 +1,420 @@
+//! Checks conditions and orderings received from clients against a resource's field metadata.
+//!
+//! The check runs before any lifecycle hook or repository call, so that a malformed condition
+//! is rejected with an HTTP 400 listing every offending clause, instead of failing (or
+//! panicking) while the storage backend builds its query.
+
+use crate::auth::RequestContext;
+use crate::data::{ConditionValueConverter, FieldLookup};
+use crate::error::CrudError;
+use crate::placeholder::PlaceholderResolver;
+use crate::resource::CrudResource;
+use crudkit_core::condition::{
+    Condition, ConditionClause, ConditionClauseValue, ConditionElement, Operator,
+};
+use crudkit_core::{OrderSpec, TextOrder, Value, ValueKind};
+use indexmap::IndexMap;
+use serde::Serialize;
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+
+/// A problem with one element of a condition.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct ConditionClauseError {
+    /// Element indices leading from the root condition to the offending element.
+    /// The negated condition of a `Not` has index 0.
+    pub path: Vec<usize>,
+
+    /// Column of the offending clause. `None` if the problem is not tied to a single clause.
+    pub column_name: Option<String>,
+
+    pub message: String,
+}
+
+/// Checks `condition` against the fields `F`, collecting all problems found.
+///
+/// A clause is accepted if
+/// - its field exists,
+/// - its value is no (unresolved) placeholder,
+/// - its value converts to the field's value kind,
+/// - its operator fits the field and value (text operators on text fields, list operators with
+///   lists, `between` with exactly two values, no comparisons against null),
+/// - Json and Enum fields are not ordered,
+/// - Json paths and `json_contains` are only used on Json fields,
+/// - array fields are only filtered with array operators (and null checks), which in turn are
+///   only used on array fields,
+///
+/// and no condition is nested deeper than `max_depth` (the root condition having depth 1).
+pub fn check_condition<F>(
+    condition: &Condition,
+    max_depth: usize,
+) -> Result<(), Vec<ConditionClauseError>>
+where
+    F: FieldLookup + ConditionValueConverter,
+{
+    let mut errors = Vec::new();
+    check_nested::<F>(condition, 1, max_depth, &mut Vec::new(), &mut errors);
+    match errors.is_empty() {
+        true => Ok(()),
+        false => Err(errors),
+    }
+}
+
+/// Checks the optional condition of a request, mapping problems to
+/// [`CrudError::InvalidCondition`].
+pub(crate) fn ensure_valid_condition<F>(
+    condition: Option<&Condition>,
+    max_depth: usize,
+) -> Result<(), CrudError>
+where
+    F: FieldLookup + ConditionValueConverter,
+{
+    match condition {
+        Some(condition) => check_condition::<F>(condition, max_depth)
+            .map_err(|errors| CrudError::InvalidCondition { errors }),
+        None => Ok(()),
+    }
+}
+
+/// Checks that the optional ordering of a request only uses the given `collations`, mapping others
+/// to [`CrudError::InvalidOrderBy`].
+pub(crate) fn ensure_allowed_collations<F>(
+    order_by: Option<&IndexMap<F, OrderSpec>>,
+    collations: &[&str],
+) -> Result<(), CrudError> {
+    let requested = order_by
+        .into_iter()
+        .flat_map(|order_by| order_by.values())
+        .filter_map(|spec| match &spec.text {
+            TextOrder::Collation(collation) => Some(collation),
+            TextOrder::Default | TextOrder::CaseInsensitive => None,
+        });
+    for collation in requested {
+        if !collations.contains(&collation.as_str()) {
+            return Err(CrudError::InvalidOrderBy {
+                reason: format!("Collation '{collation}' is not supported."),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Resolves the request-bound values in the optional condition of a request: placeholders (like
+/// `$tenant_id`) through the resource's [`PlaceholderResolver`], then relative times (like
+/// `now - P7D`) to the points in time they denote right now, in the time zone of the request.
+pub(crate) fn resolve_condition<R: CrudResource>(
+    condition: Option<&mut Condition>,
+    context: &R::Context,
+    request: &RequestContext<R::Auth>,
+) {
+    if let Some(condition) = condition {
+        condition
+            .resolve_placeholders(|name| R::PlaceholderResolver::resolve(name, context, request));
+        condition.resolve_relative_times(OffsetDateTime::now_utc(), request.utc_offset);
+    }
+}
+
+fn check_nested<F>(
+    condition: &Condition,
+    depth: usize,
+    max_depth: usize,
+    path: &mut Vec<usize>,
+    errors: &mut Vec<ConditionClauseError>,
+) where
+    F: FieldLookup + ConditionValueConverter,
+{
+    if depth > max_depth {
+        errors.push(ConditionClauseError {
+            path: path.clone(),
+            column_name: None,
+            message: format!("Conditions must not be nested deeper than {max_depth} levels."),
+        });
+        return;
+    }
+
+    match condition {
+        Condition::All(elements) | Condition::Any(elements) => {
+            for (index, element) in elements.iter().enumerate() {
+                path.push(index);
+                match element {
+                    ConditionElement::Clause(clause) => {
+                        if let Err(message) = check_clause::<F>(clause) {
+                            errors.push(ConditionClauseError {
+                                path: path.clone(),
+                                column_name: Some(clause.column_name.clone()),
+                                message,
+                            });
+                        }
+                    }
+                    ConditionElement::Condition(nested) => {
+                        check_nested::<F>(nested, depth + 1, max_depth, path, errors)
+                    }
+                }
+                path.pop();
+            }
+        }
+        Condition::Not(inner) => {
+            path.push(0);
+            check_nested::<F>(inner, depth + 1, max_depth, path, errors);
+            path.pop();
+        }
+    }
+}
+
+fn check_clause<F>(clause: &ConditionClause) -> Result<(), String>
+where
+    F: FieldLookup + ConditionValueConverter,
+{
+    let column_name = clause.column_name.as_str();
+    let operator = clause.operator;
+
+    let field =
+        F::from_name(column_name).ok_or_else(|| format!("Unknown field '{column_name}'."))?;
+
+    // Null checks ignore the clause value.
+    if operator.is_null_check() {
+        return Ok(());
+    }
+
+    if let ConditionClauseValue::Placeholder(name) = &clause.value {
+        return Err(format!("Unknown placeholder '${name}'."));
+    }
+
+    let kind = field.value_kind();
+    if clause.is_json_clause() {
+        return check_json_clause(clause, kind);
+    }
+    if operator.is_array_operator() || kind == ValueKind::Array {
+        return check_array_clause(&field, clause, kind);
+    }
+    if operator.is_text_operator() && !matches!(kind, ValueKind::String | ValueKind::Other) {
+        return Err(format!(
+            "Operator '{operator:?}' requires a text field, but '{column_name}' holds {kind:?} values."
+        ));
+    }
+    let is_ordering = matches!(
+        operator,
+        Operator::Less
+            | Operator::LessOrEqual
+            | Operator::Greater
+            | Operator::GreaterOrEqual
+            | Operator::Between
+    );
+    if is_ordering && matches!(kind, ValueKind::Json | ValueKind::Enum) {
+        return Err(format!(
+            "Operator '{operator:?}' cannot be applied to the {kind:?} field '{column_name}'."
+        ));
+    }
+
+    let value = field
+        .convert_condition_value(clause.value.clone())
+        .map_err(|reason| format!("Value does not fit field '{column_name}': {reason}"))?;
+
+    match value {
+        Value::Null => Err(format!(
+            "Operator '{operator:?}' cannot compare against null. Use 'is_null' or 'is_not_null'."
+        )),
+        Value::Array(values) if operator == Operator::Between && values.len() != 2 => Err(format!(
+            "Operator 'Between' expects exactly two values, [low, high], but got {}.",
+            values.len()
+        )),
+        Value::Array(_) if !operator.expects_list() => Err(format!(
+            "Operator '{operator:?}' expects a single value, but got a list."
+        )),
+        Value::Array(_) => Ok(()),
+        _ if operator.expects_list() => Err(format!(
+            "Operator '{operator:?}' expects a list of values, but got a single value."
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn check_array_clause<F>(field: &F, clause: &ConditionClause, kind: ValueKind) -> Result<(), String>
+where
+    F: ConditionValueConverter,
+{
+    let column_name = clause.column_name.as_str();
+    let operator = clause.operator;
+
+    match (operator.is_array_operator(), kind == ValueKind::Array) {
+        (true, false) => {
+            return Err(format!(
+                "Operator '{operator:?}' requires an array field, but '{column_name}' holds {kind:?} values."
+            ));
+        }
+        (false, _) => {
+            return Err(format!(
+                "Operator '{operator:?}' cannot be applied to the array field '{column_name}'. Use 'array_contains', 'array_overlaps' or 'array_length'."
+            ));
+        }
+        (true, true) => {}
+    }
+
+    match operator {
+        Operator::ArrayLength => clause
+            .value
+            .clone()
+            .to_array_length()
+            .map(|_| ())
+            .map_err(|reason| format!("Invalid length: {reason}")),
+        _ => field
+            .convert_condition_value(clause.value.clone())
+            .map(|_| ())
+            .map_err(|reason| format!("Value does not fit field '{column_name}': {reason}")),
+    }
+}
+
+fn check_json_clause(clause: &ConditionClause, kind: ValueKind) -> Result<(), String> {
+    let column_name = clause.column_name.as_str();
+    let operator = clause.operator;
+
+    if kind != ValueKind::Json {
+        return Err(format!(
+            "Json paths and 'json_contains' require a Json field, but '{column_name}' holds {kind:?} values."
+        ));
+    }
+
+    let value = clause
+        .value
+        .clone()
+        .to_json()
+        .map_err(|reason| format!("Value can not be used as JSON: {reason}"))?;
+
+    match value {
+        serde_json::Value::String(_) if operator.is_text_operator() => Ok(()),
+        _ if operator.is_text_operator() => {
+            Err(format!("Operator '{operator:?}' requires a string value."))
+        }
+        serde_json::Value::Array(values) if operator == Operator::Between && values.len() != 2 => {
+            Err(format!(
+                "Operator 'Between' expects exactly two values, [low, high], but got {}.",
+                values.len()
+            ))
+        }
+        serde_json::Value::Array(_) => Ok(()),
+        _ if operator.expects_list() => Err(format!(
+            "Operator '{operator:?}' expects a list of values, but got a single value."
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum TestField {
+        Id,
+        Name,
+    }
+
+    impl FieldLookup for TestField {
+        fn from_name(name: &str) -> Option<Self> {
+            match name {
+                "id" => Some(Self::Id),
+                "name" => Some(Self::Name),
+                _ => None,
+            }
+        }
+    }
+
+    impl ConditionValueConverter for TestField {
+        fn convert_condition_value(&self, value: ConditionClauseValue) -> Result<Value, String> {
+            value.into_value_of_kind(self.value_kind())
+        }
+
+        fn value_kind(&self) -> ValueKind {
+            match self {
+                Self::Id => ValueKind::I64,
+                Self::Name => ValueKind::String,
+            }
+        }
+    }
+
+    #[test]
+    fn clauses_fitting_their_fields_are_accepted() {
+        let condition = Condition::all()
+            .eq("id", 1i64)
+            .in_("id", vec![1i64, 2])
+            .contains("name", "a")
+            .is_null("name");
+
+        assert_eq!(check_condition::<TestField>(&condition, 4), Ok(()));
+    }
+
+    #[test]
+    fn unknown_columns_are_reported_with_their_path() {
+        let mut condition = Condition::all().eq("id", 1i64);
+        condition.push_condition(Condition::any().eq("name", "a").eq("typo", 1i64));
+
+        let errors = check_condition::<TestField>(&condition, 4).expect_err("unknown column");
+
+        assert_eq!(
+            errors,
+            vec![ConditionClauseError {
+                path: vec![1, 1],
+                column_name: Some("typo".to_owned()),
+                message: "Unknown field 'typo'.".to_owned(),
+            }]
+        );
+    }
+
+    #[test]
+    fn values_and_operators_not_fitting_the_field_are_reported() {
+        let condition = Condition::all()
+            .eq("id", "not a number")
+            .contains("id", "1")
+            .eq("name", 1i64)
+            .in_("name", vec!["a".to_owned()])
+            .eq("id", 1i64);
+
+        let errors = check_condition::<TestField>(&condition, 4).expect_err("type mismatches");
+
+        assert_eq!(
+            errors
+                .iter()
+                .map(|error| (error.path.as_slice(), error.column_name.as_deref()))
+                .collect::<Vec<_>>(),
+            vec![
+                (&[0][..], Some("id")),
+                (&[1][..], Some("id")),
+                (&[2][..], Some("name")),
+            ]
+        );
+        assert!(
+            errors[0]
+                .message
+                .starts_with("Value does not fit field 'id'")
+        );
+        assert_eq!(
+            errors[1].message,
+            "Operator 'Contains' requires a text field, but 'id' holds I64 values."
+        );
+        assert!(
+            errors[2]
+                .message
+                .starts_with("Value does not fit field 'name'")
+        );
+    }
+
+    #[test]
+    fn only_allowed_collations_are_accepted() {
+        let allowed = IndexMap::from([
+            ("name", OrderSpec::asc().collation("de-DE-x-icu")),
+            ("title", OrderSpec::desc().case_insensitive()),
+        ]);
+        let disallowed = IndexMap::from([("name", OrderSpec::asc().collation("C\" ; --"))]);
+
+        assert!(ensure_allowed_collations(None::<&IndexMap<&str, OrderSpec>>, &[]).is_ok());
+        assert!(ensure_allowed_collations(Some(&allowed), &["de-DE-x-icu"]).is_ok());
+        assert!(matches!(
+            ensure_allowed_collations(Some(&allowed), &[]),
+            Err(CrudError::InvalidOrderBy { .. })
+        ));
+        assert!(matches!(
+            ensure_allowed_collations(Some(&disallowed), &["de-DE-x-icu"]),
+            Err(CrudError::InvalidOrderBy { .. })
+        ));
+    }
+}
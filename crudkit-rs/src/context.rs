@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
 use crate::resource::CrudResource;
+use crate::revalidate::RevalidationState;
 use crate::validate::GlobalValidationState;
-use crate::validator::{AggregateValidator, EntityValidator};
+use crate::validator::{AggregateValidator, AsyncEntityValidator, EntityValidator};
 
 /// The context is made available to any crud operation.
 /// It may contain state relevant to its associated CrudResource.
@@ -10,8 +11,12 @@ pub struct CrudContext<R: CrudResource> {
     pub res_context: Arc<R::Context>,
     pub repository: Arc<R::Repository>,
     pub validators: Vec<Arc<dyn EntityValidator<R>>>,
+    /// Validators requiring async work, like database lookups. They run concurrently, each bounded
+    /// by its timeout.
+    pub async_validators: Vec<Arc<dyn AsyncEntityValidator<R>>>,
     pub resource_validators: Vec<Arc<dyn AggregateValidator<R>>>,
     pub validation_result_repository: Arc<R::ValidationResultRepository>,
     pub collab_service: Arc<R::CollaborationService>,
     pub global_validation_state: Arc<GlobalValidationState>,
+    pub revalidation_state: Arc<RevalidationState>,
 }
@@ -71,7 +71,7 @@ pub async fn create_one<R: CrudResource>(
 
     // TODO: Should this be done BEFORE or AFTER running the `before_create` hook?
     let violations_by_validator =
-        run_entity_validation::<R>(&context.validators, &create_model, trigger);
+        run_entity_validation::<R>(&context, &request, &create_model, trigger).await;
 
     if violations_by_validator.has_critical_violations() {
         // Critical validation errors are returned synchronously in the HTTP response.
@@ -100,7 +100,7 @@ pub async fn create_one<R: CrudResource>(
         &create_model_clone,
         &inserted_entity,
         &context.res_context,
-        request,
+        request.clone(),
         hook_data,
     )
     .await
@@ -116,9 +116,11 @@ pub async fn create_one<R: CrudResource>(
     });
 
     let violations_by_validator =
-        run_model_validation::<R>(&context.validators, &inserted_entity, trigger);
+        run_model_validation::<R>(&context, &request, &inserted_entity, trigger).await;
 
     let has_violations = violations_by_validator.has_violations();
+    let has_errors = violations_by_validator.has_errors();
+    let has_infos = violations_by_validator.has_infos();
 
     if has_violations {
         // Persist the validation results for later access/use.
@@ -160,13 +162,58 @@ pub async fn create_one<R: CrudResource>(
     }
 
     // Inform all users that the entity was created.
-    collaboration::broadcast_creation_event(&context, serializable_id, has_violations).await;
+    collaboration::broadcast_creation_event(&context, serializable_id, has_errors, has_infos).await;
 
     // Trigger global validation to check system-wide consistency.
-    run_global_validation::<R>(&context).await;
+    run_global_validation::<R>(&context);
 
     Ok(Saved {
         entity: inserted_entity,
         violations: partial,
     })
 }
+
+/// Request body for validating a would-be created entity, without creating it.
+#[derive(Debug, ToSchema, Deserialize)]
+pub struct ValidateCreate<T> {
+    /// The entity data to validate.
+    pub entity: T,
+}
+
+/// Validate a would-be created entity without creating it (dry-run).
+///
+/// Runs all entity validators as creating would, preceded by the `before_create` hook unless
+/// [`CrudResource::DRY_RUN_HOOKS`] is disabled. Nothing is persisted or broadcast. Critical
+/// violations are returned like any other violation instead of failing the request.
+#[tracing::instrument(level = "info", skip(context, request))]
+pub async fn validate_create<R: CrudResource>(
+    request: RequestContext<R::Auth>,
+    context: Arc<CrudContext<R>>,
+    body: ValidateCreate<R::CreateModel>,
+) -> Result<PartialSerializableAggregateViolations, CrudError> {
+    let mut create_model: R::CreateModel = body.entity;
+
+    if R::DRY_RUN_HOOKS {
+        R::Lifetime::before_create(
+            &mut create_model,
+            &context.res_context,
+            request.clone(),
+            R::HookData::default(),
+        )
+        .await
+        .map_err(CrudError::from)?;
+    }
+
+    let trigger = ValidationTrigger::CrudAction(ValidationContext {
+        action: CrudAction::Create,
+        when: When::Before,
+    });
+
+    let violations_by_validator =
+        run_entity_validation::<R>(&context, &request, &create_model, trigger).await;
+
+    Ok(PartialSerializableAggregateViolations::from(
+        violations_by_validator,
+        None,
+    ))
+}
@@ -24,8 +24,9 @@
 //! (typically via the `CkCreateModel` or `CkSeaOrmCreateModel` derive macros) because
 //! create models often need storage-specific conversion logic.
 
-use crudkit_core::condition::ConditionClauseValue;
-use crudkit_core::Value;
+use crudkit_core::condition::{Condition, ConditionClauseValue, ConditionParseError};
+use crudkit_core::validation::violation::Violations;
+use crudkit_core::{Value, ValueKind};
 use serde::{de::DeserializeOwned, Serialize};
 use std::fmt::Debug;
 use std::hash::Hash;
@@ -40,6 +41,13 @@ use std::hash::Hash;
 pub trait Model: Clone + Debug + Send + Sync + 'static {
     /// The field enum for this model, providing typed access to field values.
     type Field: Field;
+
+    /// Runs the checks declared with `#[ck_validate(...)]` on the fields of this model.
+    ///
+    /// Generated by the model derives. Models without such checks report no violations.
+    fn validate_fields(&self) -> Violations {
+        Violations::empty()
+    }
 }
 
 /// Trait for field enums that provide metadata about model fields.
@@ -73,6 +81,12 @@ pub trait FieldLookup: Sized {
     /// Attempt to find a field by its string name.
     /// Returns `None` if no field with that name exists.
     fn from_name(name: &str) -> Option<Self>;
+
+    /// Parses a condition in the textual filter syntax (see `crudkit_core::condition::text`),
+    /// reporting unknown field names as parse errors.
+    fn parse_condition(input: &str) -> Result<Condition, ConditionParseError> {
+        Condition::parse_with_fields(input, |name| Self::from_name(name).is_some())
+    }
 }
 
 /// Trait for converting condition values to typed values.
@@ -82,6 +96,15 @@ pub trait FieldLookup: Sized {
 pub trait ConditionValueConverter {
     /// Convert a condition clause value to a typed Value for this field.
     fn convert_condition_value(&self, value: ConditionClauseValue) -> Result<Value, String>;
+
+    /// The kind of (non-null) values this field holds.
+    ///
+    /// `ValueKind::Other` for custom types, on which only the conversion is checked. Defaults to
+    /// `ValueKind::Other`, so hand-written implementations only get their conversions checked
+    /// until they override it.
+    fn value_kind(&self) -> ValueKind {
+        ValueKind::Other
+    }
 }
 
 /// Re-export `HasId` from crudkit-id.
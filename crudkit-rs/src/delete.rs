@@ -15,13 +15,14 @@ use crate::validate::{run_global_validation, run_model_validation};
 use crate::{
     auth::RequestContext,
     collaboration,
+    condition_check::{ensure_allowed_collations, ensure_valid_condition, resolve_condition},
     error::CrudError,
     lifetime::{CrudLifetime, DeleteOperation, DeleteRequest, HookError},
     prelude::*,
     validation::{CrudAction, ValidationContext, ValidationTrigger, When},
 };
 use crudkit_core::condition::{Condition, TryIntoAllEqualCondition};
-use crudkit_core::{Deleted, DeletedMany, Order};
+use crudkit_core::{Deleted, DeletedMany, OrderSpec};
 use crudkit_core::id::{Id, SerializableId};
 use crudkit_core::validation::PartialSerializableAggregateViolations;
 
@@ -66,9 +67,10 @@ pub struct DeleteById {
 pub struct DeleteOne<R: CrudResource> {
     /// Number of entities to skip.
     pub skip: Option<u64>,
-    /// Ordering specification.
-    #[schema(value_type = Option<Object>, example = json!({"id": Order::Asc}))]
-    pub order_by: Option<IndexMap<R::ModelField, Order>>,
+    /// Ordering specification. Each field maps to an order like `"asc"`, or to an [`OrderSpec`]
+    /// also placing nulls and comparing text case-insensitively or by a collation.
+    #[schema(value_type = Option<Object>, example = json!({"id": "asc"}))]
+    pub order_by: Option<IndexMap<R::ModelField, OrderSpec>>,
     /// Filter condition.
     pub condition: Option<Condition>,
 }
@@ -112,7 +114,7 @@ pub async fn delete_by_id<R: CrudResource>(
 
     execute_single_delete(model, &delete_request, &context, &request).await?;
 
-    run_global_validation::<R>(&context).await;
+    run_global_validation::<R>(&context);
 
     Ok(Deleted {
         entities_affected: 1,
@@ -124,8 +126,12 @@ pub async fn delete_by_id<R: CrudResource>(
 pub async fn delete_one<R: CrudResource>(
     request: RequestContext<R::Auth>,
     context: Arc<CrudContext<R>>,
-    body: DeleteOne<R>,
+    mut body: DeleteOne<R>,
 ) -> Result<Deleted, CrudError> {
+    resolve_condition::<R>(body.condition.as_mut(), &context.res_context, &request);
+    ensure_valid_condition::<R::ModelField>(body.condition.as_ref(), R::MAX_CONDITION_DEPTH)?;
+    ensure_allowed_collations(body.order_by.as_ref(), R::COLLATIONS)?;
+
     let model = context
         .repository
         .fetch_one(
@@ -149,7 +155,7 @@ pub async fn delete_one<R: CrudResource>(
 
     execute_single_delete(model, &delete_request, &context, &request).await?;
 
-    run_global_validation::<R>(&context).await;
+    run_global_validation::<R>(&context);
 
     Ok(Deleted {
         entities_affected: 1,
@@ -208,7 +214,7 @@ async fn execute_single_delete<R: CrudResource>(
         when: When::Before,
     });
     let partial_validation_results =
-        run_model_validation::<R>(&context.validators, &model, trigger);
+        run_model_validation::<R>(context, request, &model, trigger).await;
 
     if partial_validation_results.has_critical_violations() {
         return Err(SingleDeleteError::ValidationFailed(
@@ -260,8 +266,11 @@ async fn execute_single_delete<R: CrudResource>(
 pub async fn delete_many<R: CrudResource>(
     request: RequestContext<R::Auth>,
     context: Arc<CrudContext<R>>,
-    body: DeleteMany,
+    mut body: DeleteMany,
 ) -> Result<DeletedMany, CrudError> {
+    resolve_condition::<R>(body.condition.as_mut(), &context.res_context, &request);
+    ensure_valid_condition::<R::ModelField>(body.condition.as_ref(), R::MAX_CONDITION_DEPTH)?;
+
     let mut result = DeletedMany {
         deleted_count: 0,
         deleted_ids: Vec::new(),
@@ -331,7 +340,7 @@ pub async fn delete_many<R: CrudResource>(
                 when: When::Before,
             });
             let partial_validation_results =
-                run_model_validation::<R>(&context.validators, &model, trigger);
+                run_model_validation::<R>(&context, &request, &model, trigger).await;
 
             if partial_validation_results.has_critical_violations() {
                 result.validation_failed.push(id_to_json(&serializable_id));
@@ -382,7 +391,7 @@ pub async fn delete_many<R: CrudResource>(
         }
     }
 
-    run_global_validation::<R>(&context).await;
+    run_global_validation::<R>(&context);
 
     Ok(result)
 }
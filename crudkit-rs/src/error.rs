@@ -1,3 +1,4 @@
+use crate::condition_check::ConditionClauseError;
 use crate::repository::RepositoryError;
 use crudkit_core::condition::IntoAllEqualConditionError;
 use crudkit_core::validation::PartialSerializableAggregateViolations;
@@ -60,6 +61,18 @@ pub enum CrudError {
     #[snafu(display("Invalid query parameters: {source}"))]
     IntoCondition { source: IntoAllEqualConditionError },
 
+    /// The request's condition does not fit the resource's fields.
+    ///
+    /// Mapped to HTTP status 400 Bad Request.
+    #[snafu(display("Invalid condition: {errors:?}"))]
+    InvalidCondition { errors: Vec<ConditionClauseError> },
+
+    /// The request's ordering is not supported by the resource.
+    ///
+    /// Mapped to HTTP status 400 Bad Request.
+    #[snafu(display("Invalid ordering: {reason}"))]
+    InvalidOrderBy { reason: String },
+
     // =========================================================================
     // Server Errors (HTTP 500)
     // =========================================================================
@@ -86,4 +99,16 @@ pub enum CrudError {
     /// Mapped to HTTP status 500 Internal Server Error.
     #[snafu(display("Could not delete validations: {reason:?}"))]
     DeleteValidations { reason: Arc<dyn RepositoryError> },
+
+    /// Could not read validation results.
+    ///
+    /// Mapped to HTTP status 500 Internal Server Error.
+    #[snafu(display("Could not read validations: {reason:?}"))]
+    ReadValidations { reason: Arc<dyn RepositoryError> },
+
+    /// The entities of the resource can not be revalidated.
+    ///
+    /// Mapped to HTTP status 500 Internal Server Error.
+    #[snafu(display("Could not revalidate: {reason}"))]
+    Revalidation { reason: String },
 }
@@ -0,0 +1,348 @@
+//! Runtime support for the `#[ck_validate(...)]` field attributes.
+//!
+//! The `CkCreateModel`, `CkUpdateModel`, `CkSeaOrmCreateModel` and `CkSeaOrmUpdateModel` derives
+//! turn these attributes into calls of the checks below, made from the generated
+//! [`Model::validate_fields`](crate::data::Model::validate_fields). The checks run through the
+//! [`FieldAttributeValidator`](crate::validator::FieldAttributeValidator), which every
+//! [`CrudContext`](crate::context::CrudContext) runs before its registered validators.
+//!
+//! Absent values, like a `None` in an optional field, are not checked.
+
+use crudkit_core::validation::violation::{Severity, Violation, ViolationParam, Violations};
+use rust_decimal::Decimal;
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub use regex::Regex;
+
+/// Values checked by `length(min = .., max = ..)`.
+pub trait HasLength {
+    /// The length of the value, or `None` if there is no value to check.
+    fn checked_length(&self) -> Option<usize>;
+}
+
+impl HasLength for String {
+    fn checked_length(&self) -> Option<usize> {
+        Some(self.chars().count())
+    }
+}
+
+impl<T> HasLength for Vec<T> {
+    fn checked_length(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<T: HasLength> HasLength for Option<T> {
+    fn checked_length(&self) -> Option<usize> {
+        self.as_ref().and_then(HasLength::checked_length)
+    }
+}
+
+/// Values checked by `regex = ".."`.
+pub trait HasText {
+    /// The text of the value, or `None` if there is no value to check.
+    fn checked_text(&self) -> Option<&str>;
+}
+
+impl HasText for String {
+    fn checked_text(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl<T: HasText> HasText for Option<T> {
+    fn checked_text(&self) -> Option<&str> {
+        self.as_ref().and_then(HasText::checked_text)
+    }
+}
+
+/// Values checked by `range(min = .., max = ..)`.
+pub trait HasNumber {
+    /// The numeric type the value and the bounds are compared in.
+    type Number: PartialOrd + FromStr + Display;
+
+    /// The value as a number, or `None` if there is no value to check.
+    fn checked_number(&self) -> Option<Self::Number>;
+}
+
+macro_rules! impl_has_number {
+    ($($ty:ty),*) => {
+        $(
+            impl HasNumber for $ty {
+                type Number = $ty;
+
+                fn checked_number(&self) -> Option<$ty> {
+                    Some(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_has_number!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64, Decimal);
+
+impl<T: HasNumber> HasNumber for Option<T> {
+    type Number = T::Number;
+
+    fn checked_number(&self) -> Option<T::Number> {
+        self.as_ref().and_then(HasNumber::checked_number)
+    }
+}
+
+/// Checks the length of `value` against `min` and `max`, both inclusive.
+pub fn check_length(
+    violations: &mut Violations,
+    field: &'static str,
+    severity: Severity,
+    value: &impl HasLength,
+    min: Option<usize>,
+    max: Option<usize>,
+) {
+    let Some(length) = value.checked_length() else {
+        return;
+    };
+    if let Some(min) = min.filter(|min| length < *min) {
+        violations.push(
+            Violation::new(severity, format!("Must have a length of at least {min}."))
+                .for_field(field)
+                .with_code("too_short")
+                .with_param("min", ViolationParam::Integer(min as i64)),
+        );
+    }
+    if let Some(max) = max.filter(|max| length > *max) {
+        violations.push(
+            Violation::new(severity, format!("Must have a length of at most {max}."))
+                .for_field(field)
+                .with_code("too_long")
+                .with_param("max", ViolationParam::Integer(max as i64)),
+        );
+    }
+}
+
+/// Checks that `regex` matches the text of `value`.
+pub fn check_regex(
+    violations: &mut Violations,
+    field: &'static str,
+    severity: Severity,
+    value: &impl HasText,
+    regex: &Regex,
+) {
+    let Some(text) = value.checked_text() else {
+        return;
+    };
+    if !regex.is_match(text) {
+        violations.push(
+            Violation::new(severity, "Does not match the expected format.")
+                .for_field(field)
+                .with_code("pattern_mismatch")
+                .with_param("pattern", regex.as_str()),
+        );
+    }
+}
+
+/// Checks `value` against `min` and `max`, both inclusive.
+///
+/// The bounds are given as written in the attribute and parsed into the numeric type of the
+/// value, so that no precision is lost.
+///
+/// # Panics
+///
+/// Panics if a bound is not a valid value of that type, like `1.5` for an integer field. The
+/// derives reject such bounds at compile time for the numeric types they recognize, so this only
+/// happens for fields declared through a type alias.
+pub fn check_range<T: HasNumber>(
+    violations: &mut Violations,
+    field: &'static str,
+    severity: Severity,
+    value: &T,
+    min: Option<&'static str>,
+    max: Option<&'static str>,
+) {
+    let Some(number) = value.checked_number() else {
+        return;
+    };
+    let parse = |bound: &'static str| match bound.parse::<T::Number>() {
+        Ok(parsed) => (parsed, bound),
+        Err(_) => panic!(
+            "range bound {bound} of field '{field}' is not a valid {}",
+            std::any::type_name::<T::Number>()
+        ),
+    };
+    if let Some((_, min)) = min.map(parse).filter(|(min, _)| number < *min) {
+        violations.push(
+            Violation::new(severity, format!("Must be at least {min}."))
+                .for_field(field)
+                .with_code("too_small")
+                .with_param("min", bound_param(min)),
+        );
+    }
+    if let Some((_, max)) = max.map(parse).filter(|(max, _)| number > *max) {
+        violations.push(
+            Violation::new(severity, format!("Must be at most {max}."))
+                .for_field(field)
+                .with_code("too_large")
+                .with_param("max", bound_param(max)),
+        );
+    }
+}
+
+/// The violation param of a range bound, a decimal unless the bound is out of its range.
+fn bound_param(bound: &str) -> ViolationParam {
+    Decimal::from_str_exact(bound).map_or_else(
+        |_| ViolationParam::String(bound.to_owned()),
+        ViolationParam::Decimal,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codes(violations: &Violations) -> Vec<&str> {
+        violations
+            .iter()
+            .filter_map(|violation| violation.code.as_deref())
+            .collect()
+    }
+
+    #[test]
+    fn check_length_counts_chars_and_skips_absent_values() {
+        let mut violations = Violations::empty();
+        let check = |violations: &mut Violations, value: &Option<String>| {
+            check_length(
+                violations,
+                "name",
+                Severity::Critical,
+                value,
+                Some(2),
+                Some(3),
+            )
+        };
+
+        check(&mut violations, &Some("äöü".to_owned()));
+        check(&mut violations, &None);
+        assert!(violations.is_empty());
+
+        check(&mut violations, &Some("a".to_owned()));
+        check(&mut violations, &Some("abcd".to_owned()));
+        assert_eq!(codes(&violations), ["too_short", "too_long"]);
+    }
+
+    #[test]
+    fn check_regex_reports_mismatches() {
+        let regex = Regex::new("^[a-z-]+$").expect("valid regex");
+        let mut violations = Violations::empty();
+
+        check_regex(
+            &mut violations,
+            "slug",
+            Severity::Major,
+            &"a-b".to_owned(),
+            &regex,
+        );
+        assert!(violations.is_empty());
+
+        check_regex(
+            &mut violations,
+            "slug",
+            Severity::Major,
+            &"A B".to_owned(),
+            &regex,
+        );
+        assert_eq!(codes(&violations), ["pattern_mismatch"]);
+    }
+
+    #[test]
+    fn check_range_compares_integers_exactly() {
+        let mut violations = Violations::empty();
+        let max = Some("9007199254740993");
+
+        check_range(
+            &mut violations,
+            "n",
+            Severity::Critical,
+            &9_007_199_254_740_993_i64,
+            None,
+            max,
+        );
+        assert!(violations.is_empty());
+
+        check_range(
+            &mut violations,
+            "n",
+            Severity::Critical,
+            &9_007_199_254_740_994_i64,
+            None,
+            max,
+        );
+        check_range(
+            &mut violations,
+            "n",
+            Severity::Critical,
+            &Some(-1_i64),
+            Some("0"),
+            None,
+        );
+        assert_eq!(codes(&violations), ["too_large", "too_small"]);
+    }
+
+    #[test]
+    fn check_range_compares_decimals_exactly() {
+        let mut violations = Violations::empty();
+        let min = Some("0.1");
+
+        check_range(
+            &mut violations,
+            "price",
+            Severity::Critical,
+            &Decimal::new(1, 1),
+            min,
+            None,
+        );
+        assert!(violations.is_empty());
+
+        check_range(
+            &mut violations,
+            "price",
+            Severity::Critical,
+            &Decimal::new(99, 3),
+            min,
+            None,
+        );
+        assert_eq!(codes(&violations), ["too_small"]);
+        assert_eq!(
+            violations[0].params.get("min"),
+            Some(&ViolationParam::Decimal(Decimal::new(1, 1)))
+        );
+    }
+
+    #[test]
+    fn check_range_skips_absent_values() {
+        let mut violations = Violations::empty();
+
+        check_range(
+            &mut violations,
+            "n",
+            Severity::Critical,
+            &None::<f64>,
+            Some("0"),
+            Some("1"),
+        );
+
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "range bound 1.5 of field 'n' is not a valid i32")]
+    fn check_range_rejects_bounds_invalid_for_the_type() {
+        check_range(
+            &mut Violations::empty(),
+            "n",
+            Severity::Critical,
+            &1_i32,
+            Some("1.5"),
+            None,
+        );
+    }
+}
@@ -32,19 +32,24 @@
 pub mod auth;
 pub mod axum_routes;
 pub mod collaboration;
+pub mod condition_check;
 pub mod context;
 pub mod create;
 pub mod data;
 pub mod delete;
 pub mod error;
+pub mod field_validation;
 pub mod lifetime;
+pub mod placeholder;
 pub mod read;
 pub mod repository;
 pub mod resource;
+pub mod revalidate;
 pub mod update;
 pub mod validate;
 pub mod validation;
 pub mod validator;
+pub mod violations;
 
 // Re-export common modules for convenience.
 pub use crudkit_core;
@@ -64,6 +69,7 @@ pub mod prelude {
 
     // Derive macros that remain in crudkit-rs (storage-agnostic).
     pub use crudkit_rs_macros::CkResourceContext;
+    pub use crudkit_core_macros::CkEnum;
     pub use crudkit_core_macros::CkId;
 
     // Authentication and authorization.
@@ -78,6 +84,10 @@ pub mod prelude {
     pub use super::auth::RequiresAuth;
     pub use super::auth::RestrictedAuthPolicy;
 
+    // Condition placeholders.
+    pub use super::placeholder::NoPlaceholders;
+    pub use super::placeholder::PlaceholderResolver;
+
     // Errors.
     pub use super::axum_routes::AxumCrudError;
     pub use super::error::CrudError;
@@ -122,10 +132,13 @@ pub mod prelude {
     pub use super::validation::When;
     pub use super::validator::AggregateValidator;
     pub use super::validator::EntityValidator;
+    pub use super::validator::FieldAttributeValidator;
 
     pub use super::create::create_one;
     // CRUD operations.
+    pub use super::create::validate_create;
     pub use super::create::CreateOne;
+    pub use super::create::ValidateCreate;
     pub use super::delete::delete_by_id;
     pub use super::delete::delete_many;
     pub use super::delete::delete_one;
@@ -139,5 +152,11 @@ pub mod prelude {
     pub use super::read::ReadMany;
     pub use super::read::ReadOne;
     pub use super::update::update_one;
+    pub use super::update::validate_update;
     pub use super::update::UpdateOne;
+    pub use super::update::ValidateUpdate;
+    pub use super::violations::read_violation_count;
+    pub use super::violations::read_violations;
+    pub use super::violations::ReadViolationCount;
+    pub use super::violations::ReadViolations;
 }
@@ -3,7 +3,7 @@
 //! Hooks allow custom logic to run before and after create, read, update, and delete operations.
 
 use crudkit_core::condition::Condition;
-use crudkit_core::Order;
+use crudkit_core::OrderSpec;
 use indexmap::IndexMap;
 use snafu::Snafu;
 use std::fmt::Debug;
@@ -141,11 +141,13 @@ pub struct ReadRequest<R: CrudResource> {
     /// Number of entities to skip before returning results.
     pub skip: Option<u64>,
     /// Ordering specification for results.
-    pub order_by: Option<IndexMap<R::ReadModelField, Order>>,
+    pub order_by: Option<IndexMap<R::ReadModelField, OrderSpec>>,
     /// Filter condition for the query.
     ///
     /// In `before_read`, this can be modified to implement row-level security
     /// by adding additional conditions (e.g., filtering by tenant_id or user ownership).
+    /// Placeholders (like `$tenant_id`) and relative times added there are resolved after the
+    /// hook, and the condition is checked again, rejecting unknown placeholders.
     pub condition: Option<Condition>,
 }
 
@@ -191,7 +193,7 @@ pub struct DeleteRequest<R: CrudResource> {
     /// Number of entities to skip (for delete_one).
     pub skip: Option<u64>,
     /// Ordering specification (for delete_one).
-    pub order_by: Option<IndexMap<R::ModelField, Order>>,
+    pub order_by: Option<IndexMap<R::ModelField, OrderSpec>>,
     /// Filter condition used to select the entity.
     /// For delete_by_id, this is the condition derived from the ID.
     pub condition: Option<Condition>,
@@ -360,11 +362,32 @@ pub trait CrudLifetime<R: CrudResource> {
         request: RequestContext<R::Auth>,
         data: R::HookData,
     ) -> impl Future<Output = Result<R::HookData, HookError<Self::Error>>> + Send;
+
+    // =========================================================================
+    // Revalidation Hooks
+    // =========================================================================
+
+    /// Called before a revalidation is started through the `revalidate` route.
+    ///
+    /// A revalidation validates all entities of the resource, regardless of the caller's
+    /// `before_read` condition. It is therefore forbidden unless this hook is overridden, e.g. to
+    /// allow administrators.
+    fn before_revalidate(
+        _context: &R::Context,
+        _request: RequestContext<R::Auth>,
+    ) -> impl Future<Output = Result<(), HookError<Self::Error>>> + Send {
+        async {
+            Err(HookError::Forbidden {
+                reason: "Revalidation is not enabled for this resource.".into(),
+            })
+        }
+    }
 }
 
 /// Default no-op implementation of lifecycle hooks.
 ///
-/// All hooks simply return `Ok(data)`, allowing the operation to proceed.
+/// All hooks simply return `Ok(data)`, allowing the operation to proceed. Revalidation through the
+/// `revalidate` route stays forbidden.
 #[derive(Debug)]
 pub struct NoopLifetimeHooks {}
 
@@ -0,0 +1,62 @@
+//! Request-bound placeholders in conditions.
+//!
+//! Conditions may refer to values of the request executing them through placeholders like
+//! `$tenant_id` or `$current_user_id`. This allows base conditions of a frontend and stored
+//! filters to restrict results to the caller, without trusting ids supplied by the client.
+//!
+//! Placeholders are resolved through the resource's [`PlaceholderResolver`] before the
+//! condition is validated and handed to the [`Repository`](crate::repository::Repository).
+//! Placeholders added by a `before_read` hook are resolved, and the condition checked again,
+//! after the hook. Conditions using placeholders unknown to the resolver are rejected with
+//! [`CrudError::InvalidCondition`](crate::error::CrudError::InvalidCondition).
+
+use crate::{auth::RequestContext, resource::CrudResource};
+
+use crudkit_core::condition::ConditionClauseValue;
+
+/// Resolves placeholders in conditions to values of the current request.
+///
+/// # Example
+///
+/// ```ignore
+/// struct ArticlePlaceholders;
+///
+/// impl PlaceholderResolver<Article> for ArticlePlaceholders {
+///     fn resolve(
+///         name: &str,
+///         _context: &ArticleContext,
+///         request: &RequestContext<KeycloakToken<Role>>,
+///     ) -> Option<ConditionClauseValue> {
+///         let auth = request.auth.as_ref()?;
+///         match name {
+///             "current_user_id" => Some(auth.subject.clone().into()),
+///             "tenant_id" => Some(auth.extra.tenant_id.into()),
+///             _ => None,
+///         }
+///     }
+/// }
+/// ```
+pub trait PlaceholderResolver<R: CrudResource>: Send + Sync + 'static {
+    /// The value of the placeholder `name` (given without the leading `$`) for `request`.
+    ///
+    /// Return `None` for unknown placeholders, or if the value is not available for this
+    /// request (e.g. user specific placeholders in unauthenticated requests).
+    fn resolve(
+        name: &str,
+        context: &R::Context,
+        request: &RequestContext<R::Auth>,
+    ) -> Option<ConditionClauseValue>;
+}
+
+/// Resolver knowing no placeholders. Conditions using placeholders are rejected.
+pub struct NoPlaceholders;
+
+impl<R: CrudResource> PlaceholderResolver<R> for NoPlaceholders {
+    fn resolve(
+        _name: &str,
+        _context: &R::Context,
+        _request: &RequestContext<R::Auth>,
+    ) -> Option<ConditionClauseValue> {
+        None
+    }
+}
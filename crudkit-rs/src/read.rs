@@ -4,13 +4,14 @@
 
 use crate::{
     auth::RequestContext,
+    condition_check::{ensure_allowed_collations, ensure_valid_condition, resolve_condition},
     error::CrudError,
     lifetime::{CrudLifetime, ReadOperation, ReadRequest, ReadResult},
     prelude::*,
 };
 
 use crudkit_core::condition::Condition;
-use crudkit_core::Order;
+use crudkit_core::OrderSpec;
 
 use indexmap::IndexMap;
 use serde::Deserialize;
@@ -30,10 +31,11 @@ pub struct ReadCount {
 pub struct ReadOne<R: CrudResource> {
     /// Number of entities to skip.
     pub skip: Option<u64>,
-    /// Ordering specification.
+    /// Ordering specification. Each field maps to an order like `"asc"`, or to an [`OrderSpec`]
+    /// also placing nulls and comparing text case-insensitively or by a collation.
     #[serde(bound = "")]
-    #[schema(value_type = Option<Object>, example = json!({"id": Order::Asc}))]
-    pub order_by: Option<IndexMap<R::ReadModelField, Order>>,
+    #[schema(value_type = Option<Object>, example = json!({"id": "asc"}))]
+    pub order_by: Option<IndexMap<R::ReadModelField, OrderSpec>>,
     /// Filter condition.
     pub condition: Option<Condition>,
 }
@@ -45,10 +47,11 @@ pub struct ReadMany<R: CrudResource> {
     pub limit: Option<u64>,
     /// Number of entities to skip.
     pub skip: Option<u64>,
-    /// Ordering specification.
+    /// Ordering specification. Each field maps to an order like `"asc"`, or to an [`OrderSpec`]
+    /// also placing nulls and comparing text case-insensitively or by a collation.
     #[serde(bound = "")]
-    #[schema(value_type = Option<Object>, example = json!({"id": Order::Asc}))]
-    pub order_by: Option<IndexMap<R::ReadModelField, Order>>,
+    #[schema(value_type = Option<Object>, example = json!({"id": "asc"}))]
+    pub order_by: Option<IndexMap<R::ReadModelField, OrderSpec>>,
     /// Filter condition.
     pub condition: Option<Condition>,
 }
@@ -58,8 +61,11 @@ pub struct ReadMany<R: CrudResource> {
 pub async fn read_count<R: CrudResource>(
     request: RequestContext<R::Auth>,
     context: Arc<CrudContext<R>>,
-    body: ReadCount,
+    mut body: ReadCount,
 ) -> Result<u64, CrudError> {
+    resolve_condition::<R>(body.condition.as_mut(), &context.res_context, &request);
+    ensure_valid_condition::<R::ReadModelField>(body.condition.as_ref(), R::MAX_CONDITION_DEPTH)?;
+
     let mut read_request = ReadRequest {
         operation: ReadOperation::Count,
         limit: None,
@@ -78,6 +84,16 @@ pub async fn read_count<R: CrudResource>(
     )
     .await
     .map_err(CrudError::from)?;
+    // The hook may have added placeholders or relative times to the condition.
+    resolve_condition::<R>(
+        read_request.condition.as_mut(),
+        &context.res_context,
+        &request,
+    );
+    ensure_valid_condition::<R::ReadModelField>(
+        read_request.condition.as_ref(),
+        R::MAX_CONDITION_DEPTH,
+    )?;
 
     let count = context
         .repository
@@ -110,8 +126,12 @@ pub async fn read_count<R: CrudResource>(
 pub async fn read_one<R: CrudResource>(
     request: RequestContext<R::Auth>,
     context: Arc<CrudContext<R>>,
-    body: ReadOne<R>,
+    mut body: ReadOne<R>,
 ) -> Result<R::ReadModel, CrudError> {
+    resolve_condition::<R>(body.condition.as_mut(), &context.res_context, &request);
+    ensure_valid_condition::<R::ReadModelField>(body.condition.as_ref(), R::MAX_CONDITION_DEPTH)?;
+    ensure_allowed_collations(body.order_by.as_ref(), R::COLLATIONS)?;
+
     let mut read_request = ReadRequest {
         operation: ReadOperation::One,
         limit: None,
@@ -130,6 +150,16 @@ pub async fn read_one<R: CrudResource>(
     )
     .await
     .map_err(CrudError::from)?;
+    // The hook may have added placeholders or relative times to the condition.
+    resolve_condition::<R>(
+        read_request.condition.as_mut(),
+        &context.res_context,
+        &request,
+    );
+    ensure_valid_condition::<R::ReadModelField>(
+        read_request.condition.as_ref(),
+        R::MAX_CONDITION_DEPTH,
+    )?;
 
     let entity = context
         .repository
@@ -168,8 +198,12 @@ pub async fn read_one<R: CrudResource>(
 pub async fn read_many<R: CrudResource>(
     request: RequestContext<R::Auth>,
     context: Arc<CrudContext<R>>,
-    body: ReadMany<R>,
+    mut body: ReadMany<R>,
 ) -> Result<Vec<R::ReadModel>, CrudError> {
+    resolve_condition::<R>(body.condition.as_mut(), &context.res_context, &request);
+    ensure_valid_condition::<R::ReadModelField>(body.condition.as_ref(), R::MAX_CONDITION_DEPTH)?;
+    ensure_allowed_collations(body.order_by.as_ref(), R::COLLATIONS)?;
+
     let mut read_request = ReadRequest {
         operation: ReadOperation::Many,
         limit: body.limit,
@@ -188,6 +222,16 @@ pub async fn read_many<R: CrudResource>(
     )
     .await
     .map_err(CrudError::from)?;
+    // The hook may have added placeholders or relative times to the condition.
+    resolve_condition::<R>(
+        read_request.condition.as_mut(),
+        &context.res_context,
+        &request,
+    );
+    ensure_valid_condition::<R::ReadModelField>(
+        read_request.condition.as_ref(),
+        R::MAX_CONDITION_DEPTH,
+    )?;
 
     let entities = context
         .repository
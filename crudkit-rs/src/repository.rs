@@ -9,9 +9,11 @@
 use crate::resource::CrudResource;
 use async_trait::async_trait;
 use crudkit_core::condition::Condition;
-use crudkit_core::Order;
-use crudkit_core::id::Id;
-use crudkit_core::validation::{ViolationsByEntity, ViolationsByResource};
+use crudkit_core::OrderSpec;
+use crudkit_core::id::{Id, SerializableId};
+use crudkit_core::validation::{
+    PersistedViolation, ViolationFilter, ViolationsByEntity, ViolationsByResource,
+};
 use indexmap::IndexMap;
 use snafu::Snafu;
 use std::future::Future;
@@ -49,7 +51,7 @@ pub trait Repository<R: CrudResource> {
         &self,
         limit: Option<u64>,
         skip: Option<u64>,
-        order_by: Option<IndexMap<R::ModelField, Order>>,
+        order_by: Option<IndexMap<R::ModelField, OrderSpec>>,
         condition: Option<&Condition>,
     ) -> impl Future<Output = Result<u64, Self::Error>> + Send;
 
@@ -60,7 +62,7 @@ pub trait Repository<R: CrudResource> {
         &self,
         limit: Option<u64>,
         skip: Option<u64>,
-        order_by: Option<IndexMap<R::ModelField, Order>>,
+        order_by: Option<IndexMap<R::ModelField, OrderSpec>>,
         condition: Option<&Condition>,
     ) -> impl Future<Output = Result<Option<R::Model>, Self::Error>> + Send;
 
@@ -69,7 +71,7 @@ pub trait Repository<R: CrudResource> {
         &self,
         limit: Option<u64>,
         skip: Option<u64>,
-        order_by: Option<IndexMap<R::ModelField, Order>>,
+        order_by: Option<IndexMap<R::ModelField, OrderSpec>>,
         condition: Option<&Condition>,
     ) -> impl Future<Output = Result<Vec<R::Model>, Self::Error>> + Send;
 
@@ -80,7 +82,7 @@ pub trait Repository<R: CrudResource> {
         &self,
         limit: Option<u64>,
         skip: Option<u64>,
-        order_by: Option<IndexMap<R::ReadModelField, Order>>,
+        order_by: Option<IndexMap<R::ReadModelField, OrderSpec>>,
         condition: Option<&Condition>,
     ) -> impl Future<Output = Result<Option<R::ReadModel>, Self::Error>> + Send;
 
@@ -89,10 +91,20 @@ pub trait Repository<R: CrudResource> {
         &self,
         limit: Option<u64>,
         skip: Option<u64>,
-        order_by: Option<IndexMap<R::ReadModelField, Order>>,
+        order_by: Option<IndexMap<R::ReadModelField, OrderSpec>>,
         condition: Option<&Condition>,
     ) -> impl Future<Output = Result<Vec<R::ReadModel>, Self::Error>> + Send;
 
+    /// Preview the result of an update without persisting it.
+    ///
+    /// Applies the UpdateModel changes to a copy of the existing Model in memory, the same way
+    /// [`update`](Self::update) would. Used to validate the merged post-update state.
+    fn preview_update(
+        &self,
+        existing: &R::Model,
+        update_model: &R::UpdateModel,
+    ) -> Result<R::Model, Self::Error>;
+
     /// Update an entity using the existing model and update data.
     ///
     /// The repository:
@@ -140,6 +152,14 @@ pub trait ValidationResultRepository {
     /// Delete all violations for the given resource.
     async fn delete_all_of_resource(&self, resource_name: &str) -> Result<(), Self::Error>;
 
+    /// Delete the violations of the given resource type produced by validators not named in
+    /// `validator_names`.
+    async fn delete_all_of_other_validators(
+        &self,
+        resource_name: &str,
+        validator_names: &[String],
+    ) -> Result<(), Self::Error>;
+
     /// Save all violations for the given resource type.
     async fn save_all<I: Id>(
         &self,
@@ -155,6 +175,29 @@ pub trait ValidationResultRepository {
 
     /// List all violations of all resource types.
     async fn list_all(&self) -> Result<ViolationsByResource, Self::Error>;
+
+    /// List the violations of the given resource type selected by `filter`, in a stable order,
+    /// skipping the first `skip` and returning at most `limit` of them.
+    ///
+    /// If `entity_ids` is given, only violations of these entities are listed.
+    async fn list_filtered(
+        &self,
+        resource_name: &str,
+        filter: &ViolationFilter,
+        entity_ids: Option<&[SerializableId]>,
+        limit: Option<u64>,
+        skip: Option<u64>,
+    ) -> Result<Vec<PersistedViolation>, Self::Error>;
+
+    /// Count the violations of the given resource type selected by `filter`.
+    ///
+    /// If `entity_ids` is given, only violations of these entities are counted.
+    async fn count_filtered(
+        &self,
+        resource_name: &str,
+        filter: &ViolationFilter,
+        entity_ids: Option<&[SerializableId]>,
+    ) -> Result<u64, Self::Error>;
 }
 
 /// A no-op validation result repository that discards all validation results.
@@ -184,6 +227,14 @@ impl ValidationResultRepository for NoopValidationResultRepository {
         Ok(())
     }
 
+    async fn delete_all_of_other_validators(
+        &self,
+        _resource_name: &str,
+        _validator_names: &[String],
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
     async fn save_all<I: Id>(
         &self,
         _resource_name: &str,
@@ -202,4 +253,24 @@ impl ValidationResultRepository for NoopValidationResultRepository {
     async fn list_all(&self) -> Result<ViolationsByResource, Self::Error> {
         Ok(ViolationsByResource::new())
     }
+
+    async fn list_filtered(
+        &self,
+        _resource_name: &str,
+        _filter: &ViolationFilter,
+        _entity_ids: Option<&[SerializableId]>,
+        _limit: Option<u64>,
+        _skip: Option<u64>,
+    ) -> Result<Vec<PersistedViolation>, Self::Error> {
+        Ok(Vec::new())
+    }
+
+    async fn count_filtered(
+        &self,
+        _resource_name: &str,
+        _filter: &ViolationFilter,
+        _entity_ids: Option<&[SerializableId]>,
+    ) -> Result<u64, Self::Error> {
+        Ok(0)
+    }
 }
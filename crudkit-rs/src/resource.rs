@@ -8,6 +8,7 @@ use crate::{
     auth::{AuthExtractor, CrudAuthPolicy},
     data::{ConditionValueConverter, Field, FieldLookup, HasId, Model},
     lifetime::CrudLifetime,
+    placeholder::PlaceholderResolver,
     prelude::*,
 };
 
@@ -53,7 +54,7 @@ pub trait CrudResourceContext {}
 /// are defined in terms of models and fields rather than ORM-specific types.
 /// The storage adapter (e.g., crudkit-sea-orm) handles the conversion between
 /// these abstract models and the underlying storage mechanism.
-pub trait CrudResource: Sized + Debug {
+pub trait CrudResource: Sized + Debug + 'static {
     // =========================================================================
     // Read Model (for querying entities).
     // =========================================================================
@@ -160,13 +161,13 @@ pub trait CrudResource: Sized + Debug {
     // =========================================================================
 
     /// The repository implementation for this resource.
-    type Repository: Repository<Self>;
+    type Repository: Repository<Self> + Send + Sync + 'static;
 
     /// The service for persisting and retrieving validation results.
-    type ValidationResultRepository: ValidationResultRepository;
+    type ValidationResultRepository: ValidationResultRepository + Send + Sync + 'static;
 
     /// Service for collaboration (e.g., WebSocket broadcasting).
-    type CollaborationService: CollaborationService + 'static;
+    type CollaborationService: CollaborationService + Send + Sync + 'static;
 
     /// Resource-specific context made available in lifecycle operations.
     type Context: CrudResourceContext + Send + Sync + 'static;
@@ -193,11 +194,34 @@ pub trait CrudResource: Sized + Debug {
     /// Defines which operations require authentication.
     type AuthPolicy: CrudAuthPolicy;
 
+    /// Resolves placeholders like `$tenant_id` in conditions of requests.
+    ///
+    /// Use `NoPlaceholders` if conditions should not use placeholders.
+    type PlaceholderResolver: PlaceholderResolver<Self>;
+
     /// The resource type identifier.
     type ResourceType: ResourceType;
 
     /// The constant identifying this resource type.
     const TYPE: Self::ResourceType;
+
+    /// Maximum nesting depth of conditions accepted in requests, the root condition having
+    /// depth 1. Deeper conditions are rejected with [`CrudError::InvalidCondition`].
+    ///
+    /// [`CrudError::InvalidCondition`]: crate::error::CrudError::InvalidCondition
+    const MAX_CONDITION_DEPTH: usize = 8;
+
+    /// Whether the dry-run `validate_create` and `validate_update` operations run the
+    /// `before_create` and `before_update` hooks first, as creating and updating would.
+    ///
+    /// Disable this if the hooks have side effects.
+    const DRY_RUN_HOOKS: bool = true;
+
+    /// Collations text fields may be ordered by in requests, e.g. `de-DE-x-icu`. Orderings by any
+    /// other collation are rejected with [`CrudError::InvalidOrderBy`].
+    ///
+    /// [`CrudError::InvalidOrderBy`]: crate::error::CrudError::InvalidOrderBy
+    const COLLATIONS: &'static [&'static str] = &[];
 }
 
 /// Trait for resource type identifiers.
@@ -0,0 +1,319 @@
+//! Revalidation of all persisted entities of a resource.
+//!
+//! Persisted violations are only replaced when an entity is validated again. After deploying a
+//! new validator version, a revalidation replaces the stale results of all entities at once.
+//! Results of validators no longer registered are deleted. Spawn it on startup or on demand with
+//! [`spawn_revalidation`]. The `revalidate` route uses [`start_revalidation`], which is forbidden
+//! unless the resource's [`CrudLifetime::before_revalidate`] hook allows it.
+
+use crate::auth::RequestContext;
+use crate::context::CrudContext;
+use crate::data::{FieldLookup, HasId};
+use crate::error::CrudError;
+use crate::lifetime::CrudLifetime;
+use crate::prelude::{CrudResource, ResourceType, ValidationTrigger};
+use crate::repository::{Repository, ValidationResultRepository};
+use crate::validate::{
+    broadcast_persisted_violations, run_global_validation, run_model_validation, validator_names,
+};
+use crudkit_core::OrderSpec;
+use crudkit_core::condition::{Condition, ConditionClauseValue};
+use crudkit_core::id::{Id, IdField};
+use crudkit_core::validation::ViolationsByEntity;
+use indexmap::IndexMap;
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use utoipa::ToSchema;
+
+/// Number of entities fetched and validated at once.
+pub const REVALIDATION_BATCH_SIZE: u64 = 500;
+
+/// Status of a revalidation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+pub enum RevalidationStatus {
+    Running,
+    Finished,
+    Failed,
+}
+
+/// Progress of a revalidation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+pub struct RevalidationProgress {
+    pub status: RevalidationStatus,
+    /// Number of entities validated so far.
+    pub validated: u64,
+    /// Number of entities counted when the revalidation started.
+    pub total: u64,
+}
+
+/// State of the revalidation of one resource.
+/// Ensures only one revalidation runs at a time and keeps the progress of the latest one.
+#[derive(Debug, Default)]
+pub struct RevalidationState {
+    running: AtomicBool,
+    progress: Mutex<Option<RevalidationProgress>>,
+}
+
+impl RevalidationState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether a revalidation is currently running.
+    pub fn is_running(&self) -> bool {
+        self.running.load(Ordering::Acquire)
+    }
+
+    /// The progress of the running or latest revalidation. `None` if none was started yet.
+    pub fn progress(&self) -> Option<RevalidationProgress> {
+        *self.progress.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn set_progress(&self, progress: RevalidationProgress) {
+        *self.progress.lock().unwrap_or_else(PoisonError::into_inner) = Some(progress);
+    }
+}
+
+/// Ends the revalidation when dropped, even if it panicked. A revalidation still reported as
+/// running is marked as failed.
+struct FinishOnDrop<'a>(&'a RevalidationState);
+
+impl Drop for FinishOnDrop<'_> {
+    fn drop(&mut self) {
+        if let Some(progress) = self
+            .0
+            .progress
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_mut()
+            .filter(|progress| progress.status == RevalidationStatus::Running)
+        {
+            progress.status = RevalidationStatus::Failed;
+        }
+        self.0.running.store(false, Ordering::Release);
+    }
+}
+
+/// Revalidate all entities of the resource in the background, if the resource's
+/// [`CrudLifetime::before_revalidate`] hook allows the request to.
+///
+/// Returns `false`, without starting anything, if a revalidation is already running.
+pub async fn start_revalidation<R: CrudResource>(
+    request: RequestContext<R::Auth>,
+    context: &Arc<CrudContext<R>>,
+) -> Result<bool, CrudError> {
+    R::Lifetime::before_revalidate(&context.res_context, request)
+        .await
+        .map_err(CrudError::from)?;
+    Ok(spawn_revalidation(context))
+}
+
+/// Revalidate all entities of the resource in the background.
+///
+/// Returns `false`, without starting anything, if a revalidation is already running.
+/// Track the progress through [`RevalidationState::progress`] of the context.
+///
+/// Not subject to any hook. Use [`start_revalidation`] for revalidations requested by users.
+pub fn spawn_revalidation<R: CrudResource>(context: &Arc<CrudContext<R>>) -> bool {
+    if context
+        .revalidation_state
+        .running
+        .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+        .is_err()
+    {
+        return false;
+    }
+
+    let context = context.clone();
+    tokio::spawn(async move {
+        let _finish = FinishOnDrop(&context.revalidation_state);
+        let resource_name = R::TYPE.name();
+        let mut progress = RevalidationProgress {
+            status: RevalidationStatus::Running,
+            validated: 0,
+            total: 0,
+        };
+        context.revalidation_state.set_progress(progress);
+
+        match revalidate_all(&context, &mut progress).await {
+            Ok(()) => {
+                progress.status = RevalidationStatus::Finished;
+                tracing::info!(
+                    "Revalidated {} entities of resource '{resource_name}'.",
+                    progress.validated
+                );
+                // Let aggregate validators replace their results as well.
+                run_global_validation(&context);
+            }
+            Err(err) => {
+                progress.status = RevalidationStatus::Failed;
+                tracing::error!(
+                    "Revalidation of resource '{resource_name}' failed after {} entities: {err:?}",
+                    progress.validated
+                );
+            }
+        }
+        context.revalidation_state.set_progress(progress);
+    });
+
+    true
+}
+
+/// Validate all entities in batches, replacing their persisted results, and delete the results of
+/// validators no longer registered.
+async fn revalidate_all<R: CrudResource>(
+    context: &CrudContext<R>,
+    progress: &mut RevalidationProgress,
+) -> Result<(), CrudError> {
+    let resource_name = R::TYPE.name();
+    let repository = &context.repository;
+
+    progress.total = repository
+        .count(None, None, None, None)
+        .await
+        .map_err(|err| CrudError::Repository {
+            reason: Arc::new(err),
+        })?;
+    context.revalidation_state.set_progress(*progress);
+
+    context
+        .validation_result_repository
+        .delete_all_of_other_validators(resource_name, &validator_names(context))
+        .await
+        .map_err(|err| CrudError::DeleteValidations {
+            reason: Arc::new(err),
+        })?;
+
+    // Batches are ordered by id and continue after the last id of the previous batch, so that
+    // they cover every entity exactly once, even while entities are created or deleted.
+    let Some(first) = repository
+        .fetch_one(None, None, None, None)
+        .await
+        .map_err(|err| CrudError::Repository {
+            reason: Arc::new(err),
+        })?
+    else {
+        return Ok(());
+    };
+    let order_by = first
+        .id()
+        .fields_iter()
+        .map(|field| {
+            R::ModelField::from_name(field.name())
+                .map(|model_field| (model_field, OrderSpec::asc()))
+                .ok_or_else(|| CrudError::Revalidation {
+                    reason: format!("ID field '{}' is not a field of the model.", field.name()),
+                })
+        })
+        .collect::<Result<IndexMap<R::ModelField, OrderSpec>, _>>()?;
+
+    let request = RequestContext::unauthenticated();
+    let mut validated_ids = Vec::new();
+    let mut after_last: Option<Condition> = None;
+    loop {
+        let models = repository
+            .fetch_many(
+                Some(REVALIDATION_BATCH_SIZE),
+                None,
+                Some(order_by.clone()),
+                after_last.as_ref(),
+            )
+            .await
+            .map_err(|err| CrudError::Repository {
+                reason: Arc::new(err),
+            })?;
+        let batch_len = models.len() as u64;
+        if let Some(last) = models.last() {
+            after_last = Some(after_id(&last.id())?);
+        }
+
+        let mut results = ViolationsByEntity::<R::Id>::new();
+        for model in models {
+            // Validators without violations are kept as empty results, clearing stale ones.
+            let violations_by_validator = run_model_validation::<R>(
+                context,
+                &request,
+                &model,
+                ValidationTrigger::Revalidation,
+            )
+            .await;
+            results.map.insert(model.id(), violations_by_validator);
+        }
+        validated_ids.extend(results.map.keys().cloned());
+
+        context
+            .validation_result_repository
+            .save_all(resource_name, results)
+            .await
+            .map_err(|err| CrudError::SaveValidations {
+                reason: Arc::new(err),
+            })?;
+
+        progress.validated += batch_len;
+        context.revalidation_state.set_progress(*progress);
+
+        if batch_len < REVALIDATION_BATCH_SIZE {
+            break;
+        }
+    }
+
+    broadcast_persisted_violations(context, validated_ids).await;
+    Ok(())
+}
+
+/// Selects the entities with an id ordered after `id`, comparing the id fields in order.
+fn after_id<I: Id>(id: &I) -> Result<Condition, CrudError> {
+    let mut after = Condition::any();
+    let mut equal_fields = Condition::all();
+    for field in id.fields_iter() {
+        let value = ConditionClauseValue::try_from(field.to_value()).map_err(|err| {
+            CrudError::Revalidation {
+                reason: err.to_string(),
+            }
+        })?;
+        after.push_condition(equal_fields.clone().gt(field.name(), value.clone()));
+        equal_fields = equal_fields.eq(field.name(), value);
+    }
+    Ok(after)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crudkit_core::id::{IdValue, SerializableId};
+
+    #[test]
+    fn after_id_compares_id_fields_in_order() {
+        let id = SerializableId(vec![
+            ("tenant_id".to_owned(), IdValue::I64(1)).into(),
+            ("id".to_owned(), IdValue::I64(7)).into(),
+        ]);
+
+        let condition = after_id(&id).expect("convertible id");
+
+        let mut expected = Condition::any();
+        expected.push_condition(Condition::all().gt("tenant_id", 1i64));
+        expected.push_condition(Condition::all().eq("tenant_id", 1i64).gt("id", 7i64));
+        assert_eq!(condition, expected);
+    }
+
+    #[test]
+    fn dropped_revalidation_is_no_longer_running_and_marked_failed() {
+        let state = RevalidationState::new();
+        state.running.store(true, Ordering::Release);
+        state.set_progress(RevalidationProgress {
+            status: RevalidationStatus::Running,
+            validated: 3,
+            total: 10,
+        });
+
+        drop(FinishOnDrop(&state));
+
+        assert!(!state.is_running());
+        assert_eq!(
+            state.progress().map(|progress| progress.status),
+            Some(RevalidationStatus::Failed)
+        );
+    }
+}
@@ -18,6 +18,7 @@ use crate::validation::{CrudAction, ValidationContext, ValidationTrigger, When};
 use crate::{
     auth::RequestContext,
     collaboration,
+    condition_check::{ensure_valid_condition, resolve_condition},
     error::CrudError,
     lifetime::{CrudLifetime, UpdateRequest},
     prelude::*,
@@ -38,7 +39,7 @@ pub struct UpdateOne<T> {
 ///
 /// 1. Fetch the existing entity matching the condition
 /// 2. Run `before_update` hook (can modify the update model)
-/// 3. Run delta validation (comparing old and new state)
+/// 3. Run delta validation (comparing old, update and merged post-update state)
 /// 4. If critical violations exist, return error
 /// 5. Update entity via repository (repository applies changes internally)
 /// 6. Delete old validation results
@@ -51,8 +52,11 @@ pub struct UpdateOne<T> {
 pub async fn update_one<R: CrudResource>(
     request: RequestContext<R::Auth>,
     context: Arc<CrudContext<R>>,
-    body: UpdateOne<R::UpdateModel>,
+    mut body: UpdateOne<R::UpdateModel>,
 ) -> Result<Saved<R::Model>, CrudError> {
+    resolve_condition::<R>(body.condition.as_mut(), &context.res_context, &request);
+    ensure_valid_condition::<R::ModelField>(body.condition.as_ref(), R::MAX_CONDITION_DEPTH)?;
+
     // Fetch the existing entity.
     let existing_model = context
         .repository
@@ -90,14 +94,29 @@ pub async fn update_one<R: CrudResource>(
     let entity_id = existing_model.id();
     let serializable_id = entity_id.to_serializable_id();
 
+    // Preview the merged post-update state, letting validators check the resulting entity.
+    let updated_model = context
+        .repository
+        .preview_update(&existing_model, &update_model)
+        .map_err(|err| CrudError::Repository {
+            reason: Arc::new(err),
+        })?;
+
     // Run delta validations comparing old and new state before updating.
     let trigger = ValidationTrigger::CrudAction(ValidationContext {
         action: CrudAction::Update,
         when: When::Before,
     });
 
-    let mut partial_validation_results =
-        run_delta_validation::<R>(&context.validators, &old_model, &update_model, trigger);
+    let mut partial_validation_results = run_delta_validation::<R>(
+        &context,
+        &request,
+        &old_model,
+        &update_model,
+        &updated_model,
+        trigger,
+    )
+    .await;
 
     // Critical violations must block the save immediately.
     if partial_validation_results.has_critical_violations() {
@@ -132,6 +151,8 @@ pub async fn update_one<R: CrudResource>(
         })?;
 
     let has_violations = partial_validation_results.has_violations();
+    let has_errors = partial_validation_results.has_errors();
+    let has_infos = partial_validation_results.has_infos();
 
     if has_violations {
         // Persist the validation results for later access/use.
@@ -178,13 +199,91 @@ pub async fn update_one<R: CrudResource>(
     .map_err(CrudError::from)?;
 
     // Inform all users that the entity was updated.
-    collaboration::broadcast_updated_event(&context, serializable_id, has_violations).await;
+    collaboration::broadcast_updated_event(&context, serializable_id, has_errors, has_infos).await;
 
     // Trigger global validation to check system-wide consistency.
-    run_global_validation::<R>(&context).await;
+    run_global_validation::<R>(&context);
 
     Ok(Saved {
         entity: result,
         violations: partial,
     })
 }
+
+/// Request body for validating an update of a single entity, without updating it.
+#[derive(Debug, ToSchema, Deserialize)]
+pub struct ValidateUpdate<T> {
+    /// Condition to identify the entity to validate the update of.
+    pub condition: Option<Condition>,
+    /// The update data to validate.
+    pub entity: T,
+}
+
+/// Validate an update of a single entity without updating it (dry-run).
+///
+/// Runs all entity validators as updating would, preceded by the `before_update` hook unless
+/// [`CrudResource::DRY_RUN_HOOKS`] is disabled. Nothing is persisted or broadcast. Critical
+/// violations are returned like any other violation instead of failing the request.
+#[tracing::instrument(level = "info", skip(context, request))]
+pub async fn validate_update<R: CrudResource>(
+    request: RequestContext<R::Auth>,
+    context: Arc<CrudContext<R>>,
+    mut body: ValidateUpdate<R::UpdateModel>,
+) -> Result<PartialSerializableAggregateViolations, CrudError> {
+    resolve_condition::<R>(body.condition.as_mut(), &context.res_context, &request);
+    ensure_valid_condition::<R::ModelField>(body.condition.as_ref(), R::MAX_CONDITION_DEPTH)?;
+
+    let existing_model = context
+        .repository
+        .fetch_one(None, None, None, body.condition.as_ref())
+        .await
+        .map_err(|err| CrudError::Repository {
+            reason: Arc::new(err),
+        })?
+        .ok_or(CrudError::NotFound)?;
+
+    let mut update_model = body.entity;
+
+    if R::DRY_RUN_HOOKS {
+        let update_request = UpdateRequest {
+            condition: body.condition,
+        };
+        R::Lifetime::before_update(
+            &existing_model,
+            &mut update_model,
+            &update_request,
+            &context.res_context,
+            request.clone(),
+            R::HookData::default(),
+        )
+        .await
+        .map_err(CrudError::from)?;
+    }
+
+    let updated_model = context
+        .repository
+        .preview_update(&existing_model, &update_model)
+        .map_err(|err| CrudError::Repository {
+            reason: Arc::new(err),
+        })?;
+
+    let trigger = ValidationTrigger::CrudAction(ValidationContext {
+        action: CrudAction::Update,
+        when: When::Before,
+    });
+
+    let violations_by_validator = run_delta_validation::<R>(
+        &context,
+        &request,
+        &existing_model,
+        &update_model,
+        &updated_model,
+        trigger,
+    )
+    .await;
+
+    Ok(PartialSerializableAggregateViolations::from(
+        violations_by_validator,
+        Some(existing_model.id().to_serializable_id()),
+    ))
+}
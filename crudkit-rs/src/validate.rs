@@ -2,61 +2,214 @@
 //!
 //! These functions run entity validation using registered validators.
 
+use crate::auth::RequestContext;
+use crate::collaboration;
 use crate::context::CrudContext;
-use crate::prelude::{CrudResource, ValidationTrigger};
-use crate::validator::EntityValidator;
+use crate::prelude::{CrudResource, ResourceType, ValidationTrigger};
+use crate::repository::ValidationResultRepository;
+use crate::validation::{CrudAction, ValidationContext};
+use crate::validator::{
+    ASYNC_VALIDATION_BUDGET, AsyncEntityValidator, EntityValidator, FieldAttributeValidator,
+};
+use crudkit_core::id::{Id, SerializableId};
+use crudkit_core::resource::ResourceName;
 use crudkit_core::validation::validator::ValidatorInfo;
-use crudkit_core::validation::ViolationsByValidator;
+use crudkit_core::validation::violation::{Violation, Violations};
+use crudkit_core::validation::{
+    ResourceViolations, ViolationsByEntity, ViolationsByResource, ViolationsByValidator,
+    into_serializable_validations,
+};
+use futures::future::join_all;
 use std::sync::atomic::{AtomicU8, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Runs the async `validations` concurrently. Each is bounded by its validator's timeout and all of
+/// them together by [`ASYNC_VALIDATION_BUDGET`].
+///
+/// A validator not finishing in time is abandoned. When validating a created or updated entity, it
+/// reports a violation of its [`timeout_severity`](AsyncEntityValidator::timeout_severity), so
+/// that the entity does not pass unchecked. Otherwise it is left out of the result, so that its
+/// persisted results are kept.
+async fn run_async_validations<'a, R: CrudResource>(
+    violations_by_validator: &mut ViolationsByValidator,
+    trigger: ValidationTrigger,
+    validations: impl Iterator<
+        Item = (
+            &'a Arc<dyn AsyncEntityValidator<R>>,
+            impl Future<Output = Violations>,
+        ),
+    >,
+) {
+    let deadline = Instant::now() + ASYNC_VALIDATION_BUDGET;
+    let results = join_all(validations.map(|(validator, validation)| async move {
+        let timeout_at = Instant::now()
+            .checked_add(validator.timeout())
+            .map_or(deadline, |timeout_at| timeout_at.min(deadline));
+        let result = tokio::time::timeout_at(timeout_at, validation).await;
+        (validator, result)
+    }))
+    .await;
+
+    for (validator, result) in results {
+        match result {
+            Ok(violations) => violations_by_validator.extend(
+                ValidatorInfo::new(validator.name(), validator.version()),
+                violations,
+            ),
+            Err(_elapsed) if reports_timeouts(trigger) => {
+                tracing::warn!("Validator '{}' did not finish in time.", validator.name());
+                let mut violations = Violations::empty();
+                violations.push(
+                    Violation::new(
+                        validator.timeout_severity(),
+                        "Could not be validated in time.",
+                    )
+                    .with_code("validation_timeout")
+                    .with_param("validator", validator.name().into_owned()),
+                );
+                violations_by_validator.extend(
+                    ValidatorInfo::new(validator.name(), validator.version()),
+                    violations,
+                );
+            }
+            Err(_elapsed) => tracing::warn!(
+                "Validator '{}' did not finish in time and was abandoned. Its persisted results are kept.",
+                validator.name()
+            ),
+        }
+    }
+}
+
+/// Whether async validators not finishing in time are reported as violations: only when validating
+/// a created or updated entity.
+fn reports_timeouts(trigger: ValidationTrigger) -> bool {
+    matches!(
+        trigger,
+        ValidationTrigger::CrudAction(ValidationContext {
+            action: CrudAction::Create | CrudAction::Update,
+            ..
+        })
+    )
+}
+
+/// The entity validators of `context`, preceded by the implicit [`FieldAttributeValidator`].
+fn entity_validators<R: CrudResource>(
+    context: &CrudContext<R>,
+) -> impl Iterator<Item = &dyn EntityValidator<R>> {
+    std::iter::once(&FieldAttributeValidator as &dyn EntityValidator<R>).chain(
+        context
+            .validators
+            .iter()
+            .map(|validator| validator.as_ref()),
+    )
+}
+
+/// The names of all validators of `context`, including the implicit and the aggregate validators.
+pub(crate) fn validator_names<R: CrudResource>(context: &CrudContext<R>) -> Vec<String> {
+    entity_validators(context)
+        .map(|validator| validator.name().into_owned())
+        .chain(
+            context
+                .async_validators
+                .iter()
+                .map(|validator| validator.name().into_owned()),
+        )
+        .chain(
+            context
+                .resource_validators
+                .iter()
+                .map(|validator| validator.name().to_owned()),
+        )
+        .collect()
+}
 
 /// Run entity validation on a CreateModel using all registered validators.
-pub fn run_entity_validation<R: CrudResource>(
-    validators: &[Arc<dyn EntityValidator<R>>],
+pub async fn run_entity_validation<R: CrudResource>(
+    context: &CrudContext<R>,
+    request: &RequestContext<R::Auth>,
     create_model: &R::CreateModel,
     trigger: ValidationTrigger,
 ) -> ViolationsByValidator {
     let mut violations_by_validator = ViolationsByValidator::new();
-    for validator in validators {
+    for validator in entity_validators(context) {
         violations_by_validator.extend(
             ValidatorInfo::new(validator.name(), validator.version()),
             validator.validate_create(create_model, trigger),
         );
     }
+    run_async_validations(
+        &mut violations_by_validator,
+        trigger,
+        context.async_validators.iter().map(|validator| {
+            let validation = validator.validate_create(create_model, context, request, trigger);
+            (validator, validation)
+        }),
+    )
+    .await;
     violations_by_validator
 }
 
 /// Run entity validation on a Model using all registered validators.
-pub fn run_model_validation<R: CrudResource>(
-    validators: &[Arc<dyn EntityValidator<R>>],
+pub async fn run_model_validation<R: CrudResource>(
+    context: &CrudContext<R>,
+    request: &RequestContext<R::Auth>,
     model: &R::Model,
     trigger: ValidationTrigger,
 ) -> ViolationsByValidator {
     let mut violations_by_validator = ViolationsByValidator::new();
-    for validator in validators {
+    for validator in entity_validators(context) {
         violations_by_validator.extend(
             ValidatorInfo::new(validator.name(), validator.version()),
             validator.validate_model(model, trigger),
         );
     }
+    run_async_validations(
+        &mut violations_by_validator,
+        trigger,
+        context.async_validators.iter().map(|validator| {
+            let validation = validator.validate_model(model, context, request, trigger);
+            (validator, validation)
+        }),
+    )
+    .await;
     violations_by_validator
 }
 
 /// Run delta validation using all registered validators.
-/// Compares old Model state with new UpdateModel to detect violations.
-pub fn run_delta_validation<R: CrudResource>(
-    validators: &[Arc<dyn EntityValidator<R>>],
+/// Validators see the old Model, the UpdateModel and the merged post-update Model.
+pub async fn run_delta_validation<R: CrudResource>(
+    context: &CrudContext<R>,
+    request: &RequestContext<R::Auth>,
     old_model: &R::Model,
     update_model: &R::UpdateModel,
+    updated_model: &R::Model,
     trigger: ValidationTrigger,
 ) -> ViolationsByValidator {
     let mut violations_by_validator = ViolationsByValidator::new();
-    for validator in validators {
+    for validator in entity_validators(context) {
         violations_by_validator.extend(
             ValidatorInfo::new(validator.name(), validator.version()),
-            validator.validate_updated(old_model, update_model, trigger),
+            validator.validate_updated(old_model, update_model, updated_model, trigger),
         );
     }
+    run_async_validations(
+        &mut violations_by_validator,
+        trigger,
+        context.async_validators.iter().map(|validator| {
+            let validation = validator.validate_updated(
+                old_model,
+                update_model,
+                updated_model,
+                context,
+                request,
+                trigger,
+            );
+            (validator, validation)
+        }),
+    )
+    .await;
     violations_by_validator
 }
 
@@ -86,12 +239,16 @@ impl GlobalValidationState {
     }
 }
 
-/// Run global validation asynchronously with debounce-like behavior.
+/// Time waited before a global validation run, letting bursts of changes coalesce into one run.
+pub const GLOBAL_VALIDATION_DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Run global validation in the background with debounce-like behavior.
 ///
 /// This function ensures efficient validation by:
+/// - Running all aggregate validators on a spawned task, off the request path
 /// - If validation is already running and another request comes in, it schedules one follow-up run
 /// - If a run is already scheduled, additional requests are ignored
-pub async fn run_global_validation<R: CrudResource>(context: &CrudContext<R>) {
+pub fn run_global_validation<R: CrudResource>(context: &Arc<CrudContext<R>>) {
     // Try to transition from IDLE to RUNNING.
     match context.global_validation_state.state.compare_exchange(
         VALIDATION_IDLE,
@@ -119,25 +276,186 @@ pub async fn run_global_validation<R: CrudResource>(context: &CrudContext<R>) {
     }
 
     // We now own the "running" state.
-    loop {
-        // Run validation (currently a placeholder).
-        // TODO: Implement actual aggregate validation here.
-
-        // Try to transition back to IDLE.
-        match context.global_validation_state.state.compare_exchange(
-            VALIDATION_RUNNING,
-            VALIDATION_IDLE,
-            Ordering::AcqRel,
-            Ordering::Acquire,
-        ) {
-            Ok(_) => break,
-            Err(current) => {
-                debug_assert_eq!(current, VALIDATION_RUNNING_WITH_PENDING);
-                context
-                    .global_validation_state
-                    .state
-                    .store(VALIDATION_RUNNING, Ordering::Release);
+    let context = context.clone();
+    tokio::spawn(async move {
+        let guard = ResetToIdleOnDrop(&context.global_validation_state.state);
+        loop {
+            tokio::time::sleep(GLOBAL_VALIDATION_DEBOUNCE).await;
+
+            run_aggregate_validation(&context).await;
+
+            // Try to transition back to IDLE.
+            match context.global_validation_state.state.compare_exchange(
+                VALIDATION_RUNNING,
+                VALIDATION_IDLE,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => break,
+                Err(current) => {
+                    debug_assert_eq!(current, VALIDATION_RUNNING_WITH_PENDING);
+                    context
+                        .global_validation_state
+                        .state
+                        .store(VALIDATION_RUNNING, Ordering::Release);
+                }
             }
         }
+        // Back in IDLE. A new run may already own the state, which must not be reset.
+        std::mem::forget(guard);
+    });
+}
+
+/// Resets the global validation state to IDLE when a run ends without doing so itself,
+/// e.g. when a validator panics. Otherwise, no global validation would ever run again.
+struct ResetToIdleOnDrop<'a>(&'a AtomicU8);
+
+impl Drop for ResetToIdleOnDrop<'_> {
+    fn drop(&mut self) {
+        self.0.store(VALIDATION_IDLE, Ordering::Release);
+    }
+}
+
+/// Run all aggregate validators, replace their persisted results and broadcast the full result.
+async fn run_aggregate_validation<R: CrudResource>(context: &CrudContext<R>) {
+    if context.resource_validators.is_empty() {
+        return;
+    }
+    let resource_name = R::TYPE.name();
+
+    let mut results = ViolationsByEntity::<R::Id>::new();
+    for validator in &context.resource_validators {
+        for (entity_id, violations_by_validator) in validator.validate_resource().await.map {
+            let entity_violations = results.map.entry(entity_id).or_default();
+            for (validator_info, violations) in violations_by_validator.violations_by_validator {
+                entity_violations.extend(validator_info, violations);
+            }
+        }
+    }
+
+    // Entity validators, including the implicit field attribute validator, share the persisted
+    // results. Only clear the entities previously flagged by an aggregate validator, by saving an
+    // empty result in their place.
+    let repository = &context.validation_result_repository;
+    let persisted = match repository
+        .list_all_of_resource::<R::Id>(resource_name)
+        .await
+    {
+        Ok(persisted) => persisted,
+        Err(err) => {
+            tracing::error!("Failed to list violations of resource '{resource_name}': {err:?}");
+            return;
+        }
+    };
+    for (entity_id, violations_by_validator) in persisted.map {
+        for validator_info in violations_by_validator.violations_by_validator.into_keys() {
+            let is_aggregate = context
+                .resource_validators
+                .iter()
+                .any(|validator| validator.name() == validator_info.validator_name.as_ref());
+            if is_aggregate {
+                results
+                    .map
+                    .entry(entity_id.clone())
+                    .or_default()
+                    .extend(validator_info, Violations::empty());
+            }
+        }
+    }
+
+    let validated_ids: Vec<R::Id> = results.map.keys().cloned().collect();
+    if let Err(err) = repository.save_all(resource_name, results).await {
+        tracing::error!("Failed to save violations of resource '{resource_name}': {err:?}");
+        return;
+    }
+
+    broadcast_persisted_violations(context, validated_ids).await;
+}
+
+/// Broadcast everything persisted for the resource, including results of all validators.
+///
+/// The `validated_ids` are included even without remaining violations, so that receivers clear them.
+pub(crate) async fn broadcast_persisted_violations<R: CrudResource>(
+    context: &CrudContext<R>,
+    validated_ids: Vec<R::Id>,
+) {
+    let resource_name = R::TYPE.name();
+    let persisted = match context
+        .validation_result_repository
+        .list_all_of_resource::<R::Id>(resource_name)
+        .await
+    {
+        Ok(persisted) => persisted,
+        Err(err) => {
+            tracing::error!("Failed to list violations of resource '{resource_name}': {err:?}");
+            return;
+        }
+    };
+    let mut resource_violations = ResourceViolations::<SerializableId>::new();
+    for entity_id in validated_ids {
+        resource_violations
+            .by_entity
+            .map
+            .insert(entity_id.to_serializable_id(), ViolationsByValidator::new());
+    }
+    for (entity_id, violations_by_validator) in persisted.map {
+        resource_violations
+            .by_entity
+            .map
+            .insert(entity_id.to_serializable_id(), violations_by_validator);
+    }
+    let mut violations_by_resource = ViolationsByResource::new();
+    violations_by_resource
+        .map
+        .insert(ResourceName::from(resource_name), resource_violations);
+
+    collaboration::broadcast_full_validation_result(
+        context,
+        into_serializable_validations(violations_by_resource),
+    )
+    .await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::When;
+
+    #[test]
+    fn panicking_run_resets_the_global_validation_state() {
+        let state = AtomicU8::new(VALIDATION_RUNNING_WITH_PENDING);
+
+        let result = std::panic::catch_unwind(|| {
+            let _guard = ResetToIdleOnDrop(&state);
+            panic!("validator panicked");
+        });
+
+        assert!(result.is_err());
+        assert_eq!(state.load(Ordering::Acquire), VALIDATION_IDLE);
+    }
+
+    #[test]
+    fn finished_run_leaves_the_global_validation_state_untouched() {
+        let state = AtomicU8::new(VALIDATION_RUNNING);
+
+        std::mem::forget(ResetToIdleOnDrop(&state));
+
+        assert_eq!(state.load(Ordering::Acquire), VALIDATION_RUNNING);
+    }
+
+    #[test]
+    fn timeouts_are_reported_for_created_and_updated_entities_only() {
+        let before = |action| {
+            ValidationTrigger::CrudAction(ValidationContext {
+                action,
+                when: When::Before,
+            })
+        };
+
+        assert!(reports_timeouts(before(CrudAction::Create)));
+        assert!(reports_timeouts(before(CrudAction::Update)));
+        assert!(!reports_timeouts(before(CrudAction::Delete)));
+        assert!(!reports_timeouts(ValidationTrigger::Revalidation));
+        assert!(!reports_timeouts(ValidationTrigger::GlobalValidation));
     }
 }
@@ -1,14 +1,26 @@
 //! Entity and aggregate validators for CRUD resources.
 //!
-//! Validators check entities for business rule violations. There are two types:
+//! Validators check entities for business rule violations. There are three types:
 //! - `EntityValidator`: Validates individual entities or entity changes
+//! - `AsyncEntityValidator`: Like `EntityValidator`, but async and with access to the context
 //! - `AggregateValidator`: Validates aggregate-level constraints across all entities
 
+use crate::auth::RequestContext;
+use crate::context::CrudContext;
+use crate::data::Model;
 use crate::prelude::{CrudResource, ValidationTrigger};
 use async_trait::async_trait;
-use crudkit_core::validation::violation::Violations;
+use crudkit_core::validation::violation::{Severity, Violations};
 use crudkit_core::validation::ViolationsByEntity;
 use std::borrow::Cow;
+use std::time::Duration;
+
+/// How long an [`AsyncEntityValidator`] may take, unless it overrides its `timeout`.
+pub const DEFAULT_VALIDATOR_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long all [`AsyncEntityValidator`]s of one validation may take together. They run
+/// concurrently.
+pub const ASYNC_VALIDATION_BUDGET: Duration = Duration::from_secs(10);
 
 /// Trait for validators that check a single entity or entity change.
 ///
@@ -19,7 +31,8 @@ use std::borrow::Cow;
 ///
 /// - `validate_create`: Validates a CreateModel before insertion
 /// - `validate_model`: Validates a Model (after insert, or existing entity)
-/// - `validate_updated`: Validates an update by comparing old Model with new UpdateModel
+/// - `validate_updated`: Validates an update, given the old Model, the UpdateModel and the
+///   merged post-update Model. Defaults to `validate_model` on the merged Model
 ///
 /// # Example
 ///
@@ -76,21 +89,154 @@ pub trait EntityValidator<R: CrudResource>: Send + Sync {
         Violations::empty()
     }
 
-    /// Validate an update by comparing the old Model with the UpdateModel.
-    ///
-    /// Override this to implement delta validation that considers the change being made.
-    /// By default, this returns no violations.
+    /// Validate an update, given the old Model, the UpdateModel and the merged post-update Model.
     ///
-    // TODO: Is it ok to take UpdateModel? Previously, we would have defaulted to only validating the new state (using validate_model).
-    //  But as we dont get the updated model, but only the update dto, this is no longer possible. Should we change this?
+    /// The merged Model is a preview of the entity after the update, not yet persisted.
+    /// By default, this validates the merged Model using `validate_model`, letting one rule
+    /// cover both created and updated entities. Override this to implement delta validation
+    /// that considers the change being made.
     fn validate_updated(
         &self,
         _old: &R::Model,
         _update: &R::UpdateModel,
+        updated: &R::Model,
+        trigger: ValidationTrigger,
+    ) -> Violations {
+        self.validate_model(updated, trigger)
+    }
+}
+
+/// Validator running the checks declared with `#[ck_validate(...)]`, see [`Model::validate_fields`].
+///
+/// Creates are checked on the create model. Persisted and updated entities are checked on the
+/// model, which runs the same checks, so that their results are persisted and survive
+/// revalidation.
+///
+/// Runs implicitly, before the validators registered in the [`CrudContext`].
+pub struct FieldAttributeValidator;
+
+impl<R: CrudResource> EntityValidator<R> for FieldAttributeValidator {
+    fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("ck_validate")
+    }
+
+    fn version(&self) -> u32 {
+        2
+    }
+
+    fn validate_create(
+        &self,
+        create_model: &R::CreateModel,
+        _trigger: ValidationTrigger,
+    ) -> Violations {
+        create_model.validate_fields()
+    }
+
+    fn validate_model(&self, model: &R::Model, _trigger: ValidationTrigger) -> Violations {
+        model.validate_fields()
+    }
+}
+
+/// Trait for validators requiring async work, like checking that an email is unique or that a
+/// referenced category exists.
+///
+/// Validators receive the [`CrudContext`], giving access to the repository, and the
+/// [`RequestContext`] of the request being validated. The validators of a resource run
+/// concurrently. Each validation is bounded by [`timeout`](Self::timeout) and all of them together
+/// by [`ASYNC_VALIDATION_BUDGET`]. A validator exceeding either is abandoned, so one slow validator
+/// cannot stall a request. For created and updated entities, it then reports a violation of its
+/// [`timeout_severity`](Self::timeout_severity). Otherwise it has no result and its persisted
+/// results are kept.
+///
+/// # Example
+///
+/// ```ignore
+/// struct UniqueEmailValidator;
+///
+/// #[async_trait]
+/// impl AsyncEntityValidator<User> for UniqueEmailValidator {
+///     fn name(&self) -> Cow<'static, str> {
+///         Cow::Borrowed("unique_email")
+///     }
+///
+///     fn version(&self) -> u32 {
+///         1
+///     }
+///
+///     async fn validate_create(
+///         &self,
+///         create_model: &UserCreateModel,
+///         context: &CrudContext<User>,
+///         _request: &RequestContext<NoAuth>,
+///         _trigger: ValidationTrigger,
+///     ) -> Violations {
+///         let condition = Condition::all().eq("email", create_model.email.clone());
+///         let taken = context.repository.count(None, None, None, Some(&condition)).await;
+///         let mut violations = Violations::empty();
+///         if taken.unwrap_or(0) > 0 {
+///             violations.push(Violation::critical("Email is already taken").for_field("email"));
+///         }
+///         violations
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait AsyncEntityValidator<R: CrudResource>: Send + Sync {
+    /// Returns the unique name of this validator.
+    fn name(&self) -> Cow<'static, str>;
+
+    /// Returns the version of this validator.
+    /// Increment when the validation logic changes to invalidate old results.
+    fn version(&self) -> u32;
+
+    /// Returns how long a single validation may take.
+    fn timeout(&self) -> Duration {
+        DEFAULT_VALIDATOR_TIMEOUT
+    }
+
+    /// Returns the severity of the violation reported when validating a created or updated entity
+    /// does not finish in time. `Critical` rejects the operation.
+    fn timeout_severity(&self) -> Severity {
+        Severity::Major
+    }
+
+    /// Validate a CreateModel before insertion.
+    async fn validate_create(
+        &self,
+        _create_model: &R::CreateModel,
+        _context: &CrudContext<R>,
+        _request: &RequestContext<R::Auth>,
+        _trigger: ValidationTrigger,
+    ) -> Violations {
+        Violations::empty()
+    }
+
+    /// Validate an existing Model.
+    async fn validate_model(
+        &self,
+        _model: &R::Model,
+        _context: &CrudContext<R>,
+        _request: &RequestContext<R::Auth>,
         _trigger: ValidationTrigger,
     ) -> Violations {
         Violations::empty()
     }
+
+    /// Validate an update, given the old Model, the UpdateModel and the merged post-update Model.
+    ///
+    /// By default, this validates the merged Model using `validate_model`.
+    async fn validate_updated(
+        &self,
+        _old: &R::Model,
+        _update: &R::UpdateModel,
+        updated: &R::Model,
+        context: &CrudContext<R>,
+        request: &RequestContext<R::Auth>,
+        trigger: ValidationTrigger,
+    ) -> Violations {
+        self.validate_model(updated, context, request, trigger)
+            .await
+    }
 }
 
 /// Trait for validators that check aggregate-level constraints.
@@ -0,0 +1,233 @@
+//! Read operations for the persisted violations of CRUD resources.
+//!
+//! These expose what the [`ValidationResultRepository`] stored, without running any validator.
+//! Only violations of entities visible to the caller are exposed: the condition the `before_read`
+//! hook adds for reading many entities also selects the entities whose violations can be read.
+//! Violations are checked against it in batches, so no request loads all visible entities.
+
+use crate::{
+    auth::RequestContext,
+    condition_check::{ensure_valid_condition, resolve_condition},
+    error::CrudError,
+    lifetime::{CrudLifetime, ReadOperation, ReadRequest},
+    prelude::*,
+};
+
+use crudkit_core::condition::{Condition, ConditionClauseValue};
+use crudkit_core::id::{Id, IdField, SerializableId};
+use crudkit_core::validation::{PersistedViolation, ViolationFilter};
+
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+/// Maximum number of violations returned by one [`read_violations`] request.
+pub const MAX_VIOLATIONS_PAGE_SIZE: u64 = 1000;
+
+/// Request body for counting persisted violations.
+#[derive(Debug, ToSchema, Deserialize)]
+pub struct ReadViolationCount {
+    /// Selects the violations to count. Counts all violations of the resource by default.
+    #[serde(default)]
+    pub filter: ViolationFilter,
+}
+
+/// Request body for reading persisted violations.
+#[derive(Debug, ToSchema, Deserialize)]
+pub struct ReadViolations {
+    /// Maximum number of violations to return, at most [`MAX_VIOLATIONS_PAGE_SIZE`], which is
+    /// also the default.
+    pub limit: Option<u64>,
+    /// Number of violations to skip.
+    pub skip: Option<u64>,
+    /// Selects the violations to read. Reads all violations of the resource by default.
+    #[serde(default)]
+    pub filter: ViolationFilter,
+}
+
+/// Count the persisted violations of the resource matching the given filter.
+#[tracing::instrument(level = "info", skip(context, request))]
+pub async fn read_violation_count<R: CrudResource>(
+    request: RequestContext<R::Auth>,
+    context: Arc<CrudContext<R>>,
+    body: ReadViolationCount,
+) -> Result<u64, CrudError> {
+    let repository = &context.validation_result_repository;
+    let Some(visibility) = visibility_condition(&request, &context).await? else {
+        return repository
+            .count_filtered(R::TYPE.name(), &body.filter, None)
+            .await
+            .map_err(|err| CrudError::ReadValidations {
+                reason: Arc::new(err),
+            });
+    };
+
+    let mut count = 0;
+    let mut offset = 0;
+    loop {
+        let batch = list_batch(&context, &body.filter, offset).await?;
+        let batch_len = batch.len() as u64;
+        offset += batch_len;
+        let visible = visible_entity_ids(&context, &visibility, &batch).await?;
+        count += batch
+            .iter()
+            .filter(|violation| visible.contains(&violation.entity_id))
+            .count() as u64;
+        if batch_len < VISIBILITY_BATCH_SIZE {
+            return Ok(count);
+        }
+    }
+}
+
+/// Read the persisted violations of the resource matching the given filter.
+#[tracing::instrument(level = "info", skip(context, request))]
+pub async fn read_violations<R: CrudResource>(
+    request: RequestContext<R::Auth>,
+    context: Arc<CrudContext<R>>,
+    body: ReadViolations,
+) -> Result<Vec<PersistedViolation>, CrudError> {
+    let limit = body.limit.map_or(MAX_VIOLATIONS_PAGE_SIZE, |limit| {
+        limit.min(MAX_VIOLATIONS_PAGE_SIZE)
+    });
+    let Some(visibility) = visibility_condition(&request, &context).await? else {
+        return context
+            .validation_result_repository
+            .list_filtered(R::TYPE.name(), &body.filter, None, Some(limit), body.skip)
+            .await
+            .map_err(|err| CrudError::ReadValidations {
+                reason: Arc::new(err),
+            });
+    };
+
+    let mut violations = Vec::new();
+    let mut to_skip = body.skip.unwrap_or(0);
+    let mut offset = 0;
+    loop {
+        let batch = list_batch(&context, &body.filter, offset).await?;
+        let batch_len = batch.len() as u64;
+        offset += batch_len;
+        let visible = visible_entity_ids(&context, &visibility, &batch).await?;
+        for violation in batch {
+            if !visible.contains(&violation.entity_id) {
+                continue;
+            }
+            if to_skip > 0 {
+                to_skip -= 1;
+                continue;
+            }
+            violations.push(violation);
+            if violations.len() as u64 == limit {
+                return Ok(violations);
+            }
+        }
+        if batch_len < VISIBILITY_BATCH_SIZE {
+            return Ok(violations);
+        }
+    }
+}
+
+/// Number of violations checked for visibility at once.
+const VISIBILITY_BATCH_SIZE: u64 = 500;
+
+/// The condition selecting the entities visible to the caller, or `None` if all entities are
+/// visible.
+///
+/// Runs the `before_read` hook as reading many entities would. Its condition, if any, selects
+/// the visible entities and is checked like the condition of a read request.
+async fn visibility_condition<R: CrudResource>(
+    request: &RequestContext<R::Auth>,
+    context: &CrudContext<R>,
+) -> Result<Option<Condition>, CrudError> {
+    let mut read_request = ReadRequest {
+        operation: ReadOperation::Many,
+        limit: None,
+        skip: None,
+        order_by: None,
+        condition: None,
+    };
+
+    R::Lifetime::before_read(
+        &mut read_request,
+        &context.res_context,
+        request.clone(),
+        R::HookData::default(),
+    )
+    .await
+    .map_err(CrudError::from)?;
+
+    resolve_condition::<R>(
+        read_request.condition.as_mut(),
+        &context.res_context,
+        request,
+    );
+    ensure_valid_condition::<R::ReadModelField>(
+        read_request.condition.as_ref(),
+        R::MAX_CONDITION_DEPTH,
+    )?;
+    Ok(read_request.condition)
+}
+
+/// The next batch of violations matching `filter`, in the stable order of the repository.
+async fn list_batch<R: CrudResource>(
+    context: &CrudContext<R>,
+    filter: &ViolationFilter,
+    offset: u64,
+) -> Result<Vec<PersistedViolation>, CrudError> {
+    context
+        .validation_result_repository
+        .list_filtered(
+            R::TYPE.name(),
+            filter,
+            None,
+            Some(VISIBILITY_BATCH_SIZE),
+            Some(offset),
+        )
+        .await
+        .map_err(|err| CrudError::ReadValidations {
+            reason: Arc::new(err),
+        })
+}
+
+/// The ids of the entities of `violations` selected by the `visibility` condition.
+async fn visible_entity_ids<R: CrudResource>(
+    context: &CrudContext<R>,
+    visibility: &Condition,
+    violations: &[PersistedViolation],
+) -> Result<HashSet<SerializableId>, CrudError> {
+    let entity_ids = violations
+        .iter()
+        .map(|violation| &violation.entity_id)
+        .collect::<HashSet<_>>();
+    // Entities whose id cannot be part of a condition are never visible.
+    let mut any_entity = Condition::any();
+    'entities: for entity_id in entity_ids {
+        let mut entity = Condition::all();
+        for field in entity_id.fields_iter() {
+            let Ok(value) = ConditionClauseValue::try_from(field.to_value()) else {
+                continue 'entities;
+            };
+            entity = entity.eq(field.name(), value);
+        }
+        any_entity.push_condition(entity);
+    }
+    if any_entity.is_empty() {
+        return Ok(HashSet::new());
+    }
+    let mut condition = Condition::all();
+    condition.push_condition(visibility.clone());
+    condition.push_condition(any_entity);
+
+    let entities = context
+        .repository
+        .read_many(None, None, None, Some(&condition))
+        .await
+        .map_err(|err| CrudError::Repository {
+            reason: Arc::new(err),
+        })?;
+
+    Ok(entities
+        .iter()
+        .map(|entity| entity.id().to_serializable_id())
+        .collect())
+}
@@ -21,6 +21,11 @@ struct DeriveReadView {
 
     table_name: String,
 
+    /// Adds the `has_validation_infos` field. Views created before that column existed must be
+    /// recreated first.
+    #[darling(default)]
+    has_validation_infos: bool,
+
     data: ast::Data<(), Field>,
 }
 
@@ -61,6 +66,12 @@ impl DeriveReadView {
 
         let fields: Vec<Field> = self.fields().iter().map(|f| f.to_owned()).collect();
         let original_fields = generate_model_fields(fields.iter());
+        let has_validation_infos = self.has_validation_infos.then(|| {
+            quote! {
+                /// Whether there are current validation infos for this entity.
+                pub has_validation_infos: bool,
+            }
+        });
 
         // TODO: new attrs or forward original attrs?
         quote!(
@@ -92,6 +103,8 @@ impl DeriveReadView {
 
                     /// Whether there are current validation errors for this entity.
                     pub has_validation_errors: bool,
+
+                    #has_validation_infos
                 }
 
                 #[derive(Debug, Clone, Copy, sea_orm::EnumIter, sea_orm::DeriveRelation)]
@@ -139,6 +139,7 @@ pub fn expand_derive_validation_model(input: DeriveInput) -> syn::Result<TokenSt
             impl core::convert::Into<crudkit_validation::ValidationViolation> for Model {
                 fn into(self) -> crudkit_validation::ValidationViolation {
                     match self.violation_severity {
+                        crudkit_sea_orm::validation::PersistedViolationSeverity::Info => crudkit_validation::Violation::info(self.violation_message),
                         crudkit_sea_orm::validation::PersistedViolationSeverity::Major => crudkit_validation::Violation::major(self.violation_message),
                         crudkit_sea_orm::validation::PersistedViolationSeverity::Critical => crudkit_validation::Violation::critical(self.violation_message),
                     }
@@ -6,6 +6,11 @@ mod derives;
 
 /// Derives a `read_view` module containing a copy of the annotated struct with the
 /// `pub has_validation_errors: bool` field added to it.
+///
+/// `#[read_view(table_name = "...", has_validation_infos)]` also adds the
+/// `pub has_validation_infos: bool` field. Its column only exists in views created since infos
+/// were introduced. Recreate older views with a new migration, e.g. using
+/// `impl_read_view_migration!`, before enabling it.
 #[proc_macro_derive(ReadView, attributes(read_view))]
 pub fn derive_migration_name(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -40,7 +45,9 @@ pub fn derive_sea_orm_bridge(input: TokenStream) -> TokenStream {
 /// - `#[ck_create_model(exclude)]` - Exclude field from CreateModel
 /// - `#[ck_create_model(optional)]` - Wrap field in Option
 /// - `#[ck_create_model(use_default)]` - Use Default::default() for excluded fields
-#[proc_macro_derive(CkSeaOrmCreateModel, attributes(ck_create_model))]
+/// - `#[ck_validate(length(min = 3, max = 120), regex = "...", range(min = 0))]` - Check the field on create,
+///   reporting field-scoped violations. Add `severity = "major"` or `"info"` to not block (default: critical)
+#[proc_macro_derive(CkSeaOrmCreateModel, attributes(ck_create_model, ck_validate))]
 pub fn derive_sea_orm_create_model(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     derives::expand_derive_sea_orm_create_model(input)
@@ -63,7 +70,9 @@ pub fn derive_sea_orm_create_model(input: TokenStream) -> TokenStream {
 /// - `#[ck_update_model(exclude)]` - Exclude field from UpdateModel
 /// - `#[ck_update_model(optional)]` - Wrap field in Option
 /// - `#[ck_update_model(use_default)]` - Use Default::default() for excluded fields
-#[proc_macro_derive(CkSeaOrmUpdateModel, attributes(ck_update_model))]
+/// - `#[ck_validate(length(min = 3, max = 120), regex = "...", range(min = 0))]` - Check the field on update,
+///   reporting field-scoped violations. Add `severity = "major"` or `"info"` to not block (default: critical)
+#[proc_macro_derive(CkSeaOrmUpdateModel, attributes(ck_update_model, ck_validate))]
 pub fn derive_sea_orm_update_model(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     derives::expand_derive_sea_orm_update_model(input)
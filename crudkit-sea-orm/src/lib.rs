@@ -11,6 +11,7 @@ pub use crudkit_sea_orm_macros::*;
 pub use crudkit_rs_macros::CkField;
 pub use crudkit_sea_orm_macros::CkValidationModel;
 pub use crudkit_sea_orm_macros::{CkSeaOrmCreateModel, CkSeaOrmUpdateModel};
+pub use crudkit_core_macros::CkEnum;
 pub use crudkit_core_macros::CkId;
 
 // Re-export dependencies used in generated code.
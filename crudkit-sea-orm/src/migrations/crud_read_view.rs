@@ -2,6 +2,11 @@
 //!
 //! These functions generate PostgreSQL views that join entity tables with the
 //! unified `CrudkitValidation` table to provide a `has_validation_errors` column.
+//! Infos are not counted as errors and are reported by the `has_validation_infos` column instead.
+//!
+//! Views created before the `has_validation_infos` column was introduced lack it. Recreate them
+//! with a new migration, e.g. using [`impl_read_view_migration!`](crate::impl_read_view_migration),
+//! before enabling `has_validation_infos` on the `ReadView` derive.
 
 use sea_orm_migration::{
     sea_orm::{ConnectionTrait, DbBackend, Statement}, DbErr,
@@ -131,7 +136,15 @@ pub fn build_create_read_view_stmt(
                        FROM "CrudkitValidation" V
                        WHERE V.resource_name = '{resource_name}'
                          AND V.entity_id = {id_json_sql}
-                   ) AS has_validation_errors
+                         AND V.violation_severity <> 'INFO'
+                   ) AS has_validation_errors,
+                   EXISTS (
+                       SELECT 1
+                       FROM "CrudkitValidation" V
+                       WHERE V.resource_name = '{resource_name}'
+                         AND V.entity_id = {id_json_sql}
+                         AND V.violation_severity = 'INFO'
+                   ) AS has_validation_infos
             FROM "{table_name}" AS N;
             "#
         ),
@@ -0,0 +1,65 @@
+//! Helpers for creating native PostgreSQL enum types from `CkEnum` types.
+//!
+//! The labels of the created type are the variant names of the enum, which is what crudkit stores
+//! for `Value::Enum`. Columns of such a type need a SeaORM `DeriveActiveEnum` whose
+//! `string_value`s equal these names.
+
+use crudkit_core::CkEnum;
+use sea_orm_migration::{
+    DbErr, SchemaManager,
+    sea_orm::{ConnectionTrait, DbBackend, Statement},
+};
+
+pub async fn create_enum_type<E: CkEnum>(
+    manager: &SchemaManager<'_>,
+    type_name: &'static str,
+) -> Result<(), DbErr> {
+    manager
+        .get_connection()
+        .execute(build_create_enum_type_stmt::<E>(type_name))
+        .await
+        .map(|_exec_result| ())
+}
+
+#[must_use]
+pub fn build_create_enum_type_stmt<E: CkEnum>(type_name: &'static str) -> Statement {
+    let labels = E::variants()
+        .iter()
+        .map(|variant| format!("'{}'", variant.name.replace('\'', "''")))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        format!(
+            r#"
+            CREATE TYPE "{type_name}" AS ENUM ({labels});
+            "#
+        ),
+        vec![],
+    )
+}
+
+pub async fn drop_enum_type(
+    manager: &SchemaManager<'_>,
+    type_name: &'static str,
+) -> Result<(), DbErr> {
+    manager
+        .get_connection()
+        .execute(build_drop_enum_type_stmt(type_name))
+        .await
+        .map(|_exec_result| ())
+}
+
+#[must_use]
+pub fn build_drop_enum_type_stmt(type_name: &'static str) -> Statement {
+    Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        format!(
+            r#"
+            DROP TYPE IF EXISTS "{type_name}";
+            "#
+        ),
+        vec![],
+    )
+}
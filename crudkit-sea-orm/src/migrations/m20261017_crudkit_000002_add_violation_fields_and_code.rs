@@ -0,0 +1,52 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(Iden)]
+enum CrudkitValidation {
+    #[iden = "CrudkitValidation"]
+    Table,
+    ViolationFields,
+    ViolationCode,
+}
+
+/// Migration adding the `violation_fields` and `violation_code` columns to the unified
+/// `CrudkitValidation` table, allowing violations to reference the fields they concern.
+///
+/// Already part of our main [`crate::migrations::Migrator`].
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CrudkitValidation::Table)
+                    .add_column(
+                        ColumnDef::new(CrudkitValidation::ViolationFields)
+                            .json_binary()
+                            .not_null()
+                            .default(Expr::cust("'[]'::jsonb")),
+                    )
+                    .add_column(
+                        ColumnDef::new(CrudkitValidation::ViolationCode)
+                            .string()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CrudkitValidation::Table)
+                    .drop_column(CrudkitValidation::ViolationFields)
+                    .drop_column(CrudkitValidation::ViolationCode)
+                    .to_owned(),
+            )
+            .await
+    }
+}
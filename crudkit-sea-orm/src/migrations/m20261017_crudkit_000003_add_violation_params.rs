@@ -0,0 +1,45 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(Iden)]
+enum CrudkitValidation {
+    #[iden = "CrudkitValidation"]
+    Table,
+    ViolationParams,
+}
+
+/// Migration adding the `violation_params` column to the unified `CrudkitValidation` table,
+/// allowing clients to render translated violation messages.
+///
+/// Already part of our main [`crate::migrations::Migrator`].
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CrudkitValidation::Table)
+                    .add_column(
+                        ColumnDef::new(CrudkitValidation::ViolationParams)
+                            .json_binary()
+                            .not_null()
+                            .default(Expr::cust("'{}'::jsonb")),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CrudkitValidation::Table)
+                    .drop_column(CrudkitValidation::ViolationParams)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -3,11 +3,16 @@
 use sea_orm_migration::{MigrationTrait, MigratorTrait};
 
 pub mod crud_read_view;
+pub mod enum_type;
 pub mod m20260118_crudkit_000001_create_unified_validation_table;
+pub mod m20261017_crudkit_000002_add_violation_fields_and_code;
+pub mod m20261017_crudkit_000003_add_violation_params;
 
 /// Provides all migrations required for crudkit to operate. This currently includes:
 ///
 /// - [`m20260118_crudkit_000001_create_unified_validation_table`] - For storing all validations in one unified database table.
+/// - [`m20261017_crudkit_000002_add_violation_fields_and_code`] - For storing the fields and code of a violation.
+/// - [`m20261017_crudkit_000003_add_violation_params`] - For storing the message parameters of a violation.
 ///
 /// # Usage
 ///
@@ -27,8 +32,10 @@ pub struct Migrator;
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(
-            m20260118_crudkit_000001_create_unified_validation_table::Migration,
-        )]
+        vec![
+            Box::new(m20260118_crudkit_000001_create_unified_validation_table::Migration),
+            Box::new(m20261017_crudkit_000002_add_violation_fields_and_code::Migration),
+            Box::new(m20261017_crudkit_000003_add_violation_params::Migration),
+        ]
     }
 }
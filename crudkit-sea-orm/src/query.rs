@@ -6,11 +6,13 @@
 use crate::newtypes::TimeDuration;
 use crate::repo::SeaOrmRepoError;
 use crate::traits::SeaOrmResource;
-use crudkit_core::condition::{Condition, ConditionElement, Operator};
-use crudkit_core::{Order, Value};
+use crudkit_core::condition::{Condition, ConditionElement, LIKE_ESCAPE, Operator};
+use crudkit_core::{NullsOrder, Order, OrderSpec, TextOrder, Value, ValueKind};
 use crudkit_rs::prelude::*;
 use indexmap::IndexMap;
-use sea_orm::{ColumnTrait, EntityTrait, Insert, QueryFilter, QueryOrder, QuerySelect, Select};
+use sea_orm::{ColumnTrait, EntityTrait, Insert, QueryFilter, QuerySelect, QueryTrait, Select};
+use sea_query::extension::postgres::{PgBinOper, PgExpr};
+use sea_query::{Alias, ArrayType, Expr, Func, LikeExpr, NullOrdering, SimpleExpr};
 use snafu::{Backtrace, GenerateImplicitData};
 
 /// Build an insert query using the SeaOrmResource trait.
@@ -28,7 +30,7 @@ where
 pub fn build_select_query<R>(
     limit: Option<u64>,
     skip: Option<u64>,
-    order_by: Option<IndexMap<R::ModelField, Order>>,
+    order_by: Option<IndexMap<R::ModelField, OrderSpec>>,
     condition: Option<&Condition>,
 ) -> Result<Select<R::Entity>, SeaOrmRepoError>
 where
@@ -45,16 +47,7 @@ where
     }
 
     if let Some(map) = order_by {
-        for (field, order) in map {
-            let column = R::model_field_to_column(&field);
-            select = select.order_by(
-                column,
-                match order {
-                    Order::Asc => sea_orm::Order::Asc,
-                    Order::Desc => sea_orm::Order::Desc,
-                },
-            );
-        }
+        select = apply_order_by(select, map, R::model_field_to_column, R::COLLATIONS)?;
     }
 
     if let Some(condition) = condition {
@@ -71,7 +64,7 @@ where
 pub fn build_read_view_query<R>(
     limit: Option<u64>,
     skip: Option<u64>,
-    order_by: Option<IndexMap<R::ReadModelField, Order>>,
+    order_by: Option<IndexMap<R::ReadModelField, OrderSpec>>,
     condition: Option<&Condition>,
 ) -> Result<Select<R::ReadViewEntity>, SeaOrmRepoError>
 where
@@ -88,16 +81,7 @@ where
     }
 
     if let Some(map) = order_by {
-        for (field, order) in map {
-            let column = R::read_model_field_to_column(&field);
-            select = select.order_by(
-                column,
-                match order {
-                    Order::Asc => sea_orm::Order::Asc,
-                    Order::Desc => sea_orm::Order::Desc,
-                },
-            );
-        }
+        select = apply_order_by(select, map, R::read_model_field_to_column, R::COLLATIONS)?;
     }
 
     if let Some(condition) = condition {
@@ -112,6 +96,58 @@ where
     Ok(select)
 }
 
+/// Add an `ORDER BY` expression for each field in `order_by`, in order.
+///
+/// Text orderings only apply to String fields, other fields are ordered by their plain value.
+/// Case-insensitive ordering compares `LOWER(column)`, collations use `column COLLATE "name"`.
+/// Only the given `collations` are accepted.
+fn apply_order_by<E, F, C>(
+    mut select: Select<E>,
+    order_by: IndexMap<F, OrderSpec>,
+    field_to_column: fn(&F) -> C,
+    collations: &[&str],
+) -> Result<Select<E>, SeaOrmRepoError>
+where
+    E: EntityTrait,
+    F: ConditionValueConverter,
+    C: ColumnTrait,
+{
+    for (field, spec) in order_by {
+        let column = field_to_column(&field);
+        let expr = match (spec.text, field.value_kind()) {
+            (TextOrder::CaseInsensitive, ValueKind::String) => {
+                Func::lower(column.into_expr()).into()
+            }
+            (TextOrder::Collation(collation), _) if !collations.contains(&collation.as_str()) => {
+                return Err(SeaOrmRepoError::UnsupportedCollation {
+                    collation,
+                    backtrace: Backtrace::generate(),
+                });
+            }
+            (TextOrder::Collation(collation), ValueKind::String) => Expr::cust_with_expr(
+                format!("$1 COLLATE \"{}\"", collation.replace('"', "\"\"")),
+                column.into_expr(),
+            ),
+            _ => SimpleExpr::from(column.into_expr()),
+        };
+        let order = match spec.order {
+            Order::Asc => sea_orm::Order::Asc,
+            Order::Desc => sea_orm::Order::Desc,
+        };
+        let query = QueryTrait::query(&mut select);
+        match spec.nulls {
+            Some(NullsOrder::First) => {
+                query.order_by_expr_with_nulls(expr, order, NullOrdering::First)
+            }
+            Some(NullsOrder::Last) => {
+                query.order_by_expr_with_nulls(expr, order, NullOrdering::Last)
+            }
+            None => query.order_by_expr(expr, order),
+        };
+    }
+    Ok(select)
+}
+
 /// Build a condition tree using the field-based approach.
 fn build_condition_tree<F, C>(
     condition: &Condition,
@@ -121,46 +157,99 @@ where
     F: Field + FieldLookup + ConditionValueConverter,
     C: ColumnTrait,
 {
-    let mut tree = match &condition {
-        Condition::All(_) => sea_query::Condition::all(),
-        Condition::Any(_) => sea_query::Condition::any(),
+    let (mut tree, elements) = match condition {
+        Condition::All(elements) => (sea_query::Condition::all(), elements),
+        Condition::Any(elements) => (sea_query::Condition::any(), elements),
+        Condition::Not(nested_condition) => {
+            let negated = build_condition_tree::<F, C>(nested_condition, field_to_column)?.not();
+            // SeaORM drops empty top-level conditions, even negated ones. Wrapping keeps `NOT TRUE`.
+            return Ok(sea_query::Condition::all().add(negated));
+        }
     };
 
-    match condition {
-        Condition::All(elements) | Condition::Any(elements) => {
-            for element in elements {
-                match element {
-                    ConditionElement::Clause(clause) => {
-                        // Look up the field by name.
-                        let field = F::from_name(&clause.column_name).ok_or_else(|| {
-                            SeaOrmRepoError::UnknownColumnSpecified {
-                                column_name: clause.column_name.clone(),
-                                backtrace: Backtrace::generate(),
-                            }
-                        })?;
-
-                        // Convert the condition value to a typed Value.
-                        let value = field
-                            .convert_condition_value(clause.value.clone())
-                            .map_err(|err| SeaOrmRepoError::UnableToParseValueAsColType {
-                                column_name: clause.column_name.clone(),
-                                reason: err,
-                                backtrace: Backtrace::generate(),
-                            })?;
-
-                        // Get the SeaORM column.
-                        let col = field_to_column(&field);
-
-                        // Add the condition based on value type.
-                        tree = add_condition_from_value(tree, col, clause.operator, value)?;
+    for element in elements {
+        match element {
+            ConditionElement::Clause(clause) => {
+                // Look up the field by name.
+                let field = F::from_name(&clause.column_name).ok_or_else(|| {
+                    SeaOrmRepoError::UnknownColumnSpecified {
+                        column_name: clause.column_name.clone(),
+                        backtrace: Backtrace::generate(),
+                    }
+                })?;
+
+                // Clauses on Json paths compare JSON instead of values of the column's type.
+                if clause.is_json_clause() {
+                    if field.value_kind() != ValueKind::Json {
+                        return Err(unsupported(
+                            clause.operator,
+                            "json paths and json_contains require a Json column",
+                        ));
+                    }
+                    let value = clause.value.clone().to_json().map_err(|err| {
+                        SeaOrmRepoError::UnableToParseValueAsColType {
+                            column_name: clause.column_name.clone(),
+                            reason: err,
+                            backtrace: Backtrace::generate(),
+                        }
+                    })?;
+                    tree = tree.add(build_json_condition(
+                        field_to_column(&field),
+                        &clause.json_path,
+                        clause.operator,
+                        value,
+                    )?);
+                    continue;
+                }
+
+                // Array operators compare the elements of an array column.
+                if clause.operator.is_array_operator() {
+                    if field.value_kind() != ValueKind::Array {
+                        return Err(unsupported(clause.operator, "an array column is required"));
                     }
-                    ConditionElement::Condition(nested_condition) => {
-                        tree = tree.add(build_condition_tree::<F, C>(
-                            nested_condition,
-                            field_to_column,
-                        )?);
+                    let value = match clause.operator {
+                        Operator::ArrayLength => clause.value.clone().to_array_length(),
+                        _ => field.convert_condition_value(clause.value.clone()),
                     }
+                    .map_err(|err| {
+                        SeaOrmRepoError::UnableToParseValueAsColType {
+                            column_name: clause.column_name.clone(),
+                            reason: err,
+                            backtrace: Backtrace::generate(),
+                        }
+                    })?;
+                    tree = tree.add(build_array_condition(
+                        field_to_column(&field),
+                        clause.operator,
+                        value,
+                    )?);
+                    continue;
                 }
+
+                // Convert the condition value to a typed Value.
+                // Null checks do not inspect the value, so it need not be convertible.
+                let value = match clause.operator.is_null_check() {
+                    true => Value::Null,
+                    false => field
+                        .convert_condition_value(clause.value.clone())
+                        .map_err(|err| SeaOrmRepoError::UnableToParseValueAsColType {
+                            column_name: clause.column_name.clone(),
+                            reason: err,
+                            backtrace: Backtrace::generate(),
+                        })?,
+                };
+
+                // Get the SeaORM column.
+                let col = field_to_column(&field);
+
+                // Add the condition based on value type.
+                tree = add_condition_from_value(tree, col, clause.operator, value)?;
+            }
+            ConditionElement::Condition(nested_condition) => {
+                tree = tree.add(build_condition_tree::<F, C>(
+                    nested_condition,
+                    field_to_column,
+                )?);
             }
         }
     }
@@ -175,59 +264,95 @@ fn add_condition_from_value<C: ColumnTrait>(
     operator: Operator,
     value: Value,
 ) -> Result<sea_query::Condition, SeaOrmRepoError> {
+    // Null checks work on any column and ignore the value.
+    match operator {
+        Operator::IsNull => return Ok(tree.add(col.is_null())),
+        Operator::IsNotNull => return Ok(tree.add(col.is_not_null())),
+        _ => {}
+    }
+
     let tree = match value {
         // Null represents explicit absence - use IS NULL condition.
         Value::Null => match operator {
             Operator::Equal => tree.add(col.is_null()),
             Operator::NotEqual => tree.add(col.is_not_null()),
-            _ => unimplemented!("Only Equal/NotEqual operators supported for Null"),
+            other => {
+                return Err(unsupported(
+                    other,
+                    "only =, != and null checks can be used with a null value",
+                ));
+            }
         },
 
-        Value::Bool(val) => add_condition(tree, col, operator, val),
-        Value::U8(val) => add_condition(tree, col, operator, val),
-        Value::U16(val) => add_condition(tree, col, operator, val),
-        Value::U32(val) => add_condition(tree, col, operator, val),
-        Value::U64(val) => add_condition(tree, col, operator, val),
+        Value::Bool(val) => add_condition(tree, col, operator, val)?,
+        Value::U8(val) => add_condition(tree, col, operator, val)?,
+        Value::U16(val) => add_condition(tree, col, operator, val)?,
+        Value::U32(val) => add_condition(tree, col, operator, val)?,
+        Value::U64(val) => add_condition(tree, col, operator, val)?,
         // SeaORM doesn't support i128/u128 directly - log a warning.
         Value::U128(_) => {
             tracing::warn!("U128 values in conditions are not supported by SeaORM");
             tree
         }
-        Value::I8(val) => add_condition(tree, col, operator, val),
-        Value::I16(val) => add_condition(tree, col, operator, val),
-        Value::I32(val) => add_condition(tree, col, operator, val),
-        Value::I64(val) => add_condition(tree, col, operator, val),
+        Value::I8(val) => add_condition(tree, col, operator, val)?,
+        Value::I16(val) => add_condition(tree, col, operator, val)?,
+        Value::I32(val) => add_condition(tree, col, operator, val)?,
+        Value::I64(val) => add_condition(tree, col, operator, val)?,
         Value::I128(_) => {
             tracing::warn!("I128 values in conditions are not supported by SeaORM");
             tree
         }
-        Value::F32(val) => add_condition(tree, col, operator, val),
-        Value::F64(val) => add_condition(tree, col, operator, val),
-        Value::String(val) => add_condition(tree, col, operator, val),
-        Value::Json(val) => add_condition(tree, col, operator, val),
-        Value::Uuid(val) => add_condition(tree, col, operator, val),
-        Value::PrimitiveDateTime(val) => add_condition(tree, col, operator, val),
-        Value::OffsetDateTime(val) => add_condition(tree, col, operator, val),
-        Value::Duration(val) => add_condition(tree, col, operator, TimeDuration(val.0)),
-
-        // Array is used for IN conditions.
+        Value::F32(val) => add_condition(tree, col, operator, val)?,
+        Value::F64(val) => add_condition(tree, col, operator, val)?,
+        Value::Decimal(val) => add_condition(tree, col, operator, val)?,
+        Value::String(val) => add_text_condition(tree, col, operator, val)?,
+        // Enums are stored by variant name. SeaORM casts the name for native Postgres enum columns.
+        Value::Enum(val) => add_condition(tree, col, operator, val)?,
+        Value::Json(val) => add_condition(tree, col, operator, val)?,
+        Value::Uuid(val) => add_condition(tree, col, operator, val)?,
+        Value::PrimitiveDateTime(val) => add_condition(tree, col, operator, val)?,
+        Value::OffsetDateTime(val) => add_condition(tree, col, operator, val)?,
+        Value::Date(val) => add_condition(tree, col, operator, val)?,
+        Value::Time(val) => add_condition(tree, col, operator, val)?,
+        Value::Duration(val) => add_condition(tree, col, operator, TimeDuration(val.0))?,
+
+        // Array is used for IN, NOT IN and BETWEEN conditions.
         Value::Array(values) => {
-            if operator != Operator::IsIn {
-                panic!(
-                    "Array value can only be used with IsIn operator, got {:?}",
-                    operator
-                );
-            }
             if let Err(index) = Value::verify_array_homogeneity(&values) {
-                panic!(
-                    "Array elements must be homogeneous. Element at index {} has different type than first element.",
-                    index
-                );
+                return Err(unsupported(
+                    operator,
+                    format!(
+                        "array elements must be homogeneous, element at index {index} has a different type than the first element"
+                    ),
+                ));
             }
-            // Convert each element to sea_orm::Value and use is_in.
-            let sea_values: Vec<sea_orm::Value> =
+            // Convert each element to sea_orm::Value.
+            let mut sea_values: Vec<sea_orm::Value> =
                 values.into_iter().map(value_to_sea_orm_value).collect();
-            tree.add(col.is_in(sea_values))
+            match operator {
+                Operator::IsIn => tree.add(col.is_in(sea_values)),
+                Operator::NotIn => tree.add(col.is_not_in(sea_values)),
+                Operator::Between => {
+                    if sea_values.len() != 2 {
+                        return Err(unsupported(
+                            operator,
+                            format!(
+                                "expected exactly two values (low, high), got {}",
+                                sea_values.len()
+                            ),
+                        ));
+                    }
+                    let high = sea_values.pop().expect("two elements");
+                    let low = sea_values.pop().expect("two elements");
+                    tree.add(col.between(low, high))
+                }
+                other => {
+                    return Err(unsupported(
+                        other,
+                        "an array value can only be used with is_in, not_in or between",
+                    ));
+                }
+            }
         }
 
         Value::Void(_) => unimplemented!("Void value in condition"),
@@ -236,24 +361,254 @@ fn add_condition_from_value<C: ColumnTrait>(
     Ok(tree)
 }
 
+/// Build a condition on a value inside a Json (`jsonb`) column.
+///
+/// The path is followed with `->` and the value reached is compared as `jsonb`. Text operators
+/// and null checks read the last key with `->>` instead, seeing text and JSON null as `NULL`.
+fn build_json_condition<C: ColumnTrait>(
+    col: C,
+    path: &[String],
+    operator: Operator,
+    value: serde_json::Value,
+) -> Result<SimpleExpr, SeaOrmRepoError> {
+    let as_text = operator.is_text_operator() || operator.is_null_check();
+    let mut expr = SimpleExpr::from(col.into_expr());
+    for (index, key) in path.iter().enumerate() {
+        expr = match as_text && index + 1 == path.len() {
+            true => expr.cast_json_field(key.as_str()),
+            false => expr.get_json_field(key.as_str()),
+        };
+    }
+    let expr = Expr::expr(expr);
+    let json = |value: serde_json::Value| sea_orm::Value::Json(Some(Box::new(value)));
+
+    Ok(match (operator, value) {
+        (Operator::IsNull, _) => expr.is_null(),
+        (Operator::IsNotNull, _) => expr.is_not_null(),
+        (Operator::Equal, value) => expr.eq(json(value)),
+        (Operator::NotEqual, value) => expr.ne(json(value)),
+        (Operator::Less, value) => expr.lt(json(value)),
+        (Operator::LessOrEqual, value) => expr.lte(json(value)),
+        (Operator::Greater, value) => expr.gt(json(value)),
+        (Operator::GreaterOrEqual, value) => expr.gte(json(value)),
+        (Operator::JsonContains, value) => expr.contains(json(value)),
+        (Operator::IsIn, serde_json::Value::Array(values)) => {
+            expr.is_in(values.into_iter().map(json))
+        }
+        (Operator::NotIn, serde_json::Value::Array(values)) => {
+            expr.is_not_in(values.into_iter().map(json))
+        }
+        (Operator::Between, serde_json::Value::Array(mut values)) => {
+            if values.len() != 2 {
+                return Err(unsupported(
+                    operator,
+                    format!(
+                        "expected exactly two values (low, high), got {}",
+                        values.len()
+                    ),
+                ));
+            }
+            let high = values.pop().expect("two elements");
+            let low = values.pop().expect("two elements");
+            expr.between(json(low), json(high))
+        }
+        (Operator::IsIn | Operator::NotIn | Operator::Between, _) => {
+            return Err(unsupported(
+                operator,
+                "an array value is required, not a scalar",
+            ));
+        }
+        (
+            Operator::Contains | Operator::StartsWith | Operator::EndsWith,
+            serde_json::Value::String(val),
+        ) => expr.like(like_expr(operator, &val)),
+        (Operator::ContainsIgnoreCase, serde_json::Value::String(val)) => {
+            Expr::expr(Func::lower(expr)).like(like_expr(operator, &val))
+        }
+        (Operator::EqualIgnoreCase, serde_json::Value::String(val)) => {
+            Expr::expr(Func::lower(expr)).eq(val.to_lowercase())
+        }
+        (
+            Operator::Contains
+            | Operator::ContainsIgnoreCase
+            | Operator::StartsWith
+            | Operator::EndsWith
+            | Operator::EqualIgnoreCase,
+            _,
+        ) => return Err(unsupported(operator, "a string value is required")),
+        (Operator::ArrayContains | Operator::ArrayOverlaps | Operator::ArrayLength, _) => {
+            return Err(unsupported(operator, "an array column is required"));
+        }
+    })
+}
+
+/// Build a condition on the elements of a Postgres array column.
+///
+/// `array_contains` and `array_overlaps` use the `@>` and `&&` operators, `array_length` compares
+/// the `cardinality` of the array, which unlike `array_length` is 0 for empty arrays.
+/// Arrays compared against native Postgres enum array columns are cast to the enum array type.
+fn build_array_condition<C: ColumnTrait>(
+    col: C,
+    operator: Operator,
+    value: Value,
+) -> Result<SimpleExpr, SeaOrmRepoError> {
+    if operator == Operator::ArrayLength {
+        let cardinality = Expr::expr(Func::cust(Alias::new("cardinality")).arg(col.into_expr()));
+        return Ok(match value {
+            Value::I64(length) => cardinality.eq(length),
+            Value::Array(bounds) => {
+                let mut bounds = bounds.into_iter().map(value_to_sea_orm_value);
+                match (bounds.next(), bounds.next(), bounds.next()) {
+                    (Some(length), None, None) => cardinality.eq(length),
+                    (Some(min), Some(max), None) => cardinality.between(min, max),
+                    _ => {
+                        return Err(unsupported(
+                            operator,
+                            "a length or a [min, max] range is required",
+                        ));
+                    }
+                }
+            }
+            _ => return Err(unsupported(operator, "a length is required")),
+        });
+    }
+
+    let Value::Array(values) = value else {
+        return Err(unsupported(operator, "an array value is required"));
+    };
+    if let Err(index) = Value::verify_array_homogeneity(&values) {
+        return Err(unsupported(
+            operator,
+            format!(
+                "array elements must be homogeneous, element at index {index} has a different type than the first element"
+            ),
+        ));
+    }
+    let elements: Vec<sea_orm::Value> = values.into_iter().map(value_to_sea_orm_value).collect();
+    let array_type = match elements.first() {
+        // Every array contains the empty array, but none overlaps with it.
+        None => return Ok(Expr::val(operator == Operator::ArrayContains).into()),
+        Some(element) => array_type_of(element).ok_or_else(|| {
+            unsupported(
+                operator,
+                format!("array elements like {element:?} are not supported"),
+            )
+        })?,
+    };
+    let array = col.save_as(Expr::val(sea_orm::Value::Array(
+        array_type,
+        Some(Box::new(elements)),
+    )));
+
+    Ok(match operator {
+        Operator::ArrayContains => Expr::expr(col.into_expr()).binary(PgBinOper::Contains, array),
+        Operator::ArrayOverlaps => Expr::expr(col.into_expr()).binary(PgBinOper::Overlap, array),
+        other => return Err(unsupported(other, "an array column is not supported")),
+    })
+}
+
+/// The Postgres array type holding elements like `element`.
+fn array_type_of(element: &sea_orm::Value) -> Option<ArrayType> {
+    Some(match element {
+        sea_orm::Value::Bool(_) => ArrayType::Bool,
+        sea_orm::Value::TinyInt(_) => ArrayType::TinyInt,
+        sea_orm::Value::SmallInt(_) => ArrayType::SmallInt,
+        sea_orm::Value::Int(_) => ArrayType::Int,
+        sea_orm::Value::BigInt(_) => ArrayType::BigInt,
+        sea_orm::Value::TinyUnsigned(_) => ArrayType::TinyUnsigned,
+        sea_orm::Value::SmallUnsigned(_) => ArrayType::SmallUnsigned,
+        sea_orm::Value::Unsigned(_) => ArrayType::Unsigned,
+        sea_orm::Value::BigUnsigned(_) => ArrayType::BigUnsigned,
+        sea_orm::Value::Float(_) => ArrayType::Float,
+        sea_orm::Value::Double(_) => ArrayType::Double,
+        sea_orm::Value::String(_) => ArrayType::String,
+        sea_orm::Value::Decimal(_) => ArrayType::Decimal,
+        sea_orm::Value::Uuid(_) => ArrayType::Uuid,
+        sea_orm::Value::TimeDate(_) => ArrayType::TimeDate,
+        sea_orm::Value::TimeTime(_) => ArrayType::TimeTime,
+        sea_orm::Value::TimeDateTime(_) => ArrayType::TimeDateTime,
+        sea_orm::Value::TimeDateTimeWithTimeZone(_) => ArrayType::TimeDateTimeWithTimeZone,
+        _ => return None,
+    })
+}
+
 fn add_condition<C, T>(
     tree: sea_query::Condition,
     col: C,
     operator: Operator,
     val: T,
-) -> sea_query::Condition
+) -> Result<sea_query::Condition, SeaOrmRepoError>
 where
     C: ColumnTrait,
     T: Into<sea_orm::Value>,
 {
-    match operator {
+    Ok(match operator {
         Operator::Equal => tree.add(col.eq(val)),
         Operator::NotEqual => tree.add(col.ne(val)),
         Operator::Less => tree.add(col.lt(val)),
         Operator::LessOrEqual => tree.add(col.lte(val)),
         Operator::Greater => tree.add(col.gt(val)),
         Operator::GreaterOrEqual => tree.add(col.gte(val)),
-        Operator::IsIn => panic!("IsIn operator requires an array value, not a scalar"),
+        Operator::IsNull => tree.add(col.is_null()),
+        Operator::IsNotNull => tree.add(col.is_not_null()),
+        Operator::IsIn | Operator::NotIn | Operator::Between => {
+            return Err(unsupported(
+                operator,
+                "an array value is required, not a scalar",
+            ));
+        }
+        Operator::Contains
+        | Operator::ContainsIgnoreCase
+        | Operator::StartsWith
+        | Operator::EndsWith
+        | Operator::EqualIgnoreCase => {
+            return Err(unsupported(operator, "a string value is required"));
+        }
+        Operator::JsonContains => {
+            return Err(unsupported(operator, "a Json column is required"));
+        }
+        Operator::ArrayContains | Operator::ArrayOverlaps | Operator::ArrayLength => {
+            return Err(unsupported(operator, "an array column is required"));
+        }
+    })
+}
+
+/// Like `add_condition`, but additionally supports the text-matching operators.
+fn add_text_condition<C: ColumnTrait>(
+    tree: sea_query::Condition,
+    col: C,
+    operator: Operator,
+    val: String,
+) -> Result<sea_query::Condition, SeaOrmRepoError> {
+    Ok(match operator {
+        Operator::Contains | Operator::StartsWith | Operator::EndsWith => {
+            tree.add(Expr::expr(col.into_expr()).like(like_expr(operator, &val)))
+        }
+        Operator::ContainsIgnoreCase => {
+            tree.add(Expr::expr(Func::lower(col.into_expr())).like(like_expr(operator, &val)))
+        }
+        Operator::EqualIgnoreCase => {
+            tree.add(Expr::expr(Func::lower(col.into_expr())).eq(val.to_lowercase()))
+        }
+        other => add_condition(tree, col, other, val)?,
+    })
+}
+
+/// The `LIKE` expression of a text operator, matching `val` literally.
+///
+/// Only call this for operators with a [`Operator::like_pattern`].
+fn like_expr(operator: Operator, val: &str) -> LikeExpr {
+    let pattern = operator
+        .like_pattern(val)
+        .expect("operator has a LIKE pattern");
+    LikeExpr::new(pattern).escape(LIKE_ESCAPE)
+}
+
+fn unsupported(operator: Operator, reason: impl Into<String>) -> SeaOrmRepoError {
+    SeaOrmRepoError::UnsupportedOperatorValue {
+        operator,
+        reason: reason.into(),
+        backtrace: Backtrace::generate(),
     }
 }
 
@@ -274,14 +629,314 @@ fn value_to_sea_orm_value(value: Value) -> sea_orm::Value {
         Value::I128(_) => panic!("I128 values are not supported by SeaORM"),
         Value::F32(v) => v.into(),
         Value::F64(v) => v.into(),
+        Value::Decimal(v) => v.into(),
         Value::String(v) => v.into(),
+        Value::Enum(v) => v.into(),
         Value::Json(v) => v.into(),
         Value::Uuid(v) => v.into(),
         Value::PrimitiveDateTime(v) => v.into(),
         Value::OffsetDateTime(v) => v.into(),
+        Value::Date(v) => v.into(),
+        Value::Time(v) => v.into(),
         Value::Duration(v) => TimeDuration(v.0).into(),
         Value::Void(_) => panic!("Void value cannot be converted to sea_orm::Value"),
         Value::Array(_) => panic!("Nested arrays are not supported in conditions"),
         Value::Other(_) => panic!("Other values are not supported in conditions"),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repo::SeaOrmRepo;
+    use crate::traits::{SeaOrmCreateModel, SeaOrmUpdateModel};
+    use crudkit_core::collaboration::CollabMessage;
+    use sea_orm::{DbBackend, IntoActiveModel};
+    use std::convert::Infallible;
+
+    mod article {
+        use crate::{CkEnum, CkField, CkId};
+        use sea_orm::entity::prelude::*;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(
+            Debug,
+            Clone,
+            Copy,
+            PartialEq,
+            Eq,
+            Serialize,
+            Deserialize,
+            EnumIter,
+            DeriveActiveEnum,
+            CkEnum,
+        )]
+        #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "article_status")]
+        #[serde(rename_all = "snake_case")]
+        pub enum Status {
+            #[sea_orm(string_value = "draft")]
+            Draft,
+            #[sea_orm(string_value = "published")]
+            Published,
+        }
+
+        #[derive(
+            Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize, CkField, CkId,
+        )]
+        #[sea_orm(table_name = "article")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i64,
+            pub title: String,
+            pub rating: Option<i32>,
+            #[sea_orm(column_type = "JsonBinary")]
+            pub metadata: serde_json::Value,
+            pub tags: Vec<String>,
+            #[ck_field(enum)]
+            pub status: Status,
+            #[ck_field(enum)]
+            pub labels: Vec<Status>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    use article::{Column, ModelField};
+
+    #[derive(Debug)]
+    struct Article;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct ArticleType;
+
+    impl ResourceType for ArticleType {
+        fn name(&self) -> &'static str {
+            "article"
+        }
+    }
+
+    struct ArticleContext;
+
+    impl CrudResourceContext for ArticleContext {}
+
+    struct NoCollaboration;
+
+    impl CollaborationService for NoCollaboration {
+        type Error = Infallible;
+
+        async fn broadcast_json(&self, _json: CollabMessage) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    impl crudkit_rs::data::CreateModel for article::Model {}
+
+    impl SeaOrmCreateModel<article::ActiveModel> for article::Model {
+        async fn into_active_model(self) -> article::ActiveModel {
+            IntoActiveModel::into_active_model(self)
+        }
+    }
+
+    impl SeaOrmUpdateModel<article::Model> for article::ActiveModel {
+        fn update_with(&mut self, update: article::Model) {
+            *self = IntoActiveModel::into_active_model(update);
+        }
+    }
+
+    impl CrudResource for Article {
+        type ReadModel = article::Model;
+        type ReadModelId = article::ModelId;
+        type ReadModelField = ModelField;
+        type CreateModel = article::Model;
+        type CreateModelField = ModelField;
+        type UpdateModel = article::Model;
+        type UpdateModelField = ModelField;
+        type Model = article::Model;
+        type Id = article::ModelId;
+        type ModelField = ModelField;
+        type Repository = SeaOrmRepo;
+        type ValidationResultRepository = NoopValidationResultRepository;
+        type CollaborationService = NoCollaboration;
+        type Context = ArticleContext;
+        type HookData = ();
+        type Lifetime = NoopLifetimeHooks;
+        type Auth = NoAuth;
+        type AuthPolicy = DefaultAuthPolicy;
+        type PlaceholderResolver = NoPlaceholders;
+        type ResourceType = ArticleType;
+
+        const TYPE: Self::ResourceType = ArticleType;
+        const COLLATIONS: &'static [&'static str] = &["de-DE-x-icu"];
+    }
+
+    impl SeaOrmResource for Article {
+        type Entity = article::Entity;
+        type SeaOrmModel = article::Model;
+        type ActiveModel = article::ActiveModel;
+        type Column = Column;
+        type PrimaryKey = article::PrimaryKey;
+        type ReadViewEntity = article::Entity;
+        type ReadViewSeaOrmModel = article::Model;
+        type ReadViewActiveModel = article::ActiveModel;
+        type ReadViewColumn = Column;
+        type ReadViewPrimaryKey = article::PrimaryKey;
+
+        fn model_field_to_column(field: &ModelField) -> Column {
+            match field {
+                ModelField::Id => Column::Id,
+                ModelField::Title => Column::Title,
+                ModelField::Rating => Column::Rating,
+                ModelField::Metadata => Column::Metadata,
+                ModelField::Tags => Column::Tags,
+                ModelField::Status => Column::Status,
+                ModelField::Labels => Column::Labels,
+            }
+        }
+
+        fn read_model_field_to_column(field: &ModelField) -> Column {
+            Self::model_field_to_column(field)
+        }
+    }
+
+    /// The Postgres SQL and the bound values of the select query for `condition` and `order_by`.
+    fn select(
+        condition: Option<&Condition>,
+        order_by: Option<IndexMap<ModelField, OrderSpec>>,
+    ) -> (String, Vec<sea_orm::Value>) {
+        let statement = build_select_query::<Article>(None, None, order_by, condition)
+            .expect("query builds")
+            .build(DbBackend::Postgres);
+        let values = statement.values.map(|values| values.0).unwrap_or_default();
+        (statement.sql, values)
+    }
+
+    /// The `WHERE` clause of the select query for `condition`.
+    fn filter(condition: &Condition) -> (String, Vec<sea_orm::Value>) {
+        let (sql, values) = select(Some(condition), None);
+        let (_, filter) = sql.split_once(" WHERE ").expect("query has a WHERE clause");
+        (filter.to_owned(), values)
+    }
+
+    fn string(value: &str) -> sea_orm::Value {
+        sea_orm::Value::String(Some(Box::new(value.to_owned())))
+    }
+
+    fn json(value: serde_json::Value) -> sea_orm::Value {
+        sea_orm::Value::Json(Some(Box::new(value)))
+    }
+
+    fn strings(array_type: ArrayType, values: &[&str]) -> sea_orm::Value {
+        let values = values.iter().map(|value| string(value)).collect();
+        sea_orm::Value::Array(array_type, Some(Box::new(values)))
+    }
+
+    #[test]
+    fn text_operators_escape_like_wildcards() {
+        let (sql, values) = filter(&ModelField::TITLE.contains(r"50%_off\"));
+        assert_eq!(sql, r#""article"."title" LIKE $1 ESCAPE E'\\'"#);
+        assert_eq!(values, vec![string(r"%50\%\_off\\%")]);
+
+        let (sql, values) = filter(&ModelField::TITLE.contains_ignore_case("Sale_"));
+        assert_eq!(sql, r#"LOWER("article"."title") LIKE $1 ESCAPE E'\\'"#);
+        assert_eq!(values, vec![string(r"%sale\_%")]);
+    }
+
+    #[test]
+    fn negated_conditions_are_wrapped_in_not() {
+        let (sql, values) = filter(
+            &!ModelField::RATING
+                .gt(3)
+                .and(ModelField::RATING.is_not_null()),
+        );
+        assert_eq!(
+            sql,
+            r#"NOT ("article"."rating" > $1 AND "article"."rating" IS NOT NULL)"#
+        );
+        assert_eq!(values, vec![sea_orm::Value::Int(Some(3))]);
+
+        let (sql, _) = filter(&!Condition::all());
+        assert_eq!(sql, "NOT TRUE");
+    }
+
+    #[test]
+    fn json_paths_compare_jsonb_and_read_text_with_the_last_key() {
+        let condition = Condition::all()
+            .json_path("metadata", ["customer", "country"], Operator::Equal, "DE")
+            .json_path("metadata", ["customer", "name"], Operator::StartsWith, "Jo")
+            .json_contains("metadata", serde_json::json!({ "featured": true }));
+        let (sql, values) = filter(&condition);
+        assert_eq!(
+            sql,
+            r#"(("article"."metadata" -> $1) -> $2) = $3 AND (("article"."metadata" -> $4) ->> $5) LIKE $6 ESCAPE E'\\' AND "article"."metadata" @> $7"#
+        );
+        assert_eq!(
+            values,
+            vec![
+                string("customer"),
+                string("country"),
+                json(serde_json::json!("DE")),
+                string("customer"),
+                string("name"),
+                string("Jo%"),
+                json(serde_json::json!({ "featured": true })),
+            ]
+        );
+    }
+
+    #[test]
+    fn array_operators_and_enums_use_postgres_operators_and_casts() {
+        let condition = Condition::all()
+            .array_contains("tags", vec!["rust".to_owned()])
+            .array_overlaps("labels", vec!["draft".to_owned(), "published".to_owned()])
+            .array_length("tags", 2)
+            .eq("status", "published");
+        let (sql, values) = filter(&condition);
+        assert_eq!(
+            sql,
+            r#""article"."tags" @> $1 AND ("article"."labels" && (CAST($2 AS article_status[]))) AND cardinality("article"."tags") = $3 AND "article"."status" = (CAST($4 AS article_status))"#
+        );
+        assert_eq!(
+            values,
+            vec![
+                strings(ArrayType::String, &["rust"]),
+                strings(ArrayType::String, &["draft", "published"]),
+                sea_orm::Value::BigInt(Some(2)),
+                string("published"),
+            ]
+        );
+    }
+
+    #[test]
+    fn orderings_apply_nulls_and_collations() {
+        let order_by = IndexMap::from([
+            (
+                ModelField::Title,
+                OrderSpec::asc().collation("de-DE-x-icu").nulls_last(),
+            ),
+            (ModelField::Rating, OrderSpec::desc().nulls_first()),
+            (ModelField::Id, OrderSpec::asc().case_insensitive()),
+        ]);
+        let (sql, values) = select(None, Some(order_by));
+        let (_, order_by) = sql
+            .split_once(" ORDER BY ")
+            .expect("query has an ORDER BY clause");
+        assert_eq!(
+            order_by,
+            r#""article"."title" COLLATE "de-DE-x-icu" ASC NULLS LAST, "article"."rating" DESC NULLS FIRST, "article"."id" ASC"#
+        );
+        assert!(values.is_empty());
+    }
+
+    #[test]
+    fn unsupported_collations_are_rejected() {
+        let order_by = IndexMap::from([(ModelField::Title, OrderSpec::asc().collation("C"))]);
+        let result = build_select_query::<Article>(None, None, Some(order_by), None);
+        assert!(matches!(
+            result,
+            Err(SeaOrmRepoError::UnsupportedCollation { .. })
+        ));
+    }
+}
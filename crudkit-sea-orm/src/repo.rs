@@ -6,7 +6,7 @@
 use std::sync::Arc;
 
 use indexmap::IndexMap;
-use sea_orm::{ActiveModelTrait, DatabaseConnection, DbErr, PaginatorTrait};
+use sea_orm::{ActiveModelTrait, DatabaseConnection, DbErr, PaginatorTrait, TryIntoModel};
 use snafu::{Backtrace, ResultExt, Snafu};
 
 use crudkit_rs::{
@@ -14,8 +14,8 @@ use crudkit_rs::{
     repository::{DeleteResult, Repository, RepositoryError},
 };
 
-use crudkit_rs::crudkit_condition::Condition;
-use crudkit_rs::crudkit_core::Order;
+use crudkit_rs::crudkit_condition::{Condition, Operator};
+use crudkit_rs::crudkit_core::OrderSpec;
 
 use crate::query;
 use crate::traits::{
@@ -59,6 +59,19 @@ pub enum SeaOrmRepoError {
         column_name: String,
         backtrace: Backtrace,
     },
+
+    #[snafu(display("SeaOrmRepoError: Collation '{collation}' is not supported."))]
+    UnsupportedCollation {
+        collation: String,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("SeaOrmRepoError: Operator '{operator:?}' cannot be applied: {reason}"))]
+    UnsupportedOperatorValue {
+        operator: Operator,
+        reason: String,
+        backtrace: Backtrace,
+    },
 }
 
 impl RepositoryError for SeaOrmRepoError {}
@@ -74,6 +87,8 @@ where
     R::SeaOrmModel: Into<R::Model>,
     // Model can be converted to ActiveModel for updates/deletes.
     R::Model: IntoActiveModelForUpdate<R::ActiveModel>,
+    // ActiveModel can be converted back to a SeaOrmModel for update previews.
+    R::ActiveModel: TryIntoModel<R::SeaOrmModel>,
     // ReadViewSeaOrmModel can be converted to ReadModel.
     R::ReadViewSeaOrmModel: Into<R::ReadModel>,
 {
@@ -97,7 +112,7 @@ where
         &self,
         limit: Option<u64>,
         skip: Option<u64>,
-        order_by: Option<IndexMap<R::ModelField, Order>>,
+        order_by: Option<IndexMap<R::ModelField, OrderSpec>>,
         condition: Option<&Condition>,
     ) -> Result<u64, Self::Error> {
         query::build_select_query::<R>(limit, skip, order_by, condition)?
@@ -110,7 +125,7 @@ where
         &self,
         limit: Option<u64>,
         skip: Option<u64>,
-        order_by: Option<IndexMap<R::ModelField, Order>>,
+        order_by: Option<IndexMap<R::ModelField, OrderSpec>>,
         condition: Option<&Condition>,
     ) -> Result<Option<R::Model>, Self::Error> {
         let result: Option<R::SeaOrmModel> =
@@ -126,7 +141,7 @@ where
         &self,
         limit: Option<u64>,
         skip: Option<u64>,
-        order_by: Option<IndexMap<R::ModelField, Order>>,
+        order_by: Option<IndexMap<R::ModelField, OrderSpec>>,
         condition: Option<&Condition>,
     ) -> Result<Vec<R::Model>, Self::Error> {
         let results: Vec<R::SeaOrmModel> =
@@ -142,7 +157,7 @@ where
         &self,
         limit: Option<u64>,
         skip: Option<u64>,
-        order_by: Option<IndexMap<R::ReadModelField, Order>>,
+        order_by: Option<IndexMap<R::ReadModelField, OrderSpec>>,
         condition: Option<&Condition>,
     ) -> Result<Option<R::ReadModel>, Self::Error> {
         let result: Option<R::ReadViewSeaOrmModel> =
@@ -158,7 +173,7 @@ where
         &self,
         limit: Option<u64>,
         skip: Option<u64>,
-        order_by: Option<IndexMap<R::ReadModelField, Order>>,
+        order_by: Option<IndexMap<R::ReadModelField, OrderSpec>>,
         condition: Option<&Condition>,
     ) -> Result<Vec<R::ReadModel>, Self::Error> {
         let results: Vec<R::ReadViewSeaOrmModel> =
@@ -170,6 +185,19 @@ where
         Ok(results.into_iter().map(Into::into).collect())
     }
 
+    fn preview_update(
+        &self,
+        existing: &R::Model,
+        update_model: &R::UpdateModel,
+    ) -> Result<R::Model, Self::Error> {
+        let mut active_model: R::ActiveModel = existing.clone().into_active_model_for_update();
+        update_model.clone().apply_to(&mut active_model);
+
+        let preview: R::SeaOrmModel = active_model.try_into_model().context(DbSnafu {})?;
+
+        Ok(preview.into())
+    }
+
     async fn update(
         &self,
         existing: R::Model,
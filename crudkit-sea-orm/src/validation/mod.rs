@@ -7,6 +7,9 @@ pub mod unified;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, EnumIter, DeriveActiveEnum)]
 #[sea_orm(rs_type = "String", db_type = "String(Some(16))")]
 pub enum PersistedViolationSeverity {
+    #[sea_orm(string_value = "INFO")]
+    Info,
+
     #[sea_orm(string_value = "MAJOR")]
     Major,
 
@@ -17,6 +20,7 @@ pub enum PersistedViolationSeverity {
 impl From<Severity> for PersistedViolationSeverity {
     fn from(value: Severity) -> Self {
         match value {
+            Severity::Info => PersistedViolationSeverity::Info,
             Severity::Major => PersistedViolationSeverity::Major,
             Severity::Critical => PersistedViolationSeverity::Critical,
         }
@@ -32,13 +32,25 @@ pub struct Model {
     /// The version of the validator.
     pub validator_version: i64,
 
-    /// The severity of the violation (e.g., "MAJOR", "CRITICAL").
+    /// The severity of the violation (e.g., "INFO", "MAJOR", "CRITICAL").
     pub violation_severity: PersistedViolationSeverity,
 
     /// The violation message.
     #[sea_orm(column_type = "Text")]
     pub violation_message: String,
 
+    /// Names of the fields the violation concerns, serialized as a JSON array.
+    /// Empty if the violation concerns the entity as a whole.
+    #[sea_orm(column_type = "JsonBinary")]
+    pub violation_fields: serde_json::Value,
+
+    /// Stable machine-readable code of the violation, if any. Also the key of its message.
+    pub violation_code: Option<String>,
+
+    /// Typed parameters of the message, serialized as a JSON object.
+    #[sea_orm(column_type = "JsonBinary")]
+    pub violation_params: serde_json::Value,
+
     /// Timestamp when this record was created.
     pub created_at: time::OffsetDateTime,
 }
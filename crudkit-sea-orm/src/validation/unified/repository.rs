@@ -5,8 +5,8 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use error_stack::{Report, ResultExt};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, Order, QueryFilter, QueryOrder,
-    Set, TransactionTrait,
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, Order, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
 };
 use thiserror::Error;
 use tracing::info;
@@ -17,7 +17,9 @@ use crudkit_core::id::{Id, SerializableId};
 use crudkit_core::resource::ResourceName;
 use crudkit_core::validation::validator::ValidatorInfo;
 use crudkit_core::validation::violation::{Violation, Violations};
-use crudkit_core::validation::{ViolationsByEntity, ViolationsByResource};
+use crudkit_core::validation::{
+    PersistedViolation, ViolationFilter, ViolationsByEntity, ViolationsByResource,
+};
 use crudkit_rs::repository::{RepositoryError, ValidationResultRepository};
 
 /// Errors that can occur when working with the validation repository.
@@ -70,6 +72,31 @@ impl UnifiedValidationRepository {
             .ok_or_else(|| Report::new(UnifiedValidationRepositoryError::Deserialization))
     }
 
+    /// Deserialize a stored violation from its columns.
+    fn deserialize_violation(
+        severity: PersistedViolationSeverity,
+        message: String,
+        fields: &serde_json::Value,
+        code: Option<String>,
+        params: &serde_json::Value,
+    ) -> Result<Violation> {
+        let fields: Vec<String> = serde_json::from_value(fields.clone())
+            .change_context(UnifiedValidationRepositoryError::Deserialization)?;
+        let params = serde_json::from_value(params.clone())
+            .change_context(UnifiedValidationRepositoryError::Deserialization)?;
+        let violation = match severity {
+            PersistedViolationSeverity::Info => Violation::info(message),
+            PersistedViolationSeverity::Major => Violation::major(message),
+            PersistedViolationSeverity::Critical => Violation::critical(message),
+        };
+        Ok(Violation {
+            fields,
+            code,
+            params,
+            ..violation
+        })
+    }
+
     /// Build a filter condition for all entities of a specific resource type.
     fn resource_filter(&self, resource_name: &str) -> sea_orm::Condition {
         sea_orm::Condition::all().add(Column::ResourceName.eq(resource_name))
@@ -88,6 +115,97 @@ impl UnifiedValidationRepository {
         Ok(cond)
     }
 
+    /// Build a filter condition for the violations of a resource type selected by `filter`.
+    fn violation_filter(
+        &self,
+        resource_name: &str,
+        filter: &ViolationFilter,
+        entity_ids: Option<&[SerializableId]>,
+    ) -> Result<sea_orm::Condition> {
+        let to_json = |entity_id: &SerializableId| {
+            serde_json::to_value(entity_id)
+                .change_context(UnifiedValidationRepositoryError::Serialization)
+        };
+        let mut cond = self.resource_filter(resource_name);
+        if let Some(entity_id) = &filter.entity_id {
+            cond = cond.add(Column::EntityId.eq(to_json(entity_id)?));
+        }
+        if let Some(entity_ids) = entity_ids {
+            let json_ids = entity_ids.iter().map(to_json).collect::<Result<Vec<_>>>()?;
+            cond = cond.add(Column::EntityId.is_in(json_ids));
+        }
+        if !filter.severities.is_empty() {
+            cond = cond.add(
+                Column::ViolationSeverity.is_in(
+                    filter
+                        .severities
+                        .iter()
+                        .map(|severity| PersistedViolationSeverity::from(*severity)),
+                ),
+            );
+        }
+        if let Some(validator_name) = &filter.validator_name {
+            cond = cond.add(Column::ValidatorName.eq(validator_name.as_str()));
+        }
+        Ok(cond)
+    }
+
+    /// List the violations selected by `filter`, in a stable order for paging.
+    async fn filtered(
+        &self,
+        resource_name: &str,
+        filter: &ViolationFilter,
+        entity_ids: Option<&[SerializableId]>,
+        limit: Option<u64>,
+        skip: Option<u64>,
+    ) -> Result<Vec<PersistedViolation>> {
+        let entries = Entity::find()
+            .filter(self.violation_filter(resource_name, filter, entity_ids)?)
+            .order_by(Column::ValidatorName, Order::Asc)
+            .order_by(Column::ValidatorVersion, Order::Desc)
+            .order_by(Column::ViolationSeverity, Order::Asc)
+            .order_by(Column::Id, Order::Asc)
+            .limit(limit)
+            .offset(skip)
+            .all(self.db.as_ref())
+            .await
+            .change_context(UnifiedValidationRepositoryError::Db)?;
+
+        entries
+            .into_iter()
+            .map(|entry| {
+                Ok(PersistedViolation {
+                    entity_id: Self::deserialize_id_untyped(&entry.entity_id)?,
+                    validator: ValidatorInfo::new_owned(
+                        entry.validator_name,
+                        entry.validator_version as u32,
+                    ),
+                    violation: Self::deserialize_violation(
+                        entry.violation_severity,
+                        entry.violation_message,
+                        &entry.violation_fields,
+                        entry.violation_code,
+                        &entry.violation_params,
+                    )?,
+                })
+            })
+            .collect()
+    }
+
+    /// Count the violations selected by `filter`.
+    async fn count(
+        &self,
+        resource_name: &str,
+        filter: &ViolationFilter,
+        entity_ids: Option<&[SerializableId]>,
+    ) -> Result<u64> {
+        Entity::find()
+            .filter(self.violation_filter(resource_name, filter, entity_ids)?)
+            .count(self.db.as_ref())
+            .await
+            .change_context(UnifiedValidationRepositoryError::Db)
+    }
+
     /// List all validation results, ordered for grouping.
     async fn list_all_ordered(&self) -> Result<Vec<Model>> {
         let query = Entity::find()
@@ -128,12 +246,13 @@ impl UnifiedValidationRepository {
             let validator_info =
                 ValidatorInfo::new_owned(entry.validator_name, entry.validator_version as u32);
 
-            let violation = match entry.violation_severity {
-                PersistedViolationSeverity::Major => Violation::major(entry.violation_message),
-                PersistedViolationSeverity::Critical => {
-                    Violation::critical(entry.violation_message)
-                }
-            };
+            let violation = Self::deserialize_violation(
+                entry.violation_severity,
+                entry.violation_message,
+                &entry.violation_fields,
+                entry.violation_code,
+                &entry.violation_params,
+            )?;
 
             violations_by_entity.push(entity_id, validator_info, violation);
         }
@@ -153,12 +272,13 @@ impl UnifiedValidationRepository {
             let validator_info =
                 ValidatorInfo::new_owned(entry.validator_name, entry.validator_version as u32);
 
-            let violation = match entry.violation_severity {
-                PersistedViolationSeverity::Major => Violation::major(entry.violation_message),
-                PersistedViolationSeverity::Critical => {
-                    Violation::critical(entry.violation_message)
-                }
-            };
+            let violation = Self::deserialize_violation(
+                entry.violation_severity,
+                entry.violation_message,
+                &entry.violation_fields,
+                entry.violation_code,
+                &entry.violation_params,
+            )?;
 
             all.map
                 .entry(aggregate_name)
@@ -220,6 +340,10 @@ impl UnifiedValidationRepository {
         // Insert new validation results.
         let mut num_saved = 0;
         for violation in violations {
+            let violation_fields = serde_json::to_value(violation.fields())
+                .change_context(UnifiedValidationRepositoryError::Serialization)?;
+            let violation_params = serde_json::to_value(&violation.params)
+                .change_context(UnifiedValidationRepositoryError::Serialization)?;
             let active_model = ActiveModel {
                 id: sea_orm::ActiveValue::NotSet,
                 resource_name: Set(resource_name.to_owned()),
@@ -227,6 +351,9 @@ impl UnifiedValidationRepository {
                 validator_name: Set(validator_name.to_owned()),
                 validator_version: Set(validator_version as i64),
                 violation_severity: Set(violation.severity().into()),
+                violation_fields: Set(violation_fields),
+                violation_code: Set(violation.code.clone()),
+                violation_params: Set(violation_params),
                 violation_message: Set(violation.into_message()),
                 created_at: Set(*now),
             };
@@ -274,6 +401,31 @@ impl UnifiedValidationRepository {
         Ok(())
     }
 
+    /// Delete all violations of a specific resource produced by validators not in `validator_names`.
+    async fn delete_violations_of_other_validators(
+        &self,
+        resource_name: &str,
+        validator_names: &[String],
+    ) -> Result<()> {
+        let delete_result = Entity::delete_many()
+            .filter(
+                self.resource_filter(resource_name)
+                    .add(Column::ValidatorName.is_not_in(validator_names.iter().cloned())),
+            )
+            .exec(self.db.as_ref())
+            .await
+            .change_context(UnifiedValidationRepositoryError::Db)?;
+
+        if delete_result.rows_affected > 0 {
+            info!(
+                "Deleted {} violations of unregistered validators for resource type '{resource_name}'.",
+                delete_result.rows_affected,
+            );
+        }
+
+        Ok(())
+    }
+
     /// Delete all violations of a specific resource.
     async fn delete_violations_of_resource(&self, resource_name: &str) -> Result<()> {
         let delete_result = Entity::delete_many()
@@ -311,6 +463,15 @@ impl ValidationResultRepository for UnifiedValidationRepository {
         self.delete_violations_of_resource(resource_name).await
     }
 
+    async fn delete_all_of_other_validators(
+        &self,
+        resource_name: &str,
+        validator_names: &[String],
+    ) -> core::result::Result<(), Self::Error> {
+        self.delete_violations_of_other_validators(resource_name, validator_names)
+            .await
+    }
+
     async fn save_all<I: Id>(
         &self,
         resource_name: &str,
@@ -351,4 +512,25 @@ impl ValidationResultRepository for UnifiedValidationRepository {
     async fn list_all(&self) -> std::result::Result<ViolationsByResource, Self::Error> {
         self.all().await
     }
+
+    async fn list_filtered(
+        &self,
+        resource_name: &str,
+        filter: &ViolationFilter,
+        entity_ids: Option<&[SerializableId]>,
+        limit: Option<u64>,
+        skip: Option<u64>,
+    ) -> std::result::Result<Vec<PersistedViolation>, Self::Error> {
+        self.filtered(resource_name, filter, entity_ids, limit, skip)
+            .await
+    }
+
+    async fn count_filtered(
+        &self,
+        resource_name: &str,
+        filter: &ViolationFilter,
+        entity_ids: Option<&[SerializableId]>,
+    ) -> std::result::Result<u64, Self::Error> {
+        self.count(resource_name, filter, entity_ids).await
+    }
 }
@@ -1,6 +1,6 @@
 use crudkit_core_macro_util::{
-    classify_base_type, is_ordered_float, path_to_string, strip_option_path, to_pascal_case,
-    ValueKind, ValueKindExt,
+    array_element_type, classify_base_type, is_ordered_float, path_to_string, strip_option_path,
+    strip_option_type, to_pascal_case, to_screaming_snake_case, ValueKind, ValueKindExt,
 };
 use darling::*;
 use proc_macro2::{Ident, Span, TokenStream};
@@ -21,7 +21,10 @@ struct ClassifiedType {
     /// Whether the type is `Option<T>`.
     is_optional: bool,
     /// Whether this is an `OrderedFloat` wrapper (requires `.into()` conversion).
+    /// For `Array` fields, this describes the element type.
     is_ordered_float: bool,
+    /// The kind of the elements of an `Array` field.
+    element_kind: Option<ValueKind>,
 }
 
 impl ClassifiedType {
@@ -37,6 +40,7 @@ impl ClassifiedType {
                 kind: ValueKind::Void,
                 is_optional: false,
                 is_ordered_float: false,
+                element_kind: None,
             };
         }
 
@@ -78,6 +82,7 @@ impl ClassifiedType {
             kind,
             is_optional,
             is_ordered_float,
+            element_kind: None,
         }
     }
 
@@ -100,12 +105,40 @@ struct CkFieldConfig {
 
     /// The Rust type of this field (from syn).
     ty: syn::Type,
+
+    /// Whether the field holds a `CkEnum`, or a `Vec` of them, set with `#[ck_field(enum)]`.
+    #[darling(default, rename = "enum")]
+    is_enum: bool,
 }
 
 impl CkFieldConfig {
+    /// The element type of a `Vec` field stored as an array, e.g. `String` for `Vec<String>`.
+    fn array_element_type(&self) -> Option<&syn::Type> {
+        array_element_type(&self.ty, self.is_enum)
+    }
+
     /// Returns the classified type for this field.
     pub fn classified_type(&self) -> ClassifiedType {
-        ClassifiedType::from_syn_type(&self.ty)
+        let classified = ClassifiedType::from_syn_type(&self.ty);
+        match (self.array_element_type(), self.is_enum) {
+            (Some(element_ty), is_enum) => {
+                let element = ClassifiedType::from_syn_type(element_ty);
+                ClassifiedType {
+                    kind: ValueKind::Array,
+                    is_ordered_float: element.is_ordered_float,
+                    element_kind: Some(match is_enum {
+                        true => ValueKind::Enum,
+                        false => element.kind,
+                    }),
+                    ..classified
+                }
+            }
+            (None, true) => ClassifiedType {
+                kind: ValueKind::Enum,
+                ..classified
+            },
+            (None, false) => classified,
+        }
     }
 }
 
@@ -161,6 +194,38 @@ fn generate_get_value_expr(field_ident: &Ident, classified: ClassifiedType) -> T
         (Void, _) => return quote! { crudkit_core::Value::Void(()) },
         (Other, _) => return quote! { crudkit_core::Value::Void(()) },
 
+        // Enums are represented by their variant names.
+        (Enum, false) => {
+            return quote! {
+                crudkit_core::Value::Enum(crudkit_core::CkEnum::variant_name(&entity.#field_ident).to_owned())
+            };
+        }
+        (Enum, true) => {
+            return quote! {
+                match &entity.#field_ident {
+                    Some(v) => crudkit_core::Value::Enum(crudkit_core::CkEnum::variant_name(v).to_owned()),
+                    None => crudkit_core::Value::Null,
+                }
+            };
+        }
+
+        // Arrays hold their elements as values.
+        (Array, false) => {
+            let element = generate_element_value_expr(classified);
+            return quote! {
+                crudkit_core::Value::Array(entity.#field_ident.iter().map(|element| #element).collect())
+            };
+        }
+        (Array, true) => {
+            let element = generate_element_value_expr(classified);
+            return quote! {
+                match &entity.#field_ident {
+                    Some(v) => crudkit_core::Value::Array(v.iter().map(|element| #element).collect()),
+                    None => crudkit_core::Value::Null,
+                }
+            };
+        }
+
         // Json needs JsonValue wrapper.
         (Json, false) => {
             return quote! {
@@ -234,6 +299,25 @@ fn generate_get_value_expr(field_ident: &Ident, classified: ClassifiedType) -> T
     }
 }
 
+/// Generates the expression converting `element`, a reference to an element of an array field,
+/// to a `Value`.
+fn generate_element_value_expr(classified: ClassifiedType) -> TokenStream {
+    let element_kind = classified
+        .element_kind
+        .expect("Array fields have an element kind");
+    let variant = element_kind.value_variant_ident();
+    match element_kind {
+        ValueKind::Enum => quote! {
+            crudkit_core::Value::Enum(crudkit_core::CkEnum::variant_name(element).to_owned())
+        },
+        ValueKind::String => quote! { crudkit_core::Value::String(element.clone()) },
+        _ if classified.is_ordered_float => {
+            quote! { crudkit_core::Value::#variant((*element).into()) }
+        }
+        _ => quote! { crudkit_core::Value::#variant(*element) },
+    }
+}
+
 /// Generates the `set_value` match arms for `CrudFieldValueTrait`.
 fn generate_set_value_arm(field: &CkFieldConfig, field_enum_ident: &Ident) -> TokenStream {
     let field_ident = field.ident.as_ref().expect("Expected named field!");
@@ -244,7 +328,10 @@ fn generate_set_value_arm(field: &CkFieldConfig, field_enum_ident: &Ident) -> To
     let classified = field.classified_type();
 
     // Generate the assignment expression for setting the field value.
-    let take_op = generate_set_value_expr(field_ident, classified);
+    let value_ty = field
+        .array_element_type()
+        .unwrap_or_else(|| strip_option_type(&field.ty));
+    let take_op = generate_set_value_expr(field_ident, value_ty, classified);
 
     quote! {
         #field_enum_ident::#field_name_as_type_ident => #take_op
@@ -275,20 +362,88 @@ fn generate_is_optional_arm(field: &CkFieldConfig, field_enum_ident: &Ident) ->
     quote! { #field_enum_ident::#field_name_as_type_ident => #is_optional }
 }
 
+/// Generates the `enum_variants` match arm for a field. Arrays of enums list the element's
+/// variants.
+fn generate_enum_variants_arm(field: &CkFieldConfig, field_enum_ident: &Ident) -> TokenStream {
+    let field_ident = field.ident.as_ref().expect("Expected named field!");
+    let field_name = field_ident.to_string();
+    let pascal_case = to_pascal_case(&field_name);
+    let field_name_as_type_ident = Ident::new(pascal_case.as_str(), Span::call_site());
+
+    match field.is_enum {
+        true => {
+            let enum_ty = field
+                .array_element_type()
+                .unwrap_or_else(|| strip_option_type(&field.ty));
+            quote! { #field_enum_ident::#field_name_as_type_ident => <#enum_ty as crudkit_core::CkEnum>::variants() }
+        }
+        false => quote! { #field_enum_ident::#field_name_as_type_ident => &[] },
+    }
+}
+
+/// Generates the `element_kind` match arm for a field.
+fn generate_element_kind_arm(field: &CkFieldConfig, field_enum_ident: &Ident) -> TokenStream {
+    let field_ident = field.ident.as_ref().expect("Expected named field!");
+    let field_name = field_ident.to_string();
+    let pascal_case = to_pascal_case(&field_name);
+    let field_name_as_type_ident = Ident::new(pascal_case.as_str(), Span::call_site());
+
+    match field.classified_type().element_kind {
+        Some(kind) => {
+            let kind_variant = kind.value_variant_ident();
+            quote! { #field_enum_ident::#field_name_as_type_ident => Some(crudkit_core::ValueKind::#kind_variant) }
+        }
+        None => quote! { #field_enum_ident::#field_name_as_type_ident => None },
+    }
+}
+
 /// Generates the expression to set a field's value from a `Value`.
-fn generate_set_value_expr(field_ident: &Ident, classified: ClassifiedType) -> TokenStream {
+///
+/// `value_ty` is the type of the field without an `Option` wrapper, or the element type of an
+/// array field.
+fn generate_set_value_expr(
+    field_ident: &Ident,
+    value_ty: &syn::Type,
+    classified: ClassifiedType,
+) -> TokenStream {
     use ValueKind::*;
 
     // Special cases that need special handling.
     match (classified.kind, classified.is_optional) {
+        // Enum: look up the variant by its name.
+        (Enum, true) => {
+            return quote! {
+                entity.#field_ident = value
+                    .as_enum()
+                    .and_then(|name| <#value_ty as crudkit_core::CkEnum>::from_variant_name(name))
+            };
+        }
+        (Enum, false) => {
+            return quote! {
+                entity.#field_ident = value
+                    .as_enum()
+                    .and_then(|name| <#value_ty as crudkit_core::CkEnum>::from_variant_name(name))
+                    .expect("Value is not a variant of the field's enum")
+            };
+        }
+
         // Void and Other: setting not allowed.
         (Void | Other, _) => {
             return quote! { ::tracing::warn!("Setting a custom field is not allowed") };
         }
 
-        // Array: not yet supported for setting.
-        (Array, _) => {
-            return quote! { ::tracing::warn!("Setting an array field is not yet supported") };
+        // Array: convert each element, skipping elements of another kind.
+        (Array, is_optional) => {
+            let element = generate_element_from_value_expr(value_ty, classified);
+            let elements = quote! {
+                value.take_array().map(|elements| {
+                    elements.into_iter().filter_map(|element| #element).collect()
+                })
+            };
+            return match is_optional {
+                true => quote! { entity.#field_ident = #elements },
+                false => quote! { entity.#field_ident = #elements.expect("Value is not Array") },
+            };
         }
 
         // Null: should never be a field kind.
@@ -343,6 +498,36 @@ fn generate_set_value_expr(field_ident: &Ident, classified: ClassifiedType) -> T
     }
 }
 
+/// Generates the expression converting `element`, a `Value`, to an element of an array field.
+fn generate_element_from_value_expr(
+    element_ty: &syn::Type,
+    classified: ClassifiedType,
+) -> TokenStream {
+    let element_kind = classified
+        .element_kind
+        .expect("Array fields have an element kind");
+    match element_kind {
+        ValueKind::Enum => quote! {
+            element
+                .as_enum()
+                .and_then(|name| <#element_ty as crudkit_core::CkEnum>::from_variant_name(name))
+        },
+        ValueKind::String => quote! { element.take_string() },
+        _ => {
+            let method_ident = Ident::new(
+                element_kind
+                    .accessor_method_name()
+                    .expect("Array elements have an accessor"),
+                Span::call_site(),
+            );
+            match classified.is_ordered_float {
+                true => quote! { element.#method_ident().map(Into::into) },
+                false => quote! { element.#method_ident() },
+            }
+        }
+    }
+}
+
 pub fn expand_derive_field(input: DeriveInput) -> syn::Result<TokenStream> {
     let input_receiver: CkFieldInputReceiver = FromDeriveInput::from_derive_input(&input)
         .map_err(|e| syn::Error::new_spanned(&input, e))?;
@@ -368,6 +553,23 @@ pub fn expand_derive_field(input: DeriveInput) -> syn::Result<TokenStream> {
         quote! { pub const #type_ident: #field_name = #field_name::#type_ident; }
     });
 
+    // Typed entry points for the condition builder, checking values at compile time,
+    // e.g. `ArticleField::TITLE.eq("x")`.
+    let typed_field_consts = input_receiver.fields().iter().map(|field| {
+        let name = field.ident.as_ref().expect("Expected named field!");
+        let const_ident = Ident::new(
+            to_screaming_snake_case(&name.to_string()).as_str(),
+            Span::call_site(),
+        );
+        let name = name.to_string();
+        let value_ty = strip_option_type(&field.ty);
+
+        quote! {
+            pub const #const_ident: crudkit_core::condition::TypedField<#value_ty> =
+                crudkit_core::condition::TypedField::new(#name);
+        }
+    });
+
     let match_field_name_to_str_arms = input_receiver.fields().iter().map(|field| {
         let name = field.ident.as_ref().expect("Expected named field!");
         let name = name.to_string();
@@ -480,6 +682,32 @@ pub fn expand_derive_field(input: DeriveInput) -> syn::Result<TokenStream> {
         },
     };
 
+    let enum_variants_arms = input_receiver
+        .fields()
+        .iter()
+        .map(|field| generate_enum_variants_arm(field, &field_name));
+    let enum_variants_impl = match input_receiver.fields().len() {
+        0 => quote! { &[] },
+        _ => quote! {
+            match self {
+                #(#enum_variants_arms),*
+            }
+        },
+    };
+
+    let element_kind_arms = input_receiver
+        .fields()
+        .iter()
+        .map(|field| generate_element_kind_arm(field, &field_name));
+    let element_kind_impl = match input_receiver.fields().len() {
+        0 => quote! { None },
+        _ => quote! {
+            match self {
+                #(#element_kind_arms),*
+            }
+        },
+    };
+
     let field_value_trait_impl = quote! {
         impl crudkit_web::FieldAccess<#name> for #field_name {
             fn value(&self, entity: &#name) -> crudkit_core::Value {
@@ -497,6 +725,14 @@ pub fn expand_derive_field(input: DeriveInput) -> syn::Result<TokenStream> {
             fn is_optional(&self) -> bool {
                 #is_optional_impl
             }
+
+            fn enum_variants(&self) -> &'static [crudkit_core::EnumVariant] {
+                #enum_variants_impl
+            }
+
+            fn element_kind(&self) -> Option<crudkit_core::ValueKind> {
+                #element_kind_impl
+            }
         }
     };
 
@@ -512,6 +748,10 @@ pub fn expand_derive_field(input: DeriveInput) -> syn::Result<TokenStream> {
             #(#typified_fields),*
         }
 
+        impl #field_name {
+            #(#typed_field_consts)*
+        }
+
         impl crudkit_core::Named for #field_name {
             fn name(&self) -> std::borrow::Cow<'static, str> {
                 std::borrow::Cow::Borrowed(#get_name_impl)
@@ -544,6 +784,14 @@ pub fn expand_derive_field(input: DeriveInput) -> syn::Result<TokenStream> {
             fn is_optional(&self) -> bool {
                 crudkit_web::FieldAccess::<#name>::is_optional(self)
             }
+
+            fn enum_variants(&self) -> &'static [crudkit_core::EnumVariant] {
+                crudkit_web::FieldAccess::<#name>::enum_variants(self)
+            }
+
+            fn element_kind(&self) -> Option<crudkit_core::ValueKind> {
+                crudkit_web::FieldAccess::<#name>::element_kind(self)
+            }
         }
 
         #model_type_based_field_trait_impl
@@ -555,7 +803,35 @@ pub fn expand_derive_field(input: DeriveInput) -> syn::Result<TokenStream> {
         }
 
         #[typetag::serde]
-        impl crudkit_web::model::ErasedModel for #name {}
+        impl crudkit_web::model::ErasedModel for #name {
+            fn as_condition_target(&self) -> Option<&dyn crudkit_core::condition::ConditionTarget> {
+                Some(self)
+            }
+        }
+
+        impl crudkit_core::condition::ConditionTarget for #name {
+            fn field_value(&self, field_name: &str) -> Option<crudkit_core::Value> {
+                <#name as crudkit_web::Model>::all_fields()
+                    .into_iter()
+                    .find(|field| crudkit_core::Named::name(field) == field_name)
+                    .map(|field| crudkit_web::FieldAccess::value(&field, self))
+            }
+
+            fn convert_clause_value(
+                &self,
+                field_name: &str,
+                value: crudkit_core::condition::ConditionClauseValue,
+            ) -> Result<crudkit_core::Value, String> {
+                let field = <#name as crudkit_web::Model>::all_fields()
+                    .into_iter()
+                    .find(|field| crudkit_core::Named::name(field) == field_name)
+                    .ok_or_else(|| format!("Unknown field '{field_name}'."))?;
+                match crudkit_web::FieldAccess::<#name>::element_kind(&field) {
+                    Some(element_kind) => value.into_array_of_kind(element_kind),
+                    None => value.into_value_of_kind(crudkit_web::FieldAccess::<#name>::value_kind(&field)),
+                }
+            }
+        }
 
         #field_value_trait_impl
     })
@@ -38,6 +38,14 @@ impl ModelType {
                 fn is_optional(&self) -> bool {
                     crudkit_web::FieldAccess::<#model_name>::is_optional(self)
                 }
+
+                fn enum_variants(&self) -> &'static [crudkit_core::EnumVariant] {
+                    crudkit_web::FieldAccess::<#model_name>::enum_variants(self)
+                }
+
+                fn element_kind(&self) -> Option<crudkit_core::ValueKind> {
+                    crudkit_web::FieldAccess::<#model_name>::element_kind(self)
+                }
             }
         }
     }
@@ -49,6 +49,7 @@ pub fn derive_resource(input: TokenStream) -> TokenStream {
 /// # Attributes
 ///
 /// - `#[ck_field(model = Create|Read|Update)]` - Required: specifies which model type this is
+/// - `#[ck_field(enum)]` - On a field: the field holds a `CkEnum`, rendered as a select
 #[proc_macro_derive(CkField, attributes(ck_field, ck_id))]
 #[proc_macro_error]
 pub fn derive_field(input: TokenStream) -> TokenStream {
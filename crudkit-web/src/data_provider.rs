@@ -3,7 +3,10 @@ use crate::reqwest_executor::ReqwestExecutor;
 use crate::{request, Model, Resource};
 use crudkit_core::condition::{merge_conditions, Condition};
 use crudkit_core::id::SerializableId;
-use crudkit_core::{Deleted, Order, Saved};
+use crudkit_core::validation::{
+    PartialSerializableAggregateViolations, PersistedViolation, ViolationFilter,
+};
+use crudkit_core::{Deleted, OrderSpec, Saved};
 use indexmap::IndexMap;
 use serde::{de::DeserializeOwned, Serialize};
 use std::sync::Arc;
@@ -19,7 +22,7 @@ pub struct ReadCount {
 pub struct ReadMany<T: Model> {
     pub limit: Option<u64>,
     pub skip: Option<u64>,
-    pub order_by: Option<IndexMap<T::Field, Order>>,
+    pub order_by: Option<IndexMap<T::Field, OrderSpec>>,
     pub condition: Option<Condition>,
 }
 
@@ -38,7 +41,7 @@ impl<T: Model> ReadMany<T> {
 #[derive(Debug, Serialize)]
 pub struct ReadOne<T: Model> {
     pub skip: Option<u64>,
-    pub order_by: Option<IndexMap<T::Field, Order>>,
+    pub order_by: Option<IndexMap<T::Field, OrderSpec>>,
     pub condition: Option<Condition>,
 }
 
@@ -53,11 +56,34 @@ pub struct UpdateOne<T: Serialize + DeserializeOwned> {
     pub condition: Option<Condition>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct ValidateCreate<T: Serialize + DeserializeOwned> {
+    pub entity: T,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ValidateUpdate<T: Serialize + DeserializeOwned> {
+    pub entity: T,
+    pub condition: Option<Condition>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct DeleteById {
     pub id: SerializableId,
 }
 
+#[derive(Debug, Default, Serialize)]
+pub struct ReadViolationCount {
+    pub filter: ViolationFilter,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ReadViolations {
+    pub limit: Option<u64>,
+    pub skip: Option<u64>,
+    pub filter: ViolationFilter,
+}
+
 #[derive(Debug, Clone)]
 pub struct CrudRestDataProvider<T: Resource> {
     api_base_url: String,
@@ -167,6 +193,46 @@ impl<T: Resource> CrudRestDataProvider<T> {
         .await
     }
 
+    /// Validate `entity` as creating it would, without creating it.
+    pub async fn validate_create(
+        &self,
+        validate_create: ValidateCreate<T::CreateModel>,
+    ) -> Result<PartialSerializableAggregateViolations, RequestError>
+    where
+        <T as Resource>::CreateModel: 'static,
+    {
+        request::post(
+            format!(
+                "{}/{}/crud/validate-create",
+                self.api_base_url, self.resource_name
+            ),
+            self.executor.as_ref(),
+            validate_create,
+        )
+        .await
+    }
+
+    /// Validate `entity` as updating the entity matching `condition` would, without updating it.
+    pub async fn validate_update(
+        &self,
+        mut validate_update: ValidateUpdate<T::UpdateModel>,
+    ) -> Result<PartialSerializableAggregateViolations, RequestError>
+    where
+        <T as Resource>::UpdateModel: 'static,
+    {
+        validate_update.condition =
+            merge_conditions(self.base_condition.clone(), validate_update.condition);
+        request::post(
+            format!(
+                "{}/{}/crud/validate-update",
+                self.api_base_url, self.resource_name
+            ),
+            self.executor.as_ref(),
+            validate_update,
+        )
+        .await
+    }
+
     pub async fn delete_by_id(&self, delete_by_id: DeleteById) -> Result<Deleted, RequestError> {
         request::post(
             format!(
@@ -178,4 +244,34 @@ impl<T: Resource> CrudRestDataProvider<T> {
         )
         .await
     }
+
+    pub async fn read_violation_count(
+        &self,
+        read_violation_count: ReadViolationCount,
+    ) -> Result<u64, RequestError> {
+        request::post(
+            format!(
+                "{}/{}/crud/read-violation-count",
+                self.api_base_url, self.resource_name
+            ),
+            self.executor.as_ref(),
+            read_violation_count,
+        )
+        .await
+    }
+
+    pub async fn read_violations(
+        &self,
+        read_violations: ReadViolations,
+    ) -> Result<Vec<PersistedViolation>, RequestError> {
+        request::post(
+            format!(
+                "{}/{}/crud/read-violations",
+                self.api_base_url, self.resource_name
+            ),
+            self.executor.as_ref(),
+            read_violations,
+        )
+        .await
+    }
 }
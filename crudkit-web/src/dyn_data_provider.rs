@@ -5,27 +5,28 @@ use crate::request::post_json;
 use crate::request_error::RequestError;
 use crate::reqwest_executor::ReqwestExecutor;
 use crudkit_core::condition::{Condition, merge_conditions};
-use crudkit_core::{Deleted, DeletedMany, Order};
+use crudkit_core::validation::{PartialSerializableAggregateViolations, PersistedViolation};
+use crudkit_core::{Deleted, DeletedMany, OrderSpec};
 use indexmap::IndexMap;
 use serde::Serialize;
 use std::fmt::Debug;
 use std::sync::Arc;
 
 // Re-export shared types from data_provider
-pub use crate::data_provider::{DeleteById, ReadCount};
+pub use crate::data_provider::{DeleteById, ReadCount, ReadViolationCount, ReadViolations};
 
 #[derive(Debug, Serialize)]
 pub struct DynReadMany {
     pub limit: Option<u64>,
     pub skip: Option<u64>,
-    pub order_by: Option<IndexMap<SerializableReadField, Order>>,
+    pub order_by: Option<IndexMap<SerializableReadField, OrderSpec>>,
     pub condition: Option<Condition>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct DynReadOne {
     pub skip: Option<u64>,
-    pub order_by: Option<IndexMap<SerializableReadField, Order>>,
+    pub order_by: Option<IndexMap<SerializableReadField, OrderSpec>>,
     pub condition: Option<Condition>,
 }
 
@@ -42,6 +43,19 @@ pub struct DynUpdateOne {
     pub condition: Option<Condition>,
 }
 
+/// Not `Serialize`, as we perform custom serialization of the model on use.
+#[derive(Debug)]
+pub struct DynValidateCreate {
+    pub entity: DynCreateModel,
+}
+
+/// Not `Serialize`, as we perform custom serialization of the model on use.
+#[derive(Debug)]
+pub struct DynValidateUpdate {
+    pub entity: DynUpdateModel,
+    pub condition: Option<Condition>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct DynDeleteMany {
     pub condition: Option<Condition>,
@@ -168,6 +182,64 @@ impl DynCrudRestDataProvider {
         .await
     }
 
+    /// Validate the entity as creating it would, without creating it.
+    pub async fn validate_create(
+        &self,
+        validate_create: DynValidateCreate,
+    ) -> Result<PartialSerializableAggregateViolations, RequestError> {
+        #[derive(Debug, Serialize)]
+        struct ValidateCreateDto {
+            entity: serde_json::Value,
+        }
+
+        let entity =
+            serialize_any_as_json_value_omitting_type_information(&validate_create.entity.inner)
+                .map_err(|e| RequestError::BadRequest(format!("Serialization failed: {e}")))?;
+
+        let json = post_json(
+            format!(
+                "{}/{}/crud/validate-create",
+                self.api_base_url, self.resource_name
+            ),
+            self.executor.as_ref(),
+            ValidateCreateDto { entity },
+        )
+        .await?;
+        serde_json::from_value(json).map_err(|e| RequestError::Deserialize(e.to_string()))
+    }
+
+    /// Validate the update as updating would, without updating the entity.
+    pub async fn validate_update(
+        &self,
+        mut validate_update: DynValidateUpdate,
+    ) -> Result<PartialSerializableAggregateViolations, RequestError> {
+        #[derive(Debug, Serialize)]
+        struct ValidateUpdateDto {
+            entity: serde_json::Value,
+            condition: Option<Condition>,
+        }
+
+        let entity =
+            serialize_any_as_json_value_omitting_type_information(&validate_update.entity.inner)
+                .map_err(|e| RequestError::BadRequest(format!("Serialization failed: {e}")))?;
+
+        validate_update.condition =
+            merge_conditions(self.base_condition.clone(), validate_update.condition);
+        let json = post_json(
+            format!(
+                "{}/{}/crud/validate-update",
+                self.api_base_url, self.resource_name
+            ),
+            self.executor.as_ref(),
+            ValidateUpdateDto {
+                entity,
+                condition: validate_update.condition,
+            },
+        )
+        .await?;
+        serde_json::from_value(json).map_err(|e| RequestError::Deserialize(e.to_string()))
+    }
+
     pub async fn delete_by_id(&self, delete_by_id: DeleteById) -> Result<Deleted, RequestError> {
         let json = post_json(
             format!(
@@ -198,6 +270,36 @@ impl DynCrudRestDataProvider {
         .await?;
         serde_json::from_value(json).map_err(|e| RequestError::Deserialize(e.to_string()))
     }
+
+    pub async fn read_violation_count(
+        &self,
+        read_violation_count: ReadViolationCount,
+    ) -> Result<u64, RequestError> {
+        crate::request::post(
+            format!(
+                "{}/{}/crud/read-violation-count",
+                self.api_base_url, self.resource_name
+            ),
+            self.executor.as_ref(),
+            read_violation_count,
+        )
+        .await
+    }
+
+    pub async fn read_violations(
+        &self,
+        read_violations: ReadViolations,
+    ) -> Result<Vec<PersistedViolation>, RequestError> {
+        crate::request::post(
+            format!(
+                "{}/{}/crud/read-violations",
+                self.api_base_url, self.resource_name
+            ),
+            self.executor.as_ref(),
+            read_violations,
+        )
+        .await
+    }
 }
 
 // Serialization helpers for type-erased models
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use crudkit_core::validation::PartialSerializableAggregateViolations;
+use crudkit_core::validation::violation::Violation;
 use serde::{Deserialize, Serialize};
 
 /// Conduit api error info for Unprocessable Entity error
@@ -7,4 +9,28 @@ use serde::{Deserialize, Serialize};
 #[serde(rename_all = "camelCase")]
 pub struct ErrorInfo {
     pub errors: HashMap<String, Vec<String>>,
+
+    /// The violations rejecting the request, if reported. Allows rendering translated messages.
+    #[serde(default)]
+    pub violations: Option<PartialSerializableAggregateViolations>,
+}
+
+impl ErrorInfo {
+    /// Returns the reported violations, keyed by the name of each field they concern.
+    /// Without reported violations, each message in `errors` becomes a critical violation.
+    pub fn violations_by_field(&self) -> HashMap<String, Vec<Violation>> {
+        match &self.violations {
+            Some(violations) => violations.violations_by_field(),
+            None => self
+                .errors
+                .iter()
+                .map(|(field, messages)| {
+                    let violations = messages.iter().map(|message| {
+                        Violation::critical(message.as_str()).for_field(field.as_str())
+                    });
+                    (field.clone(), violations.collect())
+                })
+                .collect(),
+        }
+    }
 }
@@ -42,6 +42,7 @@ pub mod prelude {
     pub use crudkit_core::id;
     pub use crudkit_core::validation;
 
+    pub use crudkit_core_macros::CkEnum;
     pub use crudkit_core_macros::CkId;
     pub use crudkit_web_macros::{CkActionPayload, CkField, CkResource};
 
@@ -70,7 +71,11 @@ pub mod prelude {
     pub use super::data_provider::ReadCount;
     pub use super::data_provider::ReadMany;
     pub use super::data_provider::ReadOne;
+    pub use super::data_provider::ReadViolationCount;
+    pub use super::data_provider::ReadViolations;
     pub use super::data_provider::UpdateOne;
+    pub use super::data_provider::ValidateCreate;
+    pub use super::data_provider::ValidateUpdate;
 
     pub use super::action::ActionPayload;
     pub use super::action::AnyActionPayload;
@@ -125,6 +130,8 @@ pub mod prelude {
     pub use super::dyn_data_provider::DynReadMany;
     pub use super::dyn_data_provider::DynReadOne;
     pub use super::dyn_data_provider::DynUpdateOne;
+    pub use super::dyn_data_provider::DynValidateCreate;
+    pub use super::dyn_data_provider::DynValidateUpdate;
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -252,6 +259,12 @@ pub trait FieldAccess<T> {
 
     /// Returns whether this field is optional.
     fn is_optional(&self) -> bool;
+
+    /// Returns the variants of the enum this field holds. Empty if it holds no `CkEnum`.
+    fn enum_variants(&self) -> &'static [crudkit_core::EnumVariant];
+
+    /// Returns the kind of the elements of an `Array` field. `None` for other fields.
+    fn element_kind(&self) -> Option<crudkit_core::ValueKind>;
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
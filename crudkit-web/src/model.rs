@@ -42,6 +42,7 @@
 //! - `downcast_mut<T>()` - Mutably borrow as concrete type (panics on mismatch)
 
 use crate::{HasId, Model, Named};
+use crudkit_core::condition::{ConditionClauseValue, ConditionTarget};
 use crudkit_core::Value;
 use dyn_clone::DynClone;
 use dyn_eq::DynEq;
@@ -55,10 +56,16 @@ use std::sync::Arc;
 pub use crudkit_core::id::DynIdentifiable;
 pub use crudkit_core::id::ErasedIdentifiable;
 
+/// Any model. Conditions can be evaluated against it in memory if it is a
+/// [`ConditionTarget`], as models deriving `CkField` are.
 #[typetag::serde]
 pub trait ErasedModel:
     ErasedIdentifiable + Debug + DynClone + DynEq + downcast_rs::Downcast + Send + Sync
 {
+    /// This model as a [`ConditionTarget`]. `None` if conditions cannot be evaluated against it.
+    fn as_condition_target(&self) -> Option<&dyn ConditionTarget> {
+        None
+    }
 }
 dyn_eq::eq_trait_object!(ErasedModel);
 dyn_clone::clone_trait_object!(ErasedModel);
@@ -133,6 +140,24 @@ macro_rules! impl_dyn_model {
                 self.inner.as_ref()
             }
         }
+
+        /// Models not being a [`ConditionTarget`] have no fields conditions could refer to.
+        impl ConditionTarget for dyn $erased_ty {
+            fn field_value(&self, field_name: &str) -> Option<Value> {
+                self.as_condition_target()?.field_value(field_name)
+            }
+
+            fn convert_clause_value(
+                &self,
+                field_name: &str,
+                value: ConditionClauseValue,
+            ) -> Result<Value, String> {
+                match self.as_condition_target() {
+                    Some(target) => target.convert_clause_value(field_name, value),
+                    None => Err(format!("Unknown field '{field_name}'.")),
+                }
+            }
+        }
     };
 }
 
@@ -165,6 +190,12 @@ pub trait ErasedField:
 
     /// Returns whether this field is optional.
     fn is_optional(&self) -> bool;
+
+    /// Returns the variants of the enum this field holds. Empty if it holds no `CkEnum`.
+    fn enum_variants(&self) -> &'static [crudkit_core::EnumVariant];
+
+    /// Returns the kind of the elements of an `Array` field. `None` for other fields.
+    fn element_kind(&self) -> Option<crudkit_core::ValueKind>;
 }
 dyn_eq::eq_trait_object!(ErasedField);
 dyn_clone::clone_trait_object!(ErasedField);
@@ -176,6 +207,8 @@ pub trait ErasedCreateField: ErasedField {
     fn set_value(&self, model: &mut DynCreateModel, value: Value);
     fn value_kind(&self) -> crudkit_core::ValueKind;
     fn is_optional(&self) -> bool;
+    fn enum_variants(&self) -> &'static [crudkit_core::EnumVariant];
+    fn element_kind(&self) -> Option<crudkit_core::ValueKind>;
 }
 dyn_eq::eq_trait_object!(ErasedCreateField);
 dyn_clone::clone_trait_object!(ErasedCreateField);
@@ -187,6 +220,8 @@ pub trait ErasedReadField: ErasedField {
     fn set_value(&self, model: &mut DynReadModel, value: Value);
     fn value_kind(&self) -> crudkit_core::ValueKind;
     fn is_optional(&self) -> bool;
+    fn enum_variants(&self) -> &'static [crudkit_core::EnumVariant];
+    fn element_kind(&self) -> Option<crudkit_core::ValueKind>;
 }
 dyn_eq::eq_trait_object!(ErasedReadField);
 dyn_clone::clone_trait_object!(ErasedReadField);
@@ -198,6 +233,8 @@ pub trait ErasedUpdateField: ErasedField {
     fn set_value(&self, model: &mut DynUpdateModel, value: Value);
     fn value_kind(&self) -> crudkit_core::ValueKind;
     fn is_optional(&self) -> bool;
+    fn enum_variants(&self) -> &'static [crudkit_core::EnumVariant];
+    fn element_kind(&self) -> Option<crudkit_core::ValueKind>;
 }
 dyn_eq::eq_trait_object!(ErasedUpdateField);
 dyn_clone::clone_trait_object!(ErasedUpdateField);
@@ -215,6 +252,12 @@ pub trait TypeErasedField:
 
     /// Returns whether this field is optional.
     fn is_optional(&self) -> bool;
+
+    /// Returns the variants of the enum this field holds. Empty if it holds no `CkEnum`.
+    fn enum_variants(&self) -> &'static [crudkit_core::EnumVariant];
+
+    /// Returns the kind of the elements of an `Array` field. `None` for other fields.
+    fn element_kind(&self) -> Option<crudkit_core::ValueKind>;
 }
 
 macro_rules! impl_dyn_field {
@@ -280,6 +323,14 @@ macro_rules! impl_dyn_field {
             fn is_optional(&self) -> bool {
                 $erased_ty::is_optional(self.inner.as_ref())
             }
+
+            fn enum_variants(&self) -> &'static [crudkit_core::EnumVariant] {
+                $erased_ty::enum_variants(self.inner.as_ref())
+            }
+
+            fn element_kind(&self) -> Option<crudkit_core::ValueKind> {
+                $erased_ty::element_kind(self.inner.as_ref())
+            }
         }
     };
 }
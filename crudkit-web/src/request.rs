@@ -6,6 +6,21 @@ use std::sync::Arc;
 use crate::request_error::{RequestError, error_response_to_request_error};
 use crate::reqwest_executor::ReqwestExecutor;
 
+/// Header telling the server our time zone, used to resolve relative times like `start_of_day`
+/// in conditions. Must match `crudkit_rs::auth::UTC_OFFSET_HEADER`.
+const UTC_OFFSET_HEADER: &str = "x-utc-offset";
+
+/// The local UTC offset formatted as `+HH:MM`, falling back to UTC if it cannot be determined.
+fn local_utc_offset() -> String {
+    let offset = time::UtcOffset::current_local_offset().unwrap_or(time::UtcOffset::UTC);
+    let sign = if offset.is_negative() { '-' } else { '+' };
+    format!(
+        "{sign}{:02}:{:02}",
+        offset.whole_hours().unsigned_abs(),
+        offset.minutes_past_hour().unsigned_abs()
+    )
+}
+
 /// build all kinds of http requests: post/get/delete etc.
 pub async fn request<B, T>(
     method: Method,
@@ -23,11 +38,14 @@ where
     let parsed_url = reqwest::Url::parse(&url)
         .map_err(|e| RequestError::BadRequest(format!("Invalid URL '{}': {}", url, e)))?;
 
+    let utc_offset = local_utc_offset();
+
     let result = executor
         .request(
             method,
             parsed_url,
             Arc::new(move |builder| {
+                let builder = builder.header(UTC_OFFSET_HEADER, utc_offset.as_str());
                 if allow_body {
                     builder
                         .header("Content-Type", "application/json")